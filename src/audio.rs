@@ -0,0 +1,244 @@
+//!
+//! Helpers for 24 bit PCM audio.
+//!
+//! Samples are u24 values holding the 24 bit two's complement representation of the sample,
+//! which is exactly what a 24 bit PCM stream stores per sample.
+//! Floating point samples are normalized to the range [-1, 1).
+//!
+use crate::u24;
+
+///
+/// Smallest value of a 24 bit sample.
+///
+pub const SAMPLE_MIN: i32 = -(1 << 23);
+
+///
+/// Largest value of a 24 bit sample.
+///
+pub const SAMPLE_MAX: i32 = (1 << 23) - 1;
+
+const SCALE: f32 = (1 << 23) as f32;
+
+///
+/// Sign extends the sample into an i32.
+///
+#[inline]
+pub const fn sample_to_i32(sample: u24) -> i32 {
+    ((sample.as_num() << 8) as i32) >> 8
+}
+
+///
+/// Converts an i32 into a sample. Values outside of SAMPLE_MIN..=SAMPLE_MAX are clipped.
+///
+#[inline]
+pub const fn sample_from_i32(n: i32) -> u24 {
+    let n = if n < SAMPLE_MIN {
+        SAMPLE_MIN
+    } else if n > SAMPLE_MAX {
+        SAMPLE_MAX
+    } else {
+        n
+    };
+
+    u24::from_num(n as u32 & 0x00FF_FFFF)
+}
+
+///
+/// Converts the sample into a f32 in the range [-1, 1).
+///
+#[inline]
+pub fn sample_to_f32(sample: u24) -> f32 {
+    sample_to_i32(sample) as f32 / SCALE
+}
+
+///
+/// Converts a f32 into the nearest sample. Values outside of [-1, 1) are clipped, NaN becomes 0.
+///
+#[inline]
+pub fn f32_to_sample(value: f32) -> u24 {
+    sample_from_i32(round(value * SCALE))
+}
+
+///
+/// Converts a f32 into the nearest sample.
+/// Returns None for NaN and for values that would have to be clipped.
+///
+#[inline]
+pub fn try_f32_to_sample(value: f32) -> Option<u24> {
+    if value.is_nan() {
+        return None;
+    }
+
+    let n = round(value * SCALE);
+    if !(SAMPLE_MIN..=SAMPLE_MAX).contains(&n) {
+        return None;
+    }
+
+    Some(sample_from_i32(n))
+}
+
+///
+/// Converts every sample of src into a f32.
+///
+/// Panics if both slices have a different length.
+///
+pub fn samples_to_f32(src: &[u24], dst: &mut [f32]) {
+    assert_eq!(src.len(), dst.len(), "source and destination have a different length");
+    for (d, s) in dst.iter_mut().zip(src) {
+        *d = sample_to_f32(*s);
+    }
+}
+
+///
+/// Rounds to the nearest integer with ties away from zero. NaN becomes 0, large values saturate.
+///
+#[inline]
+fn round(value: f32) -> i32 {
+    let shifted = if value < 0.0 { value - 0.5 } else { value + 0.5 };
+    //as truncates towards zero and saturates, which together with the shift rounds to the nearest value.
+    shifted as i32
+}
+
+///
+/// Noise that is added to samples before they are quantized to 24 bit.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Dither {
+    ///
+    /// Plain rounding to the nearest sample.
+    ///
+    #[default]
+    None,
+    ///
+    /// Uniformly distributed noise with an amplitude of half a sample step.
+    ///
+    Rectangular,
+    ///
+    /// Triangular distributed noise with an amplitude of one sample step.
+    ///
+    Triangular,
+}
+
+///
+/// Converts f32 samples into 24 bit samples with optional dithering.
+///
+/// Out of range values are clipped and counted, see clipped_samples.
+/// The noise is produced by a small deterministic generator so the output is reproducible for a given seed.
+///
+#[derive(Clone, Debug)]
+pub struct Quantizer {
+    dither: Dither,
+    state: u32,
+    clipped: usize,
+}
+
+impl Quantizer {
+    ///
+    /// Creates a quantizer with the default seed.
+    ///
+    pub const fn new(dither: Dither) -> Self {
+        Quantizer { dither, state: 0x2545_F491, clipped: 0 }
+    }
+
+    ///
+    /// Replaces the seed of the noise generator. A seed of 0 is replaced by the default seed.
+    ///
+    pub const fn with_seed(mut self, seed: u32) -> Self {
+        if seed != 0 {
+            self.state = seed;
+        }
+
+        self
+    }
+
+    ///
+    /// Amount of samples that were clipped since the quantizer was created.
+    ///
+    #[inline]
+    pub fn clipped_samples(&self) -> usize {
+        self.clipped
+    }
+
+    ///
+    /// Converts a single f32 into a sample. NaN becomes 0.
+    ///
+    pub fn quantize(&mut self, value: f32) -> u24 {
+        let noise = match self.dither {
+            Dither::None => 0.0,
+            Dither::Rectangular => self.next_noise() - 0.5,
+            Dither::Triangular => self.next_noise() - self.next_noise(),
+        };
+
+        let n = round(value * SCALE + noise);
+        if !(SAMPLE_MIN..=SAMPLE_MAX).contains(&n) {
+            self.clipped += 1;
+        }
+
+        sample_from_i32(n)
+    }
+
+    ///
+    /// Converts every f32 of src into a sample.
+    ///
+    /// Panics if both slices have a different length.
+    ///
+    pub fn quantize_slice(&mut self, src: &[f32], dst: &mut [u24]) {
+        assert_eq!(src.len(), dst.len(), "source and destination have a different length");
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = self.quantize(*s);
+        }
+    }
+
+    ///
+    /// Uniformly distributed value in [0, 1) produced by xorshift32.
+    ///
+    #[inline]
+    fn next_noise(&mut self) -> f32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        (x >> 8) as f32 / (1u32 << 24) as f32
+    }
+}
+
+///
+/// Splits interleaved frames into one slice per channel.
+/// Sample i of channel c is taken from frames[i * channels.len() + c].
+///
+/// Panics if the length of frames is not channels.len() times the length of every channel.
+///
+pub fn deinterleave<T: Copy>(frames: &[T], channels: &mut [&mut [T]]) {
+    let count = channels.len();
+    assert!(count > 0, "at least one channel is required");
+    for channel in channels.iter() {
+        assert_eq!(channel.len() * count, frames.len(), "channel length does not match the amount of frames");
+    }
+
+    for (i, frame) in frames.chunks_exact(count).enumerate() {
+        for (channel, sample) in channels.iter_mut().zip(frame) {
+            channel[i] = *sample;
+        }
+    }
+}
+
+///
+/// Joins one slice per channel into interleaved frames.
+/// Sample i of channel c is written to frames[i * channels.len() + c].
+///
+/// Panics if the length of frames is not channels.len() times the length of every channel.
+///
+pub fn interleave<T: Copy>(channels: &[&[T]], frames: &mut [T]) {
+    let count = channels.len();
+    assert!(count > 0, "at least one channel is required");
+    for channel in channels {
+        assert_eq!(channel.len() * count, frames.len(), "channel length does not match the amount of frames");
+    }
+
+    for (i, frame) in frames.chunks_exact_mut(count).enumerate() {
+        for (sample, channel) in frame.iter_mut().zip(channels) {
+            *sample = channel[i];
+        }
+    }
+}
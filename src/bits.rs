@@ -0,0 +1,717 @@
+//!
+//! Bit granular reading and writing of unaligned integers.
+//!
+//! This is useful for bit packed container formats that store fields at offsets that
+//! are not a multiple of 8 bits.
+//!
+use crate::{u104, u112, u120, u24, u40, u48, u56, u72, u80, u88, u96, UnalignedUint};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+///
+/// Order in which the bits of a byte are consumed or produced.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BitOrder {
+    ///
+    /// The most significant bit of each byte comes first.
+    /// The first bit of a value is its most significant bit.
+    /// This is the order used by most video codec bitstreams.
+    ///
+    #[default]
+    MsbFirst,
+    ///
+    /// The least significant bit of each byte comes first.
+    /// The first bit of a value is its least significant bit.
+    /// This is the order used by deflate for example.
+    ///
+    LsbFirst,
+}
+
+///
+/// Error returned by BitReader and BitWriter.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BitError {
+    ///
+    /// More bits were requested than are left in the buffer.
+    ///
+    Underrun { requested: usize, available: usize },
+    ///
+    /// More bits were written than fit into the buffer.
+    ///
+    Overflow { requested: usize, available: usize },
+    ///
+    /// More bits were requested than fit into the target type.
+    ///
+    TooManyBits { requested: u32, max: u32 },
+}
+
+impl core::fmt::Display for BitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BitError::Underrun { requested, available } => write!(
+                f,
+                "bit buffer underrun: {} bits requested but only {} bits available",
+                requested, available
+            ),
+            BitError::Overflow { requested, available } => write!(
+                f,
+                "bit buffer overflow: {} bits written but only {} bits of space available",
+                requested, available
+            ),
+            BitError::TooManyBits { requested, max } => write!(
+                f,
+                "{} bits requested but the type only holds {} bits",
+                requested, max
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitError {}
+
+#[inline]
+const fn low_mask(bits: u32) -> u8 {
+    ((1u16 << bits) - 1) as u8
+}
+
+#[inline]
+fn check_width<T: UnalignedUint>(n: u32) -> Result<(), BitError> {
+    if n as usize > T::NUM_BITS {
+        return Err(BitError::TooManyBits {
+            requested: n,
+            max: T::NUM_BITS as u32,
+        });
+    }
+
+    Ok(())
+}
+
+macro_rules! read_fn {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Reads a ", stringify!($ty), " using all of its bits.")]
+        #[inline]
+        pub fn $name(&mut self) -> Result<$ty, BitError> {
+            self.read_bits::<$ty>(<$ty>::NUM_BITS as u32)
+        }
+    };
+}
+
+macro_rules! write_fn {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Writes a ", stringify!($ty), " using all of its bits.")]
+        #[inline]
+        pub fn $name(&mut self, value: $ty) -> Result<(), BitError> {
+            self.write_bits::<$ty>(value, <$ty>::NUM_BITS as u32)
+        }
+    };
+}
+
+///
+/// Reads unaligned integers from arbitrary bit offsets of a byte slice.
+///
+#[derive(Clone, Debug)]
+pub struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    order: BitOrder,
+}
+
+impl<'a> BitReader<'a> {
+    ///
+    /// Creates a new reader that reads the bits in MSB first order.
+    ///
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self::with_order(data, BitOrder::MsbFirst)
+    }
+
+    ///
+    /// Creates a new reader that reads the bits in the given order.
+    ///
+    pub const fn with_order(data: &'a [u8], order: BitOrder) -> Self {
+        BitReader {
+            data,
+            position: 0,
+            order,
+        }
+    }
+
+    ///
+    /// Returns the bit order of this reader.
+    ///
+    #[inline]
+    pub const fn order(&self) -> BitOrder {
+        self.order
+    }
+
+    ///
+    /// Returns the amount of bits read so far.
+    ///
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    ///
+    /// Returns the amount of bits that are left to read.
+    ///
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.data.len() * 8 - self.position
+    }
+
+    ///
+    /// Returns true if the next bit that would be read is the first bit of a byte.
+    ///
+    #[inline]
+    pub const fn is_aligned(&self) -> bool {
+        self.position.is_multiple_of(8)
+    }
+
+    ///
+    /// Skips the rest of the current byte so the next read starts at a byte boundary.
+    /// Does nothing if the reader is already aligned.
+    ///
+    #[inline]
+    pub fn align(&mut self) {
+        self.position = self.position.next_multiple_of(8);
+    }
+
+    ///
+    /// Skips the given amount of bits.
+    ///
+    pub fn skip(&mut self, bits: usize) -> Result<(), BitError> {
+        self.check_remaining(bits)?;
+        self.position += bits;
+        Ok(())
+    }
+
+    ///
+    /// Moves the reader to an absolute bit position.
+    ///
+    pub fn seek(&mut self, bit_position: usize) -> Result<(), BitError> {
+        if bit_position > self.data.len() * 8 {
+            return Err(BitError::Underrun {
+                requested: bit_position,
+                available: self.data.len() * 8,
+            });
+        }
+
+        self.position = bit_position;
+        Ok(())
+    }
+
+    ///
+    /// Reads a single bit.
+    ///
+    pub fn read_bit(&mut self) -> Result<bool, BitError> {
+        Ok(self.read_raw(1)? != 0)
+    }
+
+    ///
+    /// Reads n bits into the given type.
+    /// Fails if n is larger than T::NUM_BITS or if less than n bits are left.
+    ///
+    pub fn read_bits<T: UnalignedUint>(&mut self, n: u32) -> Result<T, BitError> {
+        check_width::<T>(n)?;
+        if self.is_aligned() && n as usize == T::NUM_BITS {
+            self.check_remaining(T::NUM_BITS)?;
+            let start = self.position / 8;
+            let mut bytes = T::Bytes::default();
+            bytes
+                .as_mut()
+                .copy_from_slice(&self.data[start..start + T::BYTES]);
+            self.position += T::NUM_BITS;
+            return Ok(match self.order {
+                BitOrder::MsbFirst => T::from_be_bytes(bytes),
+                BitOrder::LsbFirst => T::from_le_bytes(bytes),
+            });
+        }
+
+        Ok(T::from_u128_truncated(self.read_raw(n)?))
+    }
+
+    read_fn!(read_u24, u24);
+    read_fn!(read_u40, u40);
+    read_fn!(read_u48, u48);
+    read_fn!(read_u56, u56);
+    read_fn!(read_u72, u72);
+    read_fn!(read_u80, u80);
+    read_fn!(read_u88, u88);
+    read_fn!(read_u96, u96);
+    read_fn!(read_u104, u104);
+    read_fn!(read_u112, u112);
+    read_fn!(read_u120, u120);
+
+    #[inline]
+    fn check_remaining(&self, bits: usize) -> Result<(), BitError> {
+        if bits > self.remaining() {
+            return Err(BitError::Underrun {
+                requested: bits,
+                available: self.remaining(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn read_raw(&mut self, n: u32) -> Result<u128, BitError> {
+        debug_assert!(n <= 128);
+        self.check_remaining(n as usize)?;
+
+        let mut result = 0u128;
+        let mut done = 0u32;
+        while done < n {
+            let byte = self.data[self.position / 8];
+            let bit_in_byte = (self.position % 8) as u32;
+            let available = 8 - bit_in_byte;
+            let take = available.min(n - done);
+
+            match self.order {
+                BitOrder::MsbFirst => {
+                    let chunk = (byte >> (available - take)) & low_mask(take);
+                    result = (result << take) | chunk as u128;
+                }
+                BitOrder::LsbFirst => {
+                    let chunk = (byte >> bit_in_byte) & low_mask(take);
+                    result |= (chunk as u128) << done;
+                }
+            }
+
+            done += take;
+            self.position += take as usize;
+        }
+
+        Ok(result)
+    }
+}
+
+enum BitSink<'a> {
+    Slice(&'a mut [u8]),
+    #[cfg(feature = "alloc")]
+    Vec(&'a mut Vec<u8>, usize),
+}
+
+///
+/// Writes unaligned integers to arbitrary bit offsets of a byte buffer.
+///
+/// The buffer is either a fixed size slice or, with the alloc feature, a Vec that grows as needed.
+/// Bits that are not written to are left unchanged in a slice and are zero in a Vec.
+///
+pub struct BitWriter<'a> {
+    sink: BitSink<'a>,
+    position: usize,
+    order: BitOrder,
+}
+
+impl<'a> BitWriter<'a> {
+    ///
+    /// Creates a new writer that writes into the slice in MSB first order.
+    ///
+    pub fn new(data: &'a mut [u8]) -> Self {
+        Self::with_order(data, BitOrder::MsbFirst)
+    }
+
+    ///
+    /// Creates a new writer that writes into the slice in the given order.
+    ///
+    pub fn with_order(data: &'a mut [u8], order: BitOrder) -> Self {
+        BitWriter {
+            sink: BitSink::Slice(data),
+            position: 0,
+            order,
+        }
+    }
+
+    ///
+    /// Creates a new writer that appends to the Vec in MSB first order.
+    ///
+    #[cfg(feature = "alloc")]
+    pub fn from_vec(data: &'a mut Vec<u8>) -> Self {
+        Self::from_vec_with_order(data, BitOrder::MsbFirst)
+    }
+
+    ///
+    /// Creates a new writer that appends to the Vec in the given order.
+    ///
+    #[cfg(feature = "alloc")]
+    pub fn from_vec_with_order(data: &'a mut Vec<u8>, order: BitOrder) -> Self {
+        let start = data.len();
+        BitWriter {
+            sink: BitSink::Vec(data, start),
+            position: 0,
+            order,
+        }
+    }
+
+    ///
+    /// Returns the bit order of this writer.
+    ///
+    #[inline]
+    pub const fn order(&self) -> BitOrder {
+        self.order
+    }
+
+    ///
+    /// Returns the amount of bits written so far.
+    ///
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    ///
+    /// Returns the amount of bytes touched so far. A partially written byte counts as a whole byte.
+    ///
+    #[inline]
+    pub const fn bytes_written(&self) -> usize {
+        self.position.div_ceil(8)
+    }
+
+    ///
+    /// Returns the amount of bits that still fit into the buffer.
+    /// This is usize::MAX for a writer backed by a Vec.
+    ///
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        match &self.sink {
+            BitSink::Slice(data) => data.len() * 8 - self.position,
+            #[cfg(feature = "alloc")]
+            BitSink::Vec(_, _) => usize::MAX,
+        }
+    }
+
+    ///
+    /// Returns true if the next bit that would be written is the first bit of a byte.
+    ///
+    #[inline]
+    pub const fn is_aligned(&self) -> bool {
+        self.position.is_multiple_of(8)
+    }
+
+    ///
+    /// Pads the current byte with zero bits so the next write starts at a byte boundary.
+    /// Does nothing if the writer is already aligned.
+    ///
+    pub fn align(&mut self) {
+        let padding = self.position.next_multiple_of(8) - self.position;
+        if padding != 0 {
+            //Can't fail, the partially written byte is always part of the buffer.
+            let _ = self.write_raw(0, padding as u32);
+        }
+    }
+
+    ///
+    /// Writes a single bit.
+    ///
+    pub fn write_bit(&mut self, bit: bool) -> Result<(), BitError> {
+        self.write_raw(bit as u128, 1)
+    }
+
+    ///
+    /// Writes the lowest n bits of the value.
+    /// Fails if n is larger than T::NUM_BITS or if less than n bits of space are left.
+    ///
+    pub fn write_bits<T: UnalignedUint>(&mut self, value: T, n: u32) -> Result<(), BitError> {
+        check_width::<T>(n)?;
+        if self.is_aligned() && n as usize == T::NUM_BITS {
+            let bytes = match self.order {
+                BitOrder::MsbFirst => value.to_be_bytes(),
+                BitOrder::LsbFirst => value.to_le_bytes(),
+            };
+            let start = self.position / 8;
+            self.bytes_mut(T::NUM_BITS)?[start..start + T::BYTES].copy_from_slice(bytes.as_ref());
+            self.position += T::NUM_BITS;
+            return Ok(());
+        }
+
+        self.write_raw(value.as_u128(), n)
+    }
+
+    write_fn!(write_u24, u24);
+    write_fn!(write_u40, u40);
+    write_fn!(write_u48, u48);
+    write_fn!(write_u56, u56);
+    write_fn!(write_u72, u72);
+    write_fn!(write_u80, u80);
+    write_fn!(write_u88, u88);
+    write_fn!(write_u96, u96);
+    write_fn!(write_u104, u104);
+    write_fn!(write_u112, u112);
+    write_fn!(write_u120, u120);
+
+    ///
+    /// Returns the underlying bytes after making sure that the next `bits` bits fit into them.
+    /// Indices are relative to the start of this writer.
+    ///
+    fn bytes_mut(&mut self, bits: usize) -> Result<&mut [u8], BitError> {
+        let needed = (self.position + bits).div_ceil(8);
+        match &mut self.sink {
+            BitSink::Slice(data) => {
+                if needed > data.len() {
+                    return Err(BitError::Overflow {
+                        requested: bits,
+                        available: data.len() * 8 - self.position,
+                    });
+                }
+
+                Ok(&mut data[..])
+            }
+            #[cfg(feature = "alloc")]
+            BitSink::Vec(data, start) => {
+                if data.len() < *start + needed {
+                    data.resize(*start + needed, 0);
+                }
+
+                Ok(&mut data[*start..])
+            }
+        }
+    }
+
+    fn write_raw(&mut self, value: u128, n: u32) -> Result<(), BitError> {
+        debug_assert!(n <= 128);
+        let order = self.order;
+        let mut position = self.position;
+        let data = self.bytes_mut(n as usize)?;
+
+        let mut done = 0u32;
+        while done < n {
+            let bit_in_byte = (position % 8) as u32;
+            let available = 8 - bit_in_byte;
+            let take = available.min(n - done);
+
+            let (chunk, shift) = match order {
+                BitOrder::MsbFirst => (
+                    (value >> (n - done - take)) as u8 & low_mask(take),
+                    available - take,
+                ),
+                BitOrder::LsbFirst => ((value >> done) as u8 & low_mask(take), bit_in_byte),
+            };
+
+            let byte = &mut data[position / 8];
+            *byte = (*byte & !(low_mask(take) << shift)) | (chunk << shift);
+
+            done += take;
+            position += take as usize;
+        }
+
+        self.position = position;
+        Ok(())
+    }
+}
+
+///
+/// Conversion between the type of a bitfield! field and the raw bits stored in the field.
+///
+/// Implemented for bool, the unsigned primitives and all types of this crate.
+/// Implement it for own types, like enums, to use them as field types.
+///
+pub trait BitfieldValue: Sized {
+    ///
+    /// Largest amount of bits a value can have. bitfield! rejects wider fields at compile time.
+    ///
+    const BITS: u32;
+
+    ///
+    /// Creates the value from the raw field bits, which never exceed BITS bits.
+    ///
+    fn from_bitfield(bits: u128) -> Self;
+
+    ///
+    /// Returns the raw bits of the value.
+    ///
+    fn into_bitfield(self) -> u128;
+}
+
+impl BitfieldValue for bool {
+    const BITS: u32 = 1;
+
+    #[inline]
+    fn from_bitfield(bits: u128) -> Self {
+        bits != 0
+    }
+
+    #[inline]
+    fn into_bitfield(self) -> u128 {
+        self as u128
+    }
+}
+
+macro_rules! bitfield_value_primitive {
+    ($($ty:ty),*) => {
+        $(
+            impl BitfieldValue for $ty {
+                const BITS: u32 = <$ty>::BITS;
+
+                #[inline]
+                fn from_bitfield(bits: u128) -> Self {
+                    bits as $ty
+                }
+
+                #[inline]
+                fn into_bitfield(self) -> u128 {
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+
+bitfield_value_primitive!(u8, u16, u32, u64, u128);
+
+impl<T: UnalignedUint> BitfieldValue for T {
+    const BITS: u32 = T::NUM_BITS as u32;
+
+    #[inline]
+    fn from_bitfield(bits: u128) -> Self {
+        T::from_u128_truncated(bits)
+    }
+
+    #[inline]
+    fn into_bitfield(self) -> u128 {
+        self.as_u128()
+    }
+}
+
+///
+/// Declares a newtype over one of the integer types of this crate with named, typed field accessors.
+///
+/// Every field is declared as `getter, setter: Type = start..end;` where bit 0 is the least significant bit.
+/// The compiler rejects fields that end beyond NUM_BITS of the storage type, empty or reversed ranges
+/// and ranges that are wider than the field type. Setters panic if the value does not fit into the range.
+///
+/// ```
+/// uintx::bitfield! {
+///     /// 24 bit header of a packet.
+///     pub struct Header(uintx::u24) {
+///         pub version, set_version: u8 = 0..4;
+///         pub length, set_length: u16 = 4..16;
+///         pub urgent, set_urgent: bool = 23..24;
+///     }
+/// }
+///
+/// let mut header = Header::new();
+/// header.set_version(3);
+/// header.set_length(1500);
+/// header.set_urgent(true);
+/// assert_eq!(header.version(), 3);
+/// assert_eq!(header.length(), 1500);
+/// assert_eq!(header.into_bits().as_num(), 0x80_5DC3);
+/// ```
+///
+/// Fields that do not fit into the storage type do not compile:
+/// ```compile_fail
+/// uintx::bitfield! {
+///     struct TooLong(uintx::u24) {
+///         value, set_value: u8 = 20..25;
+///     }
+/// }
+/// ```
+///
+/// Neither do fields that are wider than their type:
+/// ```compile_fail
+/// uintx::bitfield! {
+///     struct TooWide(uintx::u24) {
+///         value, set_value: u8 = 0..9;
+///     }
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! bitfield {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($ty:ty) {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $getter:ident, $setter:ident : $field_ty:ty = $start:literal .. $end:literal;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        #[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+        $vis struct $name($ty);
+
+        const _: () = {
+            $(
+                assert!($start < $end, concat!("bit range of field ", stringify!($getter), " is empty"));
+                assert!(
+                    $end <= <$ty>::NUM_BITS as u32,
+                    concat!("field ", stringify!($getter), " does not fit into ", stringify!($ty))
+                );
+                assert!(
+                    $end - $start <= <$field_ty as $crate::bits::BitfieldValue>::BITS,
+                    concat!("field ", stringify!($getter), " is wider than ", stringify!($field_ty))
+                );
+            )*
+        };
+
+        #[allow(dead_code)]
+        impl $name {
+            ///
+            /// Creates the value with all bits set to 0.
+            ///
+            #[inline]
+            pub const fn new() -> Self {
+                $name(<$ty>::MIN)
+            }
+
+            #[inline]
+            pub const fn from_bits(bits: $ty) -> Self {
+                $name(bits)
+            }
+
+            #[inline]
+            pub const fn into_bits(self) -> $ty {
+                self.0
+            }
+
+            $(
+                $(#[$field_meta])*
+                #[inline]
+                $field_vis fn $getter(&self) -> $field_ty {
+                    let bits = self.0.get_bits($start..$end);
+                    <$field_ty as $crate::bits::BitfieldValue>::from_bitfield(bits.into())
+                }
+
+                $(#[$field_meta])*
+                #[inline]
+                $field_vis fn $setter(&mut self, value: $field_ty) {
+                    let bits = <$field_ty as $crate::bits::BitfieldValue>::into_bitfield(value);
+                    assert!(
+                        bits >> ($end - $start) == 0,
+                        concat!("value does not fit into field ", stringify!($getter))
+                    );
+                    //The check above guarantees that bits fits into the range and thus into the type.
+                    let raw = <$ty as $crate::UnalignedUint>::from_u128_truncated(bits);
+                    self.0.set_bits($start..$end, raw.as_num());
+                }
+            )*
+        }
+
+        impl From<$ty> for $name {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                $name(value)
+            }
+        }
+
+        impl From<$name> for $ty {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $(.field(stringify!($getter), &self.$getter()))*
+                    .finish()
+            }
+        }
+    };
+}
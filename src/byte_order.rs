@@ -0,0 +1,86 @@
+//!
+//! Integration with the byteorder crate.
+//!
+use crate::UnalignedUint;
+use byteorder::ByteOrder;
+
+#[inline]
+fn is_big_endian<B: ByteOrder>() -> bool {
+    B::read_u16(&[0, 1]) == 1
+}
+
+///
+/// Extends every byteorder::ByteOrder (BigEndian, LittleEndian, ...) with
+/// methods for reading and writing the unaligned integers of this crate.
+///
+/// Like the methods of ByteOrder these methods panic if the buffers are too small.
+///
+pub trait UintxByteOrder: ByteOrder {
+    ///
+    /// Reads an unaligned integer from the start of buf.
+    /// Panics if buf.len() < T::BYTES.
+    ///
+    #[inline]
+    fn read_uintx<T: UnalignedUint>(buf: &[u8]) -> T {
+        let mut bytes = T::Bytes::default();
+        bytes.as_mut().copy_from_slice(&buf[..T::BYTES]);
+        if is_big_endian::<Self>() {
+            T::from_be_bytes(bytes)
+        } else {
+            T::from_le_bytes(bytes)
+        }
+    }
+
+    ///
+    /// Writes an unaligned integer to the start of buf.
+    /// Panics if buf.len() < T::BYTES.
+    ///
+    #[inline]
+    fn write_uintx<T: UnalignedUint>(buf: &mut [u8], n: T) {
+        let bytes = if is_big_endian::<Self>() {
+            n.to_be_bytes()
+        } else {
+            n.to_le_bytes()
+        };
+        buf[..T::BYTES].copy_from_slice(bytes.as_ref());
+    }
+
+    ///
+    /// Reads unaligned integers from src into dst.
+    /// Panics if src.len() != dst.len() * T::BYTES.
+    ///
+    fn read_uintx_into<T: UnalignedUint>(src: &[u8], dst: &mut [T]) {
+        assert_eq!(src.len(), dst.len() * T::BYTES, "buffer length mismatch");
+        T::slice_as_bytes_mut(dst).copy_from_slice(src);
+        Self::from_slice_uintx(dst);
+    }
+
+    ///
+    /// Writes unaligned integers from src into dst.
+    /// Panics if dst.len() != src.len() * T::BYTES.
+    ///
+    fn write_uintx_into<T: UnalignedUint>(src: &[T], dst: &mut [u8]) {
+        assert_eq!(dst.len(), src.len() * T::BYTES, "buffer length mismatch");
+        for (n, chunk) in src.iter().zip(dst.chunks_exact_mut(T::BYTES)) {
+            Self::write_uintx(chunk, *n);
+        }
+    }
+
+    ///
+    /// Converts the unaligned integers of the slice in place from this byte order to the native byte order.
+    /// Does nothing if this byte order is the native byte order.
+    ///
+    fn from_slice_uintx<T: UnalignedUint>(numbers: &mut [T]) {
+        if is_big_endian::<Self>() == cfg!(target_endian = "big") {
+            return;
+        }
+
+        for n in numbers.iter_mut() {
+            let mut bytes = n.to_ne_bytes();
+            bytes.as_mut().reverse();
+            *n = T::from_ne_bytes(bytes);
+        }
+    }
+}
+
+impl<B: ByteOrder> UintxByteOrder for B {}
@@ -0,0 +1,390 @@
+//!
+//! Delta and frame of reference bit packing for columns of unaligned integers.
+//!
+//! The values are split into blocks of BLOCK_LEN values. Every block starts at a byte boundary with a header
+//! that holds a reference value (T::BYTES bytes) and the bit width of the packed values (1 byte):
+//!
+//! * Delta: the reference is the first value of the block, the other values are stored as the difference
+//!   to their predecessor. Sorted columns like postings lists produce small differences.
+//! * FrameOfReference: the reference is the smallest value of the block, all values are stored as
+//!   the difference to it. This works for unsorted columns whose values are close together.
+//!
+//! Differences are computed with wrapping subtraction, so every input round trips,
+//! unsorted input just packs worse. The byte offset of every block is kept, so decoding can start at any block.
+//!
+//! ```
+//! use uintx::codec::{PackedBlocks, Transform};
+//! use uintx::u40;
+//!
+//! let postings: Vec<u40> = (0..1000u64).map(|i| u40::try_from(0x10_0000_0000 + i * 3).unwrap()).collect();
+//! let packed = PackedBlocks::encode(&postings, Transform::Delta);
+//! assert!(packed.as_bytes().len() < postings.len());
+//! assert_eq!(packed.block_first(2), postings[256]);
+//!
+//! let mut decoded = vec![u40::MIN; postings.len()];
+//! packed.decode(&mut decoded);
+//! assert_eq!(decoded, postings);
+//! ```
+//!
+use crate::bits::{BitError, BitReader, BitWriter};
+use crate::UnalignedUint;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+///
+/// Number of values in every block except the last one.
+///
+pub const BLOCK_LEN: usize = 128;
+
+///
+/// How the values of a block are turned into small numbers before they are bit packed.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Transform {
+    ///
+    /// Differences between consecutive values, for sorted columns.
+    ///
+    #[default]
+    Delta,
+    ///
+    /// Differences to the smallest value of the block.
+    ///
+    FrameOfReference,
+}
+
+#[inline]
+fn bit_width<T: UnalignedUint>(value: T) -> u32 {
+    128 - value.as_u128().leading_zeros()
+}
+
+///
+/// Number of packed values in a block of len values.
+///
+#[inline]
+const fn packed_count(transform: Transform, len: usize) -> usize {
+    match transform {
+        Transform::Delta => len - 1,
+        Transform::FrameOfReference => len,
+    }
+}
+
+///
+/// Size of an encoded block in bytes.
+///
+#[inline]
+fn block_size<T: UnalignedUint>(transform: Transform, len: usize, width: u32) -> usize {
+    T::BYTES + 1 + (packed_count(transform, len) * width as usize).div_ceil(8)
+}
+
+///
+/// Returns the reference value and the bit width of a block.
+///
+fn analyze<T: UnalignedUint>(block: &[T], transform: Transform) -> (T, u32) {
+    match transform {
+        Transform::Delta => {
+            let width = block.windows(2).map(|w| bit_width(w[1].wrapping_sub(w[0]))).max().unwrap_or(0);
+            (block[0], width)
+        }
+        Transform::FrameOfReference => {
+            let reference = *block.iter().min().unwrap();
+            let width = block.iter().map(|v| bit_width(v.wrapping_sub(reference))).max().unwrap();
+            (reference, width)
+        }
+    }
+}
+
+///
+/// A column of unaligned integers compressed into bit packed blocks.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PackedBlocks<T: UnalignedUint> {
+    transform: Transform,
+    len: usize,
+    bytes: Vec<u8>,
+    offsets: Vec<usize>,
+    marker: PhantomData<T>,
+}
+
+impl<T: UnalignedUint> PackedBlocks<T> {
+    ///
+    /// Compresses the values with the given transform.
+    ///
+    pub fn encode(values: &[T], transform: Transform) -> Self {
+        let layout: Vec<(T, u32)> = values.chunks(BLOCK_LEN).map(|block| analyze(block, transform)).collect();
+
+        let mut offsets = Vec::with_capacity(layout.len());
+        let mut size = 0;
+        for (block, (_, width)) in values.chunks(BLOCK_LEN).zip(&layout) {
+            offsets.push(size);
+            size += block_size::<T>(transform, block.len(), *width);
+        }
+
+        let mut bytes = vec![0u8; size];
+        let mut writer = BitWriter::new(&mut bytes);
+        //The buffer has exactly the computed size and every width fits into T, so no write can fail.
+        for (block, (reference, width)) in values.chunks(BLOCK_LEN).zip(layout) {
+            writer.write_bits(reference, T::NUM_BITS as u32).unwrap();
+            writer.write_bits(T::from_u128_truncated(width as u128), 8).unwrap();
+            match transform {
+                Transform::Delta => {
+                    for w in block.windows(2) {
+                        writer.write_bits(w[1].wrapping_sub(w[0]), width).unwrap();
+                    }
+                }
+                Transform::FrameOfReference => {
+                    for v in block {
+                        writer.write_bits(v.wrapping_sub(reference), width).unwrap();
+                    }
+                }
+            }
+            writer.align();
+        }
+
+        PackedBlocks { transform, len: values.len(), bytes, offsets, marker: PhantomData }
+    }
+
+    ///
+    /// Restores the compressed column from the bytes returned by as_bytes.
+    /// transform and len must be the same as for the encoded column.
+    /// Fails if the bytes are too short or contain an invalid bit width. Bytes after the last block are discarded.
+    ///
+    pub fn from_bytes(mut bytes: Vec<u8>, transform: Transform, len: usize) -> Result<Self, BitError> {
+        //len is not validated yet, every block takes at least its header, so the byte count bounds the block count.
+        let mut offsets = Vec::with_capacity(len.div_ceil(BLOCK_LEN).min(bytes.len() / (T::BYTES + 1)));
+        let mut reader = BitReader::new(&bytes);
+        let mut remaining = len;
+        while remaining > 0 {
+            let block_len = remaining.min(BLOCK_LEN);
+            offsets.push(reader.position() / 8);
+            reader.skip(T::NUM_BITS)?;
+            let width = reader.read_bits::<T>(8)?.as_u128() as u32;
+            if width as usize > T::NUM_BITS {
+                return Err(BitError::TooManyBits { requested: width, max: T::NUM_BITS as u32 });
+            }
+
+            reader.skip(packed_count(transform, block_len) * width as usize)?;
+            reader.align();
+            remaining -= block_len;
+        }
+
+        let end = reader.position() / 8;
+        bytes.truncate(end);
+        Ok(PackedBlocks { transform, len, bytes, offsets, marker: PhantomData })
+    }
+
+    ///
+    /// The transform the column was encoded with.
+    ///
+    #[inline]
+    pub const fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    ///
+    /// Number of values.
+    ///
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    ///
+    /// True if the column contains no values.
+    ///
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///
+    /// The compressed blocks.
+    ///
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    ///
+    /// Number of blocks.
+    ///
+    #[inline]
+    pub fn block_count(&self) -> usize {
+        self.offsets.len()
+    }
+
+    ///
+    /// Number of values in a block, this is BLOCK_LEN for every block but the last.
+    /// Panics if block is out of bounds.
+    ///
+    #[inline]
+    pub fn block_len(&self, block: usize) -> usize {
+        assert!(block < self.block_count(), "block {} out of bounds, there are {} blocks", block, self.block_count());
+        (self.len - block * BLOCK_LEN).min(BLOCK_LEN)
+    }
+
+    ///
+    /// Byte offset of a block in as_bytes.
+    /// Panics if block is out of bounds.
+    ///
+    #[inline]
+    pub fn block_offset(&self, block: usize) -> usize {
+        self.offsets[block]
+    }
+
+    ///
+    /// Returns the first value of a block without decoding the rest of it.
+    /// For sorted columns this allows a binary search over the blocks.
+    /// Panics if block is out of bounds.
+    ///
+    pub fn block_first(&self, block: usize) -> T {
+        let mut reader = BitReader::new(&self.bytes[self.offsets[block]..]);
+        let reference = reader.read_bits::<T>(T::NUM_BITS as u32).unwrap();
+        match self.transform {
+            Transform::Delta => reference,
+            Transform::FrameOfReference => {
+                let width = reader.read_bits::<T>(8).unwrap().as_u128() as u32;
+                reference.wrapping_add(reader.read_bits::<T>(width).unwrap())
+            }
+        }
+    }
+
+    ///
+    /// Decodes a block into the start of out and returns the number of values written.
+    /// Panics if block is out of bounds or out is shorter than block_len(block).
+    ///
+    pub fn decode_block(&self, block: usize, out: &mut [T]) -> usize {
+        let len = self.block_len(block);
+        let out = &mut out[..len];
+        //The layout was validated when the column was created, so no read can fail.
+        let mut reader = BitReader::new(&self.bytes[self.offsets[block]..]);
+        let reference = reader.read_bits::<T>(T::NUM_BITS as u32).unwrap();
+        let width = reader.read_bits::<T>(8).unwrap().as_u128() as u32;
+        match self.transform {
+            Transform::Delta => {
+                let mut current = reference;
+                out[0] = current;
+                for v in &mut out[1..] {
+                    current = current.wrapping_add(reader.read_bits::<T>(width).unwrap());
+                    *v = current;
+                }
+            }
+            Transform::FrameOfReference => {
+                for v in out.iter_mut() {
+                    *v = reference.wrapping_add(reader.read_bits::<T>(width).unwrap());
+                }
+            }
+        }
+
+        len
+    }
+
+    ///
+    /// Decodes all values into out.
+    /// Panics if out.len() != len().
+    ///
+    pub fn decode(&self, out: &mut [T]) {
+        assert_eq!(out.len(), self.len, "output length mismatch");
+        for (block, chunk) in out.chunks_mut(BLOCK_LEN).enumerate() {
+            self.decode_block(block, chunk);
+        }
+    }
+
+    ///
+    /// Decodes all values into a new Vec.
+    ///
+    pub fn to_vec(&self) -> Vec<T> {
+        let mut out = vec![T::MIN; self.len];
+        self.decode(&mut out);
+        out
+    }
+
+    ///
+    /// Returns a decoder that starts at the first block.
+    ///
+    #[inline]
+    pub fn decoder(&self) -> Decoder<'_, T> {
+        Decoder { blocks: self, next_block: 0, buffer: [T::MIN; BLOCK_LEN], buffered: 0..0 }
+    }
+}
+
+///
+/// Decodes a PackedBlocks column block by block into caller provided buffers.
+///
+#[derive(Clone, Debug)]
+pub struct Decoder<'a, T: UnalignedUint> {
+    blocks: &'a PackedBlocks<T>,
+    next_block: usize,
+    buffer: [T; BLOCK_LEN],
+    buffered: core::ops::Range<usize>,
+}
+
+impl<T: UnalignedUint> Decoder<'_, T> {
+    ///
+    /// Continues decoding at the start of a block, discarding any buffered values.
+    /// Panics if block is larger than the number of blocks.
+    ///
+    pub fn seek_block(&mut self, block: usize) {
+        assert!(block <= self.blocks.block_count(), "block {} out of bounds", block);
+        self.next_block = block;
+        self.buffered = 0..0;
+    }
+
+    ///
+    /// Number of values that have not been decoded yet.
+    ///
+    pub fn remaining(&self) -> usize {
+        let decoded_blocks = (self.next_block * BLOCK_LEN).min(self.blocks.len);
+        self.blocks.len - decoded_blocks + self.buffered.len()
+    }
+
+    ///
+    /// Decodes as many values as fit into out and returns how many were written.
+    /// Returns 0 once all values are decoded.
+    ///
+    pub fn decode_into(&mut self, out: &mut [T]) -> usize {
+        let mut written = 0;
+        while written < out.len() {
+            if !self.buffered.is_empty() {
+                let count = self.buffered.len().min(out.len() - written);
+                let start = self.buffered.start;
+                out[written..written + count].copy_from_slice(&self.buffer[start..start + count]);
+                self.buffered.start += count;
+                written += count;
+                continue;
+            }
+
+            if self.next_block == self.blocks.block_count() {
+                break;
+            }
+
+            let block_len = self.blocks.block_len(self.next_block);
+            if out.len() - written >= block_len {
+                written += self.blocks.decode_block(self.next_block, &mut out[written..]);
+            } else {
+                self.buffered = 0..self.blocks.decode_block(self.next_block, &mut self.buffer);
+            }
+            self.next_block += 1;
+        }
+
+        written
+    }
+}
+
+impl<T: UnalignedUint> Iterator for Decoder<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let mut value = [T::MIN];
+        match self.decode_into(&mut value) {
+            0 => None,
+            _ => Some(value[0]),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining(), Some(self.remaining()))
+    }
+}
+
+impl<T: UnalignedUint> ExactSizeIterator for Decoder<'_, T> {}
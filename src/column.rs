@@ -0,0 +1,237 @@
+//!
+//! Integer columns stored in the narrowest width that holds their maximum value.
+//!
+//! NarrowedColumn inspects the values once, picks the smallest of u8, u16, u24, u32, u40 ... u120, u128
+//! that fits the maximum and stores the values in a vector of that type.
+//! The encoded form is a single tag byte holding the width in bytes followed by the values in little endian byte order.
+//!
+//! ```
+//! use uintx::column::NarrowedColumn;
+//!
+//! let column = NarrowedColumn::from_slice(&[7u64, 0x12_3456_7890, 3]);
+//! assert_eq!(column.width(), 5);
+//! assert!(matches!(column, NarrowedColumn::U40(_)));
+//! assert_eq!(column.get(1), 0x12_3456_7890);
+//!
+//! let bytes = column.to_bytes();
+//! assert_eq!(bytes.len(), 1 + 3 * 5);
+//! assert_eq!(NarrowedColumn::from_bytes(&bytes), Ok(column));
+//! ```
+//!
+use crate::{u104, u112, u120, u24, u40, u48, u56, u72, u80, u88, u96};
+use alloc::vec::Vec;
+use core::fmt;
+
+///
+/// Error returned when decoding a NarrowedColumn from bytes fails.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ColumnDecodeError {
+    ///
+    /// The input does not even contain the width tag.
+    ///
+    Empty,
+    ///
+    /// The width tag is not one of the supported widths 1 to 16.
+    ///
+    InvalidWidth { width: u8 },
+    ///
+    /// The length of the data after the tag is not a multiple of the width.
+    ///
+    InvalidLength { len: usize, width: u8 },
+}
+
+impl fmt::Display for ColumnDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColumnDecodeError::Empty => write!(f, "column data is empty, expected a width tag"),
+            ColumnDecodeError::InvalidWidth { width } => {
+                write!(f, "invalid column width tag {}, expected a width of 1 to 16 bytes", width)
+            }
+            ColumnDecodeError::InvalidLength { len, width } => write!(
+                f,
+                "column data of {} bytes is not a multiple of the width of {} bytes",
+                len, width
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ColumnDecodeError {}
+
+macro_rules! narrowed_column {
+    ($($variant:ident($ty:ty, $bytes:literal)),*) => {
+        ///
+        /// A column of unsigned integers stored in the narrowest type that holds all of its values.
+        ///
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum NarrowedColumn {
+            $(
+                #[doc = concat!("Values stored as ", stringify!($ty), ".")]
+                $variant(Vec<$ty>),
+            )*
+        }
+
+        impl NarrowedColumn {
+            ///
+            /// Stores the values in the narrowest type that holds max, which must be at least the maximum of the values.
+            ///
+            fn narrow<N: Into<u128> + Copy>(values: &[N], max: u128) -> Self {
+                $(
+                    if max <= u128::from(<$ty>::MAX) {
+                        return NarrowedColumn::$variant(
+                            values.iter().map(|v| <$ty>::try_from((*v).into()).unwrap()).collect(),
+                        );
+                    }
+                )*
+
+                unreachable!()
+            }
+
+            ///
+            /// Size of one value in bytes, this is also the tag of the encoded form.
+            ///
+            pub const fn width(&self) -> usize {
+                match self {
+                    $(NarrowedColumn::$variant(_) => $bytes,)*
+                }
+            }
+
+            ///
+            /// Number of values.
+            ///
+            pub fn len(&self) -> usize {
+                match self {
+                    $(NarrowedColumn::$variant(values) => values.len(),)*
+                }
+            }
+
+            ///
+            /// Returns the value at index, independent of the width it is stored in.
+            /// Panics if index is out of bounds.
+            ///
+            #[track_caller]
+            pub fn get(&self, index: usize) -> u128 {
+                match self {
+                    $(NarrowedColumn::$variant(values) => u128::from(values[index]),)*
+                }
+            }
+
+            ///
+            /// Appends the encoded form of the column to out.
+            ///
+            pub fn write_bytes(&self, out: &mut Vec<u8>) {
+                out.reserve(self.encoded_len());
+                out.push(self.width() as u8);
+                match self {
+                    $(
+                        NarrowedColumn::$variant(values) => {
+                            for v in values {
+                                out.extend_from_slice(&v.to_le_bytes());
+                            }
+                        }
+                    )*
+                }
+            }
+
+            ///
+            /// Decodes a column from its encoded form.
+            ///
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self, ColumnDecodeError> {
+                let (&width, data) = bytes.split_first().ok_or(ColumnDecodeError::Empty)?;
+                match width {
+                    $(
+                        $bytes => {
+                            if !data.len().is_multiple_of($bytes) {
+                                return Err(ColumnDecodeError::InvalidLength { len: data.len(), width });
+                            }
+
+                            Ok(NarrowedColumn::$variant(
+                                data.chunks_exact($bytes).map(|c| <$ty>::from_le_bytes(c.try_into().unwrap())).collect(),
+                            ))
+                        }
+                    )*
+                    _ => Err(ColumnDecodeError::InvalidWidth { width }),
+                }
+            }
+        }
+    };
+}
+
+narrowed_column!(
+    U8(u8, 1),
+    U16(u16, 2),
+    U24(u24, 3),
+    U32(u32, 4),
+    U40(u40, 5),
+    U48(u48, 6),
+    U56(u56, 7),
+    U64(u64, 8),
+    U72(u72, 9),
+    U80(u80, 10),
+    U88(u88, 11),
+    U96(u96, 12),
+    U104(u104, 13),
+    U112(u112, 14),
+    U120(u120, 15),
+    U128(u128, 16)
+);
+
+impl NarrowedColumn {
+    ///
+    /// Stores the values in the narrowest type that holds their maximum.
+    ///
+    pub fn from_slice<N: Into<u128> + Copy>(values: &[N]) -> Self {
+        let max = values.iter().map(|v| (*v).into()).max().unwrap_or(0);
+        Self::narrow(values, max)
+    }
+
+    ///
+    /// True if the column contains no values.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///
+    /// Iterates over the values independent of the width they are stored in.
+    ///
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = u128> + ExactSizeIterator + '_ {
+        (0..self.len()).map(|i| self.get(i))
+    }
+
+    ///
+    /// Size of the encoded form in bytes.
+    ///
+    pub fn encoded_len(&self) -> usize {
+        1 + self.len() * self.width()
+    }
+
+    ///
+    /// Encodes the column as a width tag followed by the values in little endian byte order.
+    ///
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write_bytes(&mut out);
+        out
+    }
+}
+
+impl Default for NarrowedColumn {
+    fn default() -> Self {
+        NarrowedColumn::U8(Vec::new())
+    }
+}
+
+impl FromIterator<u64> for NarrowedColumn {
+    fn from_iter<I: IntoIterator<Item = u64>>(iter: I) -> Self {
+        Self::from_slice(&iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl FromIterator<u128> for NarrowedColumn {
+    fn from_iter<I: IntoIterator<Item = u128>>(iter: I) -> Self {
+        Self::from_slice(&iter.into_iter().collect::<Vec<_>>())
+    }
+}
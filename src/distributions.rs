@@ -0,0 +1,139 @@
+//!
+//! Integration with the rand crate.
+//!
+//! Every type implements `Distribution<T> for Standard` and `SampleUniform`.
+//! The orphan rule prevents implementing rand::Fill for slices like [u24] outside of rand,
+//! use the fill and try_fill functions of this module instead.
+//! Random bytes are always interpreted in little endian byte order so seeded generators
+//! produce the same values on every host.
+//!
+use crate::UnalignedUint;
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use rand::Rng;
+
+///
+/// Produces a value with all bits uniformly distributed.
+///
+#[inline]
+pub(crate) fn sample_standard<T: UnalignedUint, R: Rng + ?Sized>(rng: &mut R) -> T {
+    let mut bytes = T::Bytes::default();
+    rng.fill_bytes(bytes.as_mut());
+    T::from_le_bytes(bytes)
+}
+
+///
+/// Fills the slice with values that have all bits uniformly distributed.
+/// All bytes are requested from the rng with a single call.
+///
+/// Panics if the rng fails, see try_fill.
+///
+#[inline]
+pub fn fill<T: UnalignedUint, R: Rng + ?Sized>(slice: &mut [T], rng: &mut R) {
+    try_fill(slice, rng).unwrap_or_else(|e| panic!("Rng::fill failed: {}", e))
+}
+
+///
+/// Fills the slice with values that have all bits uniformly distributed.
+/// All bytes are requested from the rng with a single call.
+///
+pub fn try_fill<T: UnalignedUint, R: Rng + ?Sized>(slice: &mut [T], rng: &mut R) -> Result<(), rand::Error> {
+    rng.try_fill_bytes(T::slice_as_bytes_mut(slice))?;
+    if cfg!(target_endian = "big") {
+        for n in slice.iter_mut() {
+            *n = T::from_le_bytes(n.to_ne_bytes());
+        }
+    }
+
+    Ok(())
+}
+
+///
+/// The UniformSampler of all types of this crate.
+///
+/// Sampling is delegated to the unbiased sampler of the next largest aligned type
+/// so rng.gen_range(u40::from(5u8)..u40::MAX) behaves exactly like it would for u64.
+///
+pub struct UniformUintx<T: UnalignedUint>
+where
+    T::Helper: SampleUniform,
+{
+    inner: <T::Helper as SampleUniform>::Sampler,
+}
+
+impl<T: UnalignedUint> Clone for UniformUintx<T>
+where
+    T::Helper: SampleUniform,
+    <T::Helper as SampleUniform>::Sampler: Clone,
+{
+    fn clone(&self) -> Self {
+        UniformUintx { inner: self.inner.clone() }
+    }
+}
+
+impl<T: UnalignedUint> core::fmt::Debug for UniformUintx<T>
+where
+    T::Helper: SampleUniform,
+    <T::Helper as SampleUniform>::Sampler: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("UniformUintx").field("inner", &self.inner).finish()
+    }
+}
+
+impl<T: UnalignedUint> UniformSampler for UniformUintx<T>
+where
+    T::Helper: SampleUniform,
+{
+    type X = T;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<T> + Sized,
+        B2: SampleBorrow<T> + Sized,
+    {
+        UniformUintx {
+            inner: UniformSampler::new(low.borrow().as_num(), high.borrow().as_num()),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<T> + Sized,
+        B2: SampleBorrow<T> + Sized,
+    {
+        UniformUintx {
+            inner: UniformSampler::new_inclusive(low.borrow().as_num(), high.borrow().as_num()),
+        }
+    }
+
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        T::from_num_truncated(self.inner.sample(rng))
+    }
+
+    #[inline]
+    fn sample_single<R: Rng + ?Sized, B1, B2>(low: B1, high: B2, rng: &mut R) -> T
+    where
+        B1: SampleBorrow<T> + Sized,
+        B2: SampleBorrow<T> + Sized,
+    {
+        T::from_num_truncated(<T::Helper as SampleUniform>::Sampler::sample_single(
+            low.borrow().as_num(),
+            high.borrow().as_num(),
+            rng,
+        ))
+    }
+
+    #[inline]
+    fn sample_single_inclusive<R: Rng + ?Sized, B1, B2>(low: B1, high: B2, rng: &mut R) -> T
+    where
+        B1: SampleBorrow<T> + Sized,
+        B2: SampleBorrow<T> + Sized,
+    {
+        T::from_num_truncated(<T::Helper as SampleUniform>::Sampler::sample_single_inclusive(
+            low.borrow().as_num(),
+            high.borrow().as_num(),
+            rng,
+        ))
+    }
+}
@@ -0,0 +1,89 @@
+//!
+//! Edge value biased generators for the arbitrary, proptest and quickcheck features.
+//!
+//! About a quarter of all generated values are edge values: MIN, 1, MAX, MAX_VALUE - 1,
+//! every power of two and every power of two minus one. The remaining values are uniformly distributed.
+//!
+use crate::UnalignedUint;
+
+///
+/// Amount of distinct indices accepted by edge_value.
+///
+#[inline]
+pub(crate) fn edge_value_count<T: UnalignedUint>() -> u32 {
+    2 * T::NUM_BITS as u32 + 1
+}
+
+///
+/// Maps an index smaller than edge_value_count to an edge value of T.
+///
+pub(crate) fn edge_value<T: UnalignedUint>(index: u32) -> T {
+    let bits = T::NUM_BITS as u32;
+    let max = T::MAX.as_u128();
+    let value = match index {
+        0 => 0,
+        1 => max,
+        2 => max - 1,
+        //1, 2, 4, ... 2^(bits-1)
+        n if n < bits + 3 => 1u128 << (n - 3),
+        //3, 7, 15, ... 2^(bits-1) - 1
+        n => (1u128 << (n - bits - 1)) - 1,
+    };
+
+    T::from_u128_truncated(value)
+}
+
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary<T: UnalignedUint>(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<T> {
+    let choice: u8 = arbitrary::Arbitrary::arbitrary(u)?;
+    if choice & 0b11 == 0 {
+        let index = u.choose_index(edge_value_count::<T>() as usize)?;
+        return Ok(edge_value(index as u32));
+    }
+
+    let mut bytes = T::Bytes::default();
+    u.fill_buffer(bytes.as_mut())?;
+    Ok(T::from_le_bytes(bytes))
+}
+
+#[cfg(feature = "arbitrary")]
+#[inline]
+pub(crate) fn arbitrary_size_hint<T: UnalignedUint>() -> (usize, Option<usize>) {
+    (1, Some(1 + T::BYTES))
+}
+
+#[cfg(feature = "proptest")]
+pub(crate) fn strategy<T: UnalignedUint>() -> proptest::strategy::BoxedStrategy<T> {
+    use proptest::strategy::Strategy;
+
+    proptest::prop_oneof![
+        1 => (0..edge_value_count::<T>()).prop_map(edge_value::<T>),
+        3 => (0..=T::MAX.as_u128()).prop_map(T::from_u128_truncated),
+    ]
+    .boxed()
+}
+
+#[cfg(feature = "quickcheck")]
+pub(crate) fn quickcheck_arbitrary<T: UnalignedUint>(g: &mut quickcheck::Gen) -> T {
+    use quickcheck::Arbitrary;
+
+    if u8::arbitrary(g) & 0b11 == 0 {
+        return edge_value(u32::arbitrary(g) % edge_value_count::<T>());
+    }
+
+    let mut bytes = T::Bytes::default();
+    for byte in bytes.as_mut() {
+        *byte = u8::arbitrary(g);
+    }
+
+    T::from_le_bytes(bytes)
+}
+
+#[cfg(feature = "quickcheck")]
+pub(crate) fn quickcheck_shrink<T: UnalignedUint>(value: T) -> Box<dyn Iterator<Item = T>>
+where
+    T::Helper: quickcheck::Arbitrary,
+{
+    //Shrinking only produces smaller values so they always fit into T.
+    Box::new(quickcheck::Arbitrary::shrink(&value.as_num()).map(T::from_num_truncated))
+}
@@ -0,0 +1,523 @@
+//!
+//! Unsigned fixed-point numbers stored in the unaligned integer types.
+//!
+//! `Fixed<u24, 8>` is a Q16.8 number: 3 bytes in memory with 16 integer bits and 8 fractional bits.
+//! Additions and subtractions use the arithmetic of the underlying type,
+//! products and quotients are calculated with a u128 intermediate for types up to 64 bits
+//! and a 256 bit intermediate for the wider types, so they never overflow before rescaling.
+//!
+use crate::UnalignedUint;
+use core::fmt;
+
+///
+/// Determines how results that cannot be represented exactly are rounded.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    ///
+    /// Round towards zero. This discards all bits that do not fit.
+    ///
+    #[default]
+    Floor,
+    ///
+    /// Round away from zero.
+    ///
+    Ceil,
+    ///
+    /// Round to the nearest value, ties are rounded away from zero.
+    ///
+    Nearest,
+    ///
+    /// Round to the nearest value, ties are rounded to the value with an even last bit.
+    ///
+    NearestEven,
+}
+
+impl RoundingMode {
+    ///
+    /// Decides if a truncated result has to be incremented.
+    /// remainder/divisor is the discarded fraction which is always smaller than 1.
+    ///
+    #[inline]
+    fn round_up(self, truncated: u128, remainder: u128, divisor: u128) -> bool {
+        if remainder == 0 {
+            return false;
+        }
+
+        //remainder < divisor, so divisor - remainder does not underflow and comparing
+        //remainder against divisor - remainder avoids calculating 2 * remainder.
+        let rest = divisor - remainder;
+        match self {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => true,
+            RoundingMode::Nearest => remainder >= rest,
+            RoundingMode::NearestEven => remainder > rest || (remainder == rest && truncated & 1 == 1),
+        }
+    }
+}
+
+///
+/// Unsigned fixed-point number with FRAC fractional bits stored in T.
+///
+/// The raw value `bits` represents the number `bits / 2^FRAC`.
+/// FRAC must not be larger than T::NUM_BITS, this is checked at compile time.
+///
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<T: UnalignedUint, const FRAC: u32>(T);
+
+/// Q16.8 stored in 3 bytes.
+pub type UQ16_8 = Fixed<crate::u24, 8>;
+/// Q8.16 stored in 3 bytes.
+pub type UQ8_16 = Fixed<crate::u24, 16>;
+/// Q32.8 stored in 5 bytes.
+pub type UQ32_8 = Fixed<crate::u40, 8>;
+/// Q24.16 stored in 5 bytes.
+pub type UQ24_16 = Fixed<crate::u40, 16>;
+/// Q40.8 stored in 6 bytes.
+pub type UQ40_8 = Fixed<crate::u48, 8>;
+/// Q32.16 stored in 6 bytes.
+pub type UQ32_16 = Fixed<crate::u48, 16>;
+
+impl<T: UnalignedUint, const FRAC: u32> Fixed<T, FRAC> {
+    ///
+    /// Amount of fractional bits.
+    ///
+    pub const FRAC_BITS: u32 = FRAC;
+    ///
+    /// Amount of integer bits.
+    ///
+    pub const INT_BITS: u32 = T::NUM_BITS as u32 - FRAC;
+    ///
+    /// Smallest value (0).
+    ///
+    pub const MIN: Self = Fixed(T::MIN);
+    ///
+    /// Largest value.
+    ///
+    pub const MAX: Self = Fixed(T::MAX);
+
+    ///
+    /// Returns the smallest value larger than 0.
+    ///
+    #[inline]
+    pub fn delta() -> Self {
+        Self::from_bits(T::from_u128_truncated(1))
+    }
+
+    ///
+    /// Creates the number from its raw representation.
+    ///
+    #[inline]
+    pub fn from_bits(bits: T) -> Self {
+        const { assert!(FRAC as usize <= T::NUM_BITS, "FRAC is larger than the amount of bits in T") };
+        Fixed(bits)
+    }
+
+    ///
+    /// Returns the raw representation of the number.
+    ///
+    #[inline]
+    pub fn to_bits(self) -> T {
+        self.0
+    }
+
+    #[inline]
+    fn raw(self) -> u128 {
+        self.0.as_u128()
+    }
+
+    #[inline]
+    fn from_raw(raw: u128) -> Option<Self> {
+        if raw > T::MAX.as_u128() {
+            return None;
+        }
+
+        Some(Self::from_bits(T::from_u128_truncated(raw)))
+    }
+
+    ///
+    /// Creates the number from an integer.
+    /// Returns None if the integer does not fit into the integer bits.
+    ///
+    pub fn checked_from_int(n: u128) -> Option<Self> {
+        if Self::INT_BITS == 0 {
+            return (n == 0).then_some(Self::MIN);
+        }
+
+        if n >> Self::INT_BITS != 0 {
+            return None;
+        }
+
+        Self::from_raw(n << FRAC)
+    }
+
+    ///
+    /// Returns the integer part of the number.
+    ///
+    #[inline]
+    pub fn int_part(self) -> u128 {
+        self.raw().checked_shr(FRAC).unwrap_or(0)
+    }
+
+    ///
+    /// Returns the fractional part of the number.
+    ///
+    #[inline]
+    pub fn frac_part(self) -> Self {
+        Self::from_bits(T::from_u128_truncated(self.raw() & frac_mask(FRAC)))
+    }
+
+    ///
+    /// Converts a f64 into the number rounding to the nearest value.
+    /// Returns None for NaN, negative numbers and numbers that are too large.
+    ///
+    #[inline]
+    pub fn from_f64(value: f64) -> Option<Self> {
+        Self::from_f64_rounded(value, RoundingMode::Nearest)
+    }
+
+    ///
+    /// Converts a f64 into the number using the given rounding mode.
+    /// Returns None for NaN, negative numbers and numbers that are too large.
+    ///
+    pub fn from_f64_rounded(value: f64, mode: RoundingMode) -> Option<Self> {
+        let scaled = value * pow2(FRAC);
+        //This is false for NaN.
+        if !(scaled >= 0.0 && scaled < pow2(T::NUM_BITS as u32)) {
+            return None;
+        }
+
+        //Values of 2^53 and above have no fractional digits so truncating them is exact.
+        let truncated = scaled as u128;
+        let fraction = scaled - truncated as f64;
+        let round_up = match mode {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => fraction > 0.0,
+            RoundingMode::Nearest => fraction >= 0.5,
+            RoundingMode::NearestEven => fraction > 0.5 || (fraction == 0.5 && truncated & 1 == 1),
+        };
+
+        Self::from_raw(truncated + round_up as u128)
+    }
+
+    ///
+    /// Converts a f64 into the number rounding to the nearest value.
+    /// Values that are too large become MAX, NaN and negative values become MIN.
+    ///
+    pub fn saturating_from_f64(value: f64) -> Self {
+        match Self::from_f64(value) {
+            Some(n) => n,
+            None if value > 0.0 => Self::MAX,
+            None => Self::MIN,
+        }
+    }
+
+    ///
+    /// Converts a f32 into the number rounding to the nearest value.
+    /// Returns None for NaN, negative numbers and numbers that are too large.
+    ///
+    #[inline]
+    pub fn from_f32(value: f32) -> Option<Self> {
+        Self::from_f64(value as f64)
+    }
+
+    ///
+    /// Converts a f32 into the number using the given rounding mode.
+    /// Returns None for NaN, negative numbers and numbers that are too large.
+    ///
+    #[inline]
+    pub fn from_f32_rounded(value: f32, mode: RoundingMode) -> Option<Self> {
+        Self::from_f64_rounded(value as f64, mode)
+    }
+
+    ///
+    /// Converts the number into the nearest f64.
+    ///
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.raw() as f64 / pow2(FRAC)
+    }
+
+    ///
+    /// Converts the number into the nearest f32.
+    ///
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    ///
+    /// Adds the numbers, returns None on overflow.
+    ///
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self::from_bits)
+    }
+
+    ///
+    /// Subtracts the numbers, returns None on underflow.
+    ///
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self::from_bits)
+    }
+
+    ///
+    /// Adds the numbers, returns MAX on overflow.
+    ///
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self::from_bits(self.0.saturating_add(rhs.0))
+    }
+
+    ///
+    /// Subtracts the numbers, returns MIN on underflow.
+    ///
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self::from_bits(self.0.saturating_sub(rhs.0))
+    }
+
+    ///
+    /// Multiplies the numbers discarding the bits that do not fit. Returns None on overflow.
+    ///
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.checked_mul_rounded(rhs, RoundingMode::Floor)
+    }
+
+    ///
+    /// Multiplies the numbers using the given rounding mode. Returns None on overflow.
+    ///
+    pub fn checked_mul_rounded(self, rhs: Self, mode: RoundingMode) -> Option<Self> {
+        if T::NUM_BITS <= 64 {
+            //The product of two numbers with at most 64 bits fits into u128.
+            let product = self.raw() * rhs.raw();
+            let truncated = product >> FRAC;
+            let round_up = mode.round_up(truncated, product & frac_mask(FRAC), 1u128 << FRAC);
+            return Self::from_raw(truncated + round_up as u128);
+        }
+
+        let (hi, lo) = widening_mul(self.raw(), rhs.raw());
+        if FRAC == 0 {
+            return if hi == 0 { Self::from_raw(lo) } else { None };
+        }
+
+        if hi >> FRAC != 0 {
+            return None;
+        }
+
+        let truncated = (hi << (128 - FRAC)) | (lo >> FRAC);
+        let remainder = lo & frac_mask(FRAC);
+        let round_up = mode.round_up(truncated, remainder, 1u128 << FRAC);
+        Self::from_raw(truncated.checked_add(round_up as u128)?)
+    }
+
+    ///
+    /// Multiplies the numbers discarding the bits that do not fit. Returns MAX on overflow.
+    ///
+    #[inline]
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).unwrap_or(Self::MAX)
+    }
+
+    ///
+    /// Divides the numbers discarding the bits that do not fit.
+    /// Returns None on overflow or if rhs is 0.
+    ///
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_div_rounded(rhs, RoundingMode::Floor)
+    }
+
+    ///
+    /// Divides the numbers using the given rounding mode.
+    /// Returns None on overflow or if rhs is 0.
+    ///
+    pub fn checked_div_rounded(self, rhs: Self, mode: RoundingMode) -> Option<Self> {
+        let divisor = rhs.raw();
+        if divisor == 0 {
+            return None;
+        }
+
+        let dividend = self.raw();
+        if T::NUM_BITS <= 64 {
+            //The dividend has at most 64 bits and FRAC is at most 64, so the scaled dividend fits into u128.
+            let scaled = dividend << FRAC;
+            let quotient = scaled / divisor;
+            let round_up = mode.round_up(quotient, scaled % divisor, divisor);
+            return Self::from_raw(quotient + round_up as u128);
+        }
+
+        let (hi, lo) = if FRAC == 0 { (0, dividend) } else { (dividend >> (128 - FRAC), dividend << FRAC) };
+        let (quotient, remainder) = wide_div(hi, lo, divisor)?;
+        let round_up = mode.round_up(quotient, remainder, divisor);
+        Self::from_raw(quotient.checked_add(round_up as u128)?)
+    }
+
+    ///
+    /// Divides the numbers discarding the bits that do not fit.
+    /// Returns MAX on overflow or if rhs is 0.
+    ///
+    #[inline]
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        self.checked_div(rhs).unwrap_or(Self::MAX)
+    }
+}
+
+#[inline]
+fn frac_mask(frac: u32) -> u128 {
+    1u128.checked_shl(frac).map_or(u128::MAX, |n| n - 1)
+}
+
+///
+/// Exact 2^exp for exp < 1024.
+///
+#[inline]
+fn pow2(exp: u32) -> f64 {
+    f64::from_bits(((1023 + exp) as u64) << 52)
+}
+
+///
+/// Full 256 bit product of both numbers as (high, low).
+///
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let middle = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let lo = (middle << 64) | (lo_lo & MASK);
+    let hi = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (middle >> 64);
+    (hi, lo)
+}
+
+///
+/// Divides the 256 bit number (hi, lo) by divisor.
+/// Returns (quotient, remainder) or None if the quotient does not fit into 128 bits.
+///
+fn wide_div(hi: u128, lo: u128, divisor: u128) -> Option<(u128, u128)> {
+    if hi == 0 {
+        return Some((lo / divisor, lo % divisor));
+    }
+
+    if hi >= divisor {
+        return None;
+    }
+
+    //Restoring long division, the remainder is always smaller than the divisor.
+    let mut remainder = hi;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((lo >> bit) & 1);
+        if carry == 1 || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1 << bit;
+        }
+    }
+
+    Some((quotient, remainder))
+}
+
+macro_rules! fixed_op {
+    ($trait:ident, $fn:ident, $checked:ident, $msg:literal) => {
+        impl<T: UnalignedUint, const FRAC: u32> core::ops::$trait for Fixed<T, FRAC> {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: Self) -> Self {
+                self.$checked(rhs).expect($msg)
+            }
+        }
+    };
+}
+
+fixed_op!(Add, add, checked_add, "attempt to add with overflow");
+fixed_op!(Sub, sub, checked_sub, "attempt to subtract with overflow");
+fixed_op!(Mul, mul, checked_mul, "attempt to multiply with overflow");
+fixed_op!(Div, div, checked_div, "attempt to divide with overflow or by zero");
+
+impl<T: UnalignedUint, const FRAC: u32> fmt::Display for Fixed<T, FRAC> {
+    ///
+    /// Prints all decimals that are required to represent the number exactly.
+    /// If a precision is given the number is rounded to the nearest value with that many decimals.
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        //39 integer digits, the dot and up to 128 decimals.
+        let mut buf = [0u8; 168];
+        let mut int_part = self.int_part();
+        let mut frac = self.raw() & frac_mask(FRAC);
+
+        //Every fractional bit adds exactly one decimal, so FRAC decimals are always exact.
+        let decimals = f.precision().unwrap_or(FRAC as usize).min(128);
+        let mut digits = [0u8; 128];
+        for digit in digits.iter_mut().take(decimals) {
+            //frac < 2^FRAC <= 2^120 so multiplying by 10 cannot overflow.
+            frac *= 10;
+            *digit = (frac >> FRAC) as u8;
+            frac &= frac_mask(FRAC);
+        }
+
+        //Round half up on the remaining fraction.
+        if FRAC > 0 && frac >= 1u128 << (FRAC - 1) {
+            let mut idx = decimals;
+            loop {
+                if idx == 0 {
+                    int_part += 1;
+                    break;
+                }
+
+                idx -= 1;
+                if digits[idx] == 9 {
+                    digits[idx] = 0;
+                } else {
+                    digits[idx] += 1;
+                    break;
+                }
+            }
+        }
+
+        let mut len = decimals;
+        if f.precision().is_none() {
+            while len > 0 && digits[len - 1] == 0 {
+                len -= 1;
+            }
+        }
+
+        let mut pos = buf.len();
+        for digit in digits[..len].iter().rev() {
+            pos -= 1;
+            buf[pos] = b'0' + digit;
+        }
+
+        if len > 0 {
+            pos -= 1;
+            buf[pos] = b'.';
+        }
+
+        loop {
+            pos -= 1;
+            buf[pos] = b'0' + (int_part % 10) as u8;
+            int_part /= 10;
+            if int_part == 0 {
+                break;
+            }
+        }
+
+        //Only ascii digits and the dot were written.
+        f.pad_integral(true, "", core::str::from_utf8(&buf[pos..]).unwrap())
+    }
+}
+
+impl<T: UnalignedUint, const FRAC: u32> fmt::Debug for Fixed<T, FRAC> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,121 @@
+//!
+//! Indexing slices with unaligned integers.
+//!
+//! The index is converted to usize with a checked conversion, so an index that does not fit into usize
+//! on the target is treated like any other index that is out of bounds.
+//!
+//! ```
+//! use uintx::index::{UintxIndex, UintxSliceExt};
+//! use uintx::u24;
+//!
+//! let palette = [10u8, 20, 30];
+//! assert_eq!(palette.get_by(u24::from(1u8)), Some(&20));
+//! assert_eq!(palette.get_by(u24::MAX), None);
+//!
+//! let mut table = [0u32; 4];
+//! table[UintxIndex(u24::from(3u8))] = 7;
+//! assert_eq!(table[UintxIndex(u24::from(3u8))], 7);
+//! ```
+//!
+use crate::UnalignedUint;
+use core::ops::{Index, IndexMut};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[inline]
+fn to_usize<I: UnalignedUint>(index: I) -> Option<usize> {
+    usize::try_from(index.as_u128()).ok()
+}
+
+///
+/// Bounds checked access to slice elements by unaligned integer indices.
+///
+pub trait UintxSliceExt<T> {
+    ///
+    /// Returns the element at index or None if index is out of bounds.
+    ///
+    fn get_by<I: UnalignedUint>(&self, index: I) -> Option<&T>;
+
+    ///
+    /// Returns the element at index or None if index is out of bounds.
+    ///
+    fn get_by_mut<I: UnalignedUint>(&mut self, index: I) -> Option<&mut T>;
+}
+
+impl<T> UintxSliceExt<T> for [T] {
+    #[inline]
+    fn get_by<I: UnalignedUint>(&self, index: I) -> Option<&T> {
+        self.get(to_usize(index)?)
+    }
+
+    #[inline]
+    fn get_by_mut<I: UnalignedUint>(&mut self, index: I) -> Option<&mut T> {
+        self.get_mut(to_usize(index)?)
+    }
+}
+
+///
+/// Wraps an unaligned integer so it can be used with the [] operator on slices, arrays and (with alloc) vectors.
+/// Panics if the index is out of bounds, just like indexing with usize.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UintxIndex<I>(pub I);
+
+impl<I: UnalignedUint> From<I> for UintxIndex<I> {
+    #[inline]
+    fn from(value: I) -> Self {
+        UintxIndex(value)
+    }
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn index_out_of_bounds(index: u128, len: usize) -> ! {
+    panic!("index out of bounds: the len is {} but the index is {}", len, index)
+}
+
+impl<T, I: UnalignedUint> Index<UintxIndex<I>> for [T] {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: UintxIndex<I>) -> &T {
+        match self.get_by(index.0) {
+            Some(element) => element,
+            None => index_out_of_bounds(index.0.as_u128(), self.len()),
+        }
+    }
+}
+
+impl<T, I: UnalignedUint> IndexMut<UintxIndex<I>> for [T] {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, index: UintxIndex<I>) -> &mut T {
+        let len = self.len();
+        match self.get_by_mut(index.0) {
+            Some(element) => element,
+            None => index_out_of_bounds(index.0.as_u128(), len),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, I: UnalignedUint> Index<UintxIndex<I>> for Vec<T> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: UintxIndex<I>) -> &T {
+        &self.as_slice()[index]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, I: UnalignedUint> IndexMut<UintxIndex<I>> for Vec<T> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, index: UintxIndex<I>) -> &mut T {
+        &mut self.as_mut_slice()[index]
+    }
+}
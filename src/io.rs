@@ -0,0 +1,185 @@
+//!
+//! Extension traits for reading and writing unaligned integers with std::io.
+//!
+use crate::{u104, u112, u120, u24, u40, u48, u56, u72, u80, u88, u96, UnalignedUint};
+use std::io;
+
+macro_rules! read_fns {
+    ($ty:ty, $le:ident, $be:ident, $le_into:ident, $be_into:ident) => {
+        #[doc = concat!("Reads a ", stringify!($ty), " in little endian byte order.")]
+        #[inline]
+        fn $le(&mut self) -> io::Result<$ty> {
+            self.read_uintx_le::<$ty>()
+        }
+
+        #[doc = concat!("Reads a ", stringify!($ty), " in big endian byte order.")]
+        #[inline]
+        fn $be(&mut self) -> io::Result<$ty> {
+            self.read_uintx_be::<$ty>()
+        }
+
+        #[doc = concat!("Fills the slice with ", stringify!($ty), " values stored in little endian byte order.")]
+        #[inline]
+        fn $le_into(&mut self, dst: &mut [$ty]) -> io::Result<()> {
+            self.read_uintx_le_into::<$ty>(dst)
+        }
+
+        #[doc = concat!("Fills the slice with ", stringify!($ty), " values stored in big endian byte order.")]
+        #[inline]
+        fn $be_into(&mut self, dst: &mut [$ty]) -> io::Result<()> {
+            self.read_uintx_be_into::<$ty>(dst)
+        }
+    };
+}
+
+macro_rules! write_fns {
+    ($ty:ty, $le:ident, $be:ident) => {
+        #[doc = concat!("Writes a ", stringify!($ty), " in little endian byte order.")]
+        #[inline]
+        fn $le(&mut self, n: $ty) -> io::Result<()> {
+            self.write_uintx_le::<$ty>(n)
+        }
+
+        #[doc = concat!("Writes a ", stringify!($ty), " in big endian byte order.")]
+        #[inline]
+        fn $be(&mut self, n: $ty) -> io::Result<()> {
+            self.write_uintx_be::<$ty>(n)
+        }
+    };
+}
+
+///
+/// Extends io::Read with methods for reading unaligned integers.
+///
+/// All methods fail with io::ErrorKind::UnexpectedEof if the reader ends before enough bytes were read.
+///
+pub trait ReadUintxExt: io::Read {
+    ///
+    /// Reads any unaligned integer in little endian byte order.
+    ///
+    #[inline]
+    fn read_uintx_le<T: UnalignedUint>(&mut self) -> io::Result<T> {
+        let mut buf = T::Bytes::default();
+        self.read_exact(buf.as_mut())?;
+        Ok(T::from_le_bytes(buf))
+    }
+
+    ///
+    /// Reads any unaligned integer in big endian byte order.
+    ///
+    #[inline]
+    fn read_uintx_be<T: UnalignedUint>(&mut self) -> io::Result<T> {
+        let mut buf = T::Bytes::default();
+        self.read_exact(buf.as_mut())?;
+        Ok(T::from_be_bytes(buf))
+    }
+
+    ///
+    /// Fills the slice with unaligned integers stored in little endian byte order.
+    /// All bytes are read with a single read_exact call.
+    ///
+    fn read_uintx_le_into<T: UnalignedUint>(&mut self, dst: &mut [T]) -> io::Result<()> {
+        self.read_exact(T::slice_as_bytes_mut(dst))?;
+        if cfg!(target_endian = "big") {
+            for n in dst.iter_mut() {
+                *n = T::from_le_bytes(n.to_ne_bytes());
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Fills the slice with unaligned integers stored in big endian byte order.
+    /// All bytes are read with a single read_exact call.
+    ///
+    fn read_uintx_be_into<T: UnalignedUint>(&mut self, dst: &mut [T]) -> io::Result<()> {
+        self.read_exact(T::slice_as_bytes_mut(dst))?;
+        if cfg!(target_endian = "little") {
+            for n in dst.iter_mut() {
+                *n = T::from_be_bytes(n.to_ne_bytes());
+            }
+        }
+
+        Ok(())
+    }
+
+    read_fns!(u24, read_u24_le, read_u24_be, read_u24_le_into, read_u24_be_into);
+    read_fns!(u40, read_u40_le, read_u40_be, read_u40_le_into, read_u40_be_into);
+    read_fns!(u48, read_u48_le, read_u48_be, read_u48_le_into, read_u48_be_into);
+    read_fns!(u56, read_u56_le, read_u56_be, read_u56_le_into, read_u56_be_into);
+    read_fns!(u72, read_u72_le, read_u72_be, read_u72_le_into, read_u72_be_into);
+    read_fns!(u80, read_u80_le, read_u80_be, read_u80_le_into, read_u80_be_into);
+    read_fns!(u88, read_u88_le, read_u88_be, read_u88_le_into, read_u88_be_into);
+    read_fns!(u96, read_u96_le, read_u96_be, read_u96_le_into, read_u96_be_into);
+    read_fns!(u104, read_u104_le, read_u104_be, read_u104_le_into, read_u104_be_into);
+    read_fns!(u112, read_u112_le, read_u112_be, read_u112_le_into, read_u112_be_into);
+    read_fns!(u120, read_u120_le, read_u120_be, read_u120_le_into, read_u120_be_into);
+}
+
+impl<R: io::Read + ?Sized> ReadUintxExt for R {}
+
+///
+/// Extends io::Write with methods for writing unaligned integers.
+///
+pub trait WriteUintxExt: io::Write {
+    ///
+    /// Writes any unaligned integer in little endian byte order.
+    ///
+    #[inline]
+    fn write_uintx_le<T: UnalignedUint>(&mut self, n: T) -> io::Result<()> {
+        self.write_all(n.to_le_bytes().as_ref())
+    }
+
+    ///
+    /// Writes any unaligned integer in big endian byte order.
+    ///
+    #[inline]
+    fn write_uintx_be<T: UnalignedUint>(&mut self, n: T) -> io::Result<()> {
+        self.write_all(n.to_be_bytes().as_ref())
+    }
+
+    ///
+    /// Writes all unaligned integers of the slice in little endian byte order.
+    ///
+    fn write_uintx_le_from<T: UnalignedUint>(&mut self, src: &[T]) -> io::Result<()> {
+        if cfg!(target_endian = "little") {
+            return self.write_all(T::slice_as_bytes(src));
+        }
+
+        for n in src {
+            self.write_all(n.to_le_bytes().as_ref())?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Writes all unaligned integers of the slice in big endian byte order.
+    ///
+    fn write_uintx_be_from<T: UnalignedUint>(&mut self, src: &[T]) -> io::Result<()> {
+        if cfg!(target_endian = "big") {
+            return self.write_all(T::slice_as_bytes(src));
+        }
+
+        for n in src {
+            self.write_all(n.to_be_bytes().as_ref())?;
+        }
+
+        Ok(())
+    }
+
+    write_fns!(u24, write_u24_le, write_u24_be);
+    write_fns!(u40, write_u40_le, write_u40_be);
+    write_fns!(u48, write_u48_le, write_u48_be);
+    write_fns!(u56, write_u56_le, write_u56_be);
+    write_fns!(u72, write_u72_le, write_u72_be);
+    write_fns!(u80, write_u80_le, write_u80_be);
+    write_fns!(u88, write_u88_le, write_u88_be);
+    write_fns!(u96, write_u96_le, write_u96_be);
+    write_fns!(u104, write_u104_le, write_u104_be);
+    write_fns!(u112, write_u112_le, write_u112_be);
+    write_fns!(u120, write_u120_le, write_u120_be);
+}
+
+impl<W: io::Write + ?Sized> WriteUintxExt for W {}
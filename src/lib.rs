@@ -1,11 +1,52 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+#[cfg(feature = "alloc")]
+extern crate alloc;
 mod type_macro;
+mod unaligned_uint;
+pub mod bits;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "byteorder")]
+pub mod byte_order;
+#[cfg(feature = "serde")]
+mod serde_support;
+#[cfg(feature = "rkyv")]
+mod rkyv_support;
+#[cfg(feature = "serde")]
+pub mod serde_as;
+#[cfg(feature = "rand")]
+pub mod distributions;
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+mod edge_values;
+pub mod fixed;
+pub mod pixel;
+pub mod audio;
+pub mod mac;
+pub mod record;
+pub mod uint;
+pub mod index;
+pub mod wrapping;
+pub mod radix;
+#[cfg(feature = "alloc")]
+pub mod packed_vec;
+#[cfg(feature = "alloc")]
+pub mod column;
+#[cfg(feature = "alloc")]
+pub mod codec;
+#[cfg(feature = "std")]
+pub mod wav;
+#[cfg(feature = "mmap")]
+pub mod mmap;
 
 use crate::type_macro::{*};
+pub use crate::unaligned_uint::UnalignedUint;
+pub use crate::wrapping::{Checked, Saturating, Wrapping};
+#[cfg(feature = "rkyv")]
+pub use crate::rkyv_support::ArchivedLe;
+pub use crate::uint::{u136, u144, u152, u160, u168, u176, u184, u192, u256, UInt};
 
 
 #[derive(Copy, Clone, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct u24(pub(crate) [u8; 3]);
@@ -75,13 +116,15 @@ impl From<[i8; 3]> for u24 {
     }
 }
 
-impl Into<[i8; 3]> for u24 {
-    fn into(self) -> [i8; 3] {
-        return self.as_i8_array();
+impl From<u24> for [i8; 3] {
+    fn from(value: u24) -> Self {
+        return value.as_i8_array();
     }
 }
 
 type_impl!(u24, u32, 3);
+usize_conv_impl!(u24, u32, any(target_pointer_width = "32", target_pointer_width = "64"));
+primitive_conv_impl!(u24, lossless: u32, u64, u128, i32, i64, i128; narrowing: u8, u16, i8, i16);
 type_conversion!(u40, u32, u24);
 type_conversion!(u48, u32, u24);
 type_conversion!(u56, u32, u24);
@@ -94,7 +137,6 @@ type_conversion!(u112, u32, u24);
 type_conversion!(u120, u32, u24);
 
 #[derive(Copy, Clone, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct u40(pub(crate) [u8; 5]);
@@ -165,13 +207,15 @@ impl From<[i8; 5]> for u40 {
     }
 }
 
-impl Into<[i8; 5]> for u40 {
-    fn into(self) -> [i8; 5] {
-        return self.as_i8_array();
+impl From<u40> for [i8; 5] {
+    fn from(value: u40) -> Self {
+        return value.as_i8_array();
     }
 }
 
 type_impl!(u40, u64, 5);
+usize_conv_impl!(u40, u64, target_pointer_width = "64");
+primitive_conv_impl!(u40, lossless: u64, u128, i64, i128; narrowing: u8, u16, u32, i8, i16, i32);
 type_conversion!(u24, u64, u40);
 type_conversion!(u48, u64, u40);
 type_conversion!(u56, u64, u40);
@@ -184,7 +228,6 @@ type_conversion!(u112, u64, u40);
 type_conversion!(u120, u64, u40);
 
 #[derive(Copy, Clone, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct u48(pub(crate) [u8; 6]);
@@ -297,9 +340,9 @@ impl From<[i8; 6]> for u48 {
     }
 }
 
-impl Into<[i8; 6]> for u48 {
-    fn into(self) -> [i8; 6] {
-        return self.as_i8_array();
+impl From<u48> for [i8; 6] {
+    fn from(value: u48) -> Self {
+        return value.as_i8_array();
     }
 }
 
@@ -321,9 +364,9 @@ impl From<[u16; 3]> for u48 {
     }
 }
 
-impl Into<[u16; 3]> for u48 {
-    fn into(self) -> [u16; 3] {
-        return self.as_u16_array();
+impl From<u48> for [u16; 3] {
+    fn from(value: u48) -> Self {
+        return value.as_u16_array();
     }
 }
 
@@ -345,9 +388,9 @@ impl From<[i16; 3]> for u48 {
     }
 }
 
-impl Into<[i16; 3]> for u48 {
-    fn into(self) -> [i16; 3] {
-        return self.as_i16_array();
+impl From<u48> for [i16; 3] {
+    fn from(value: u48) -> Self {
+        return value.as_i16_array();
     }
 }
 
@@ -371,9 +414,9 @@ impl From<[half::f16; 3]> for u48 {
 }
 
 #[cfg(feature = "half_support")]
-impl Into<[half::f16; 3]> for u48 {
-    fn into(self) -> [half::f16; 3] {
-        return self.as_f16_array();
+impl From<u48> for [half::f16; 3] {
+    fn from(value: u48) -> Self {
+        return value.as_f16_array();
     }
 }
 
@@ -394,14 +437,16 @@ impl From<[u24; 2]> for u48 {
     }
 }
 
-impl Into<[u24; 2]> for u48 {
-    fn into(self) -> [u24; 2] {
-        return self.as_u24_array();
+impl From<u48> for [u24; 2] {
+    fn from(value: u48) -> Self {
+        return value.as_u24_array();
     }
 }
 
 
 type_impl!(u48, u64, 6);
+usize_conv_impl!(u48, u64, target_pointer_width = "64");
+primitive_conv_impl!(u48, lossless: u64, u128, i64, i128; narrowing: u8, u16, u32, i8, i16, i32);
 type_conversion!(u24, u64, u48);
 type_conversion!(u40, u64, u48);
 type_conversion!(u56, u64, u48);
@@ -414,7 +459,6 @@ type_conversion!(u112, u64, u48);
 type_conversion!(u120, u64, u48);
 
 #[derive(Copy, Clone, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct u56(pub(crate) [u8; 7]);
@@ -489,14 +533,16 @@ impl From<[i8; 7]> for u56 {
     }
 }
 
-impl Into<[i8; 7]> for u56 {
-    fn into(self) -> [i8; 7] {
-        return self.as_i8_array();
+impl From<u56> for [i8; 7] {
+    fn from(value: u56) -> Self {
+        return value.as_i8_array();
     }
 }
 
 
 type_impl!(u56, u64, 7);
+usize_conv_impl!(u56, u64, target_pointer_width = "64");
+primitive_conv_impl!(u56, lossless: u64, u128, i64, i128; narrowing: u8, u16, u32, i8, i16, i32);
 type_conversion!(u24, u64, u56);
 type_conversion!(u40, u64, u56);
 type_conversion!(u48, u64, u56);
@@ -509,7 +555,6 @@ type_conversion!(u112, u64, u56);
 type_conversion!(u120, u64, u56);
 
 #[derive(Copy, Clone, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct u72(pub(crate) [u8; 9]);
@@ -598,9 +643,9 @@ impl From<[i8; 9]> for u72 {
     }
 }
 
-impl Into<[i8; 9]> for u72 {
-    fn into(self) -> [i8; 9] {
-        return self.as_i8_array();
+impl From<u72> for [i8; 9] {
+    fn from(value: u72) -> Self {
+        return value.as_i8_array();
     }
 }
 
@@ -625,15 +670,17 @@ impl From<[u24; 3]> for u72 {
     }
 }
 
-impl Into<[u24; 3]> for u72 {
-    fn into(self) -> [u24; 3] {
-        return self.as_u24_array();
+impl From<u72> for [u24; 3] {
+    fn from(value: u72) -> Self {
+        return value.as_u24_array();
     }
 }
 
 
 
 type_impl!(u72, u128, 9);
+usize_conv_impl!(u72, u128, any());
+primitive_conv_impl!(u72, lossless: u128, i128; narrowing: u8, u16, u32, u64, i8, i16, i32, i64);
 type_conversion!(u24, u128, u72);
 type_conversion!(u40, u128, u72);
 type_conversion!(u48, u128, u72);
@@ -646,7 +693,6 @@ type_conversion!(u112, u128, u72);
 type_conversion!(u120, u128, u72);
 
 #[derive(Copy, Clone, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct u80(pub(crate) [u8; 10]);
@@ -773,9 +819,9 @@ impl From<[i8; 10]> for u80 {
     }
 }
 
-impl Into<[i8; 10]> for u80 {
-    fn into(self) -> [i8; 10] {
-        return self.as_i8_array();
+impl From<u80> for [i8; 10] {
+    fn from(value: u80) -> Self {
+        return value.as_i8_array();
     }
 }
 
@@ -803,9 +849,9 @@ impl From<[u16; 5]> for u80 {
     }
 }
 
-impl Into<[u16; 5]> for u80 {
-    fn into(self) -> [u16; 5] {
-        return self.as_u16_array();
+impl From<u80> for [u16; 5] {
+    fn from(value: u80) -> Self {
+        return value.as_u16_array();
     }
 }
 
@@ -833,9 +879,9 @@ impl From<[i16; 5]> for u80 {
     }
 }
 
-impl Into<[i16; 5]> for u80 {
-    fn into(self) -> [i16; 5] {
-        return self.as_i16_array();
+impl From<u80> for [i16; 5] {
+    fn from(value: u80) -> Self {
+        return value.as_i16_array();
     }
 }
 
@@ -865,9 +911,9 @@ impl From<[half::f16; 5]> for u80 {
 }
 
 #[cfg(feature = "half_support")]
-impl Into<[half::f16; 5]> for u80 {
-    fn into(self) -> [half::f16; 5] {
-        return self.as_f16_array();
+impl From<u80> for [half::f16; 5] {
+    fn from(value: u80) -> Self {
+        return value.as_f16_array();
     }
 }
 
@@ -892,14 +938,16 @@ impl From<[u40; 2]> for u80 {
     }
 }
 
-impl Into<[u40; 2]> for u80 {
-    fn into(self) -> [u40; 2] {
-        return self.as_u40_array();
+impl From<u80> for [u40; 2] {
+    fn from(value: u80) -> Self {
+        return value.as_u40_array();
     }
 }
 
 
 type_impl!(u80, u128, 10);
+usize_conv_impl!(u80, u128, any());
+primitive_conv_impl!(u80, lossless: u128, i128; narrowing: u8, u16, u32, u64, i8, i16, i32, i64);
 type_conversion!(u24, u128, u80);
 type_conversion!(u40, u128, u80);
 type_conversion!(u48, u128, u80);
@@ -912,7 +960,6 @@ type_conversion!(u112, u128, u80);
 type_conversion!(u120, u128, u80);
 
 #[derive(Copy, Clone, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct u88(pub(crate) [u8; 11]);
@@ -996,13 +1043,15 @@ impl From<[i8; 11]> for u88 {
     }
 }
 
-impl Into<[i8; 11]> for u88 {
-    fn into(self) -> [i8; 11] {
-        return self.as_i8_array();
+impl From<u88> for [i8; 11] {
+    fn from(value: u88) -> Self {
+        return value.as_i8_array();
     }
 }
 
 type_impl!(u88, u128, 11);
+usize_conv_impl!(u88, u128, any());
+primitive_conv_impl!(u88, lossless: u128, i128; narrowing: u8, u16, u32, u64, i8, i16, i32, i64);
 type_conversion!(u24, u128, u88);
 type_conversion!(u40, u128, u88);
 type_conversion!(u48, u128, u88);
@@ -1015,7 +1064,6 @@ type_conversion!(u112, u128, u88);
 type_conversion!(u120, u128, u88);
 
 #[derive(Copy, Clone, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct u96(pub(crate) [u8; 12]);
@@ -1191,9 +1239,9 @@ impl From<[i8; 12]> for u96 {
     }
 }
 
-impl Into<[i8; 12]> for u96 {
-    fn into(self) -> [i8; 12] {
-        return self.as_i8_array();
+impl From<u96> for [i8; 12] {
+    fn from(value: u96) -> Self {
+        return value.as_i8_array();
     }
 }
 
@@ -1224,9 +1272,9 @@ impl From<[u16; 6]> for u96 {
     }
 }
 
-impl Into<[u16; 6]> for u96 {
-    fn into(self) -> [u16; 6] {
-        return self.as_u16_array();
+impl From<u96> for [u16; 6] {
+    fn from(value: u96) -> Self {
+        return value.as_u16_array();
     }
 }
 
@@ -1257,9 +1305,9 @@ impl From<[i16; 6]> for u96 {
     }
 }
 
-impl Into<[i16; 6]> for u96 {
-    fn into(self) -> [i16; 6] {
-        return self.as_i16_array();
+impl From<u96> for [i16; 6] {
+    fn from(value: u96) -> Self {
+        return value.as_i16_array();
     }
 }
 #[cfg(feature = "half_support")]
@@ -1291,9 +1339,9 @@ impl From<[half::f16; 6]> for u96 {
 }
 
 #[cfg(feature = "half_support")]
-impl Into<[half::f16; 6]> for u96 {
-    fn into(self) -> [half::f16; 6] {
-        return self.as_f16_array();
+impl From<u96> for [half::f16; 6] {
+    fn from(value: u96) -> Self {
+        return value.as_f16_array();
     }
 }
 
@@ -1322,9 +1370,9 @@ impl From<[u24; 4]> for u96 {
     }
 }
 
-impl Into<[u24; 4]> for u96 {
-    fn into(self) -> [u24; 4] {
-        return self.as_u24_array();
+impl From<u96> for [u24; 4] {
+    fn from(value: u96) -> Self {
+        return value.as_u24_array();
     }
 }
 
@@ -1352,9 +1400,9 @@ impl From<[u32; 3]> for u96 {
     }
 }
 
-impl Into<[u32; 3]> for u96 {
-    fn into(self) -> [u32; 3] {
-        return self.as_u32_array();
+impl From<u96> for [u32; 3] {
+    fn from(value: u96) -> Self {
+        return value.as_u32_array();
     }
 }
 
@@ -1382,9 +1430,9 @@ impl From<[i32; 3]> for u96 {
     }
 }
 
-impl Into<[i32; 3]> for u96 {
-    fn into(self) -> [i32; 3] {
-        return self.as_i32_array();
+impl From<u96> for [i32; 3] {
+    fn from(value: u96) -> Self {
+        return value.as_i32_array();
     }
 }
 
@@ -1412,9 +1460,9 @@ impl From<[f32; 3]> for u96 {
     }
 }
 
-impl Into<[f32; 3]> for u96 {
-    fn into(self) -> [f32; 3] {
-        return self.as_f32_array();
+impl From<u96> for [f32; 3] {
+    fn from(value: u96) -> Self {
+        return value.as_f32_array();
     }
 }
 
@@ -1441,13 +1489,15 @@ impl From<[u48; 2]> for u96 {
     }
 }
 
-impl Into<[u48; 2]> for u96 {
-    fn into(self) -> [u48; 2] {
-        return self.as_u48_array();
+impl From<u96> for [u48; 2] {
+    fn from(value: u96) -> Self {
+        return value.as_u48_array();
     }
 }
 
 type_impl!(u96, u128, 12);
+usize_conv_impl!(u96, u128, any());
+primitive_conv_impl!(u96, lossless: u128, i128; narrowing: u8, u16, u32, u64, i8, i16, i32, i64);
 type_conversion!(u24, u128, u96);
 type_conversion!(u40, u128, u96);
 type_conversion!(u48, u128, u96);
@@ -1460,7 +1510,6 @@ type_conversion!(u112, u128, u96);
 type_conversion!(u120, u128, u96);
 
 #[derive(Copy, Clone, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct u104(pub(crate) [u8; 13]);
@@ -1548,13 +1597,15 @@ impl From<[i8; 13]> for u104 {
     }
 }
 
-impl Into<[i8; 13]> for u104 {
-    fn into(self) -> [i8; 13] {
-        return self.as_i8_array();
+impl From<u104> for [i8; 13] {
+    fn from(value: u104) -> Self {
+        return value.as_i8_array();
     }
 }
 
 type_impl!(u104, u128, 13);
+usize_conv_impl!(u104, u128, any());
+primitive_conv_impl!(u104, lossless: u128, i128; narrowing: u8, u16, u32, u64, i8, i16, i32, i64);
 type_conversion!(u24, u128, u104);
 type_conversion!(u40, u128, u104);
 type_conversion!(u48, u128, u104);
@@ -1567,7 +1618,6 @@ type_conversion!(u112, u128, u104);
 type_conversion!(u120, u128, u104);
 
 #[derive(Copy, Clone, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct u112(pub(crate) [u8; 14]);
@@ -1708,9 +1758,9 @@ impl From<[i8; 14]> for u112 {
     }
 }
 
-impl Into<[i8; 14]> for u112 {
-    fn into(self) -> [i8; 14] {
-        return self.as_i8_array();
+impl From<u112> for [i8; 14] {
+    fn from(value: u112) -> Self {
+        return value.as_i8_array();
     }
 }
 
@@ -1744,9 +1794,9 @@ impl From<[u16; 7]> for u112 {
     }
 }
 
-impl Into<[u16; 7]> for u112 {
-    fn into(self) -> [u16; 7] {
-        return self.as_u16_array();
+impl From<u112> for [u16; 7] {
+    fn from(value: u112) -> Self {
+        return value.as_u16_array();
     }
 }
 
@@ -1780,9 +1830,9 @@ impl From<[i16; 7]> for u112 {
     }
 }
 
-impl Into<[i16; 7]> for u112 {
-    fn into(self) -> [i16; 7] {
-        return self.as_i16_array();
+impl From<u112> for [i16; 7] {
+    fn from(value: u112) -> Self {
+        return value.as_i16_array();
     }
 }
 
@@ -1819,9 +1869,9 @@ impl From<[half::f16; 7]> for u112 {
 
 
 #[cfg(feature = "half_support")]
-impl Into<[half::f16; 7]> for u112 {
-    fn into(self) -> [half::f16; 7] {
-        return self.as_f16_array();
+impl From<u112> for [half::f16; 7] {
+    fn from(value: u112) -> Self {
+        return value.as_f16_array();
     }
 }
 
@@ -1850,15 +1900,17 @@ impl From<[u56; 2]> for u112 {
     }
 }
 
-impl Into<[u56; 2]> for u112 {
-    fn into(self) -> [u56; 2] {
-        return self.as_u56_array();
+impl From<u112> for [u56; 2] {
+    fn from(value: u112) -> Self {
+        return value.as_u56_array();
     }
 }
 
 
 
 type_impl!(u112, u128, 14);
+usize_conv_impl!(u112, u128, any());
+primitive_conv_impl!(u112, lossless: u128, i128; narrowing: u8, u16, u32, u64, i8, i16, i32, i64);
 type_conversion!(u24, u128, u112);
 type_conversion!(u40, u128, u112);
 type_conversion!(u48, u128, u112);
@@ -1871,7 +1923,6 @@ type_conversion!(u104, u128, u112);
 type_conversion!(u120, u128, u112);
 
 #[derive(Copy, Clone, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 pub struct u120(pub(crate) [u8; 15]);
@@ -1984,9 +2035,9 @@ impl From<[i8; 15]> for u120 {
     }
 }
 
-impl Into<[i8; 15]> for u120 {
-    fn into(self) -> [i8; 15] {
-        return self.as_i8_array();
+impl From<u120> for [i8; 15] {
+    fn from(value: u120) -> Self {
+        return value.as_i8_array();
     }
 }
 
@@ -2020,9 +2071,9 @@ impl From<[u24; 5]> for u120 {
     }
 }
 
-impl Into<[u24; 5]> for u120 {
-    fn into(self) -> [u24; 5] {
-        return self.as_u24_array();
+impl From<u120> for [u24; 5] {
+    fn from(value: u120) -> Self {
+        return value.as_u24_array();
     }
 }
 
@@ -2054,13 +2105,15 @@ impl From<[u40; 3]> for u120 {
     }
 }
 
-impl Into<[u40; 3]> for u120 {
-    fn into(self) -> [u40; 3] {
-        return self.as_u40_array();
+impl From<u120> for [u40; 3] {
+    fn from(value: u120) -> Self {
+        return value.as_u40_array();
     }
 }
 
 type_impl!(u120, u128, 15);
+usize_conv_impl!(u120, u128, any());
+primitive_conv_impl!(u120, lossless: u128, i128; narrowing: u8, u16, u32, u64, i8, i16, i32, i64);
 type_conversion!(u24, u128, u120);
 type_conversion!(u40, u128, u120);
 type_conversion!(u48, u128, u120);
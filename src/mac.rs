@@ -0,0 +1,377 @@
+//!
+//! MAC addresses (EUI-48) stored in a u48.
+//!
+//! The numeric value of the u48 is the address in network byte order,
+//! so aa:bb:cc:dd:ee:ff is stored as 0xAABBCCDDEEFF and addresses sort like their octets.
+//!
+use crate::{u24, u48};
+use core::fmt;
+use core::str::FromStr;
+
+///
+/// A MAC address (EUI-48).
+///
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MacAddr(u48);
+
+///
+/// Error returned when parsing a MAC address fails.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParseMacAddrError {
+    ///
+    /// The input does not match any of the supported formats.
+    ///
+    InvalidFormat,
+    ///
+    /// The character at the given byte position is not a hexadecimal digit.
+    ///
+    InvalidDigit { position: usize },
+}
+
+impl fmt::Display for ParseMacAddrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseMacAddrError::InvalidFormat => write!(
+                f,
+                "invalid MAC address format, expected aa:bb:cc:dd:ee:ff, aa-bb-cc-dd-ee-ff, aabb.ccdd.eeff or aabbccddeeff"
+            ),
+            ParseMacAddrError::InvalidDigit { position } => {
+                write!(f, "invalid hexadecimal digit at position {} of MAC address", position)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseMacAddrError {}
+
+///
+/// Textual representations of a MAC address.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MacAddrFormat {
+    ///
+    /// aa:bb:cc:dd:ee:ff
+    ///
+    #[default]
+    Colon,
+    ///
+    /// AA-BB-CC-DD-EE-FF as used by IEEE.
+    ///
+    Hyphen,
+    ///
+    /// aabb.ccdd.eeff as used by Cisco.
+    ///
+    Dot,
+    ///
+    /// aabbccddeeff
+    ///
+    Plain,
+}
+
+impl MacAddr {
+    ///
+    /// ff:ff:ff:ff:ff:ff
+    ///
+    pub const BROADCAST: MacAddr = MacAddr(u48::MAX);
+
+    ///
+    /// 00:00:00:00:00:00
+    ///
+    pub const ZERO: MacAddr = MacAddr(u48::MIN);
+
+    #[inline]
+    pub const fn new(octets: [u8; 6]) -> Self {
+        MacAddr(u48::from_be_bytes(octets))
+    }
+
+    ///
+    /// Returns the octets in network byte order.
+    ///
+    #[inline]
+    pub const fn octets(self) -> [u8; 6] {
+        self.0.to_be_bytes()
+    }
+
+    ///
+    /// Creates the address from the organizationally unique identifier and the network interface specific part.
+    ///
+    #[inline]
+    pub const fn from_oui_nic(oui: u24, nic: u24) -> Self {
+        let [a, b, c] = oui.to_be_bytes();
+        let [d, e, f] = nic.to_be_bytes();
+        Self::new([a, b, c, d, e, f])
+    }
+
+    ///
+    /// Returns the organizationally unique identifier, the first three octets.
+    ///
+    #[inline]
+    pub const fn oui(self) -> u24 {
+        let [a, b, c, _, _, _] = self.octets();
+        u24::from_be_bytes([a, b, c])
+    }
+
+    ///
+    /// Returns the network interface specific part, the last three octets.
+    ///
+    #[inline]
+    pub const fn nic(self) -> u24 {
+        let [_, _, _, d, e, f] = self.octets();
+        u24::from_be_bytes([d, e, f])
+    }
+
+    ///
+    /// True if the group bit (least significant bit of the first octet) is set.
+    ///
+    #[inline]
+    pub const fn is_multicast(self) -> bool {
+        self.octets()[0] & 0x01 != 0
+    }
+
+    #[inline]
+    pub const fn is_unicast(self) -> bool {
+        !self.is_multicast()
+    }
+
+    ///
+    /// True if the locally administered bit (second least significant bit of the first octet) is set.
+    ///
+    #[inline]
+    pub const fn is_local(self) -> bool {
+        self.octets()[0] & 0x02 != 0
+    }
+
+    #[inline]
+    pub const fn is_universal(self) -> bool {
+        !self.is_local()
+    }
+
+    #[inline]
+    pub const fn is_broadcast(self) -> bool {
+        self.0.as_num() == u48::MAX_VALUE
+    }
+
+    ///
+    /// Expands the address into an EUI-64 by inserting ff:fe between the OUI and the NIC.
+    ///
+    #[inline]
+    pub const fn to_eui64(self) -> u64 {
+        let [a, b, c, d, e, f] = self.octets();
+        u64::from_be_bytes([a, b, c, 0xFF, 0xFE, d, e, f])
+    }
+
+    ///
+    /// Expands the address into the modified EUI-64 used for IPv6 interface identifiers,
+    /// which additionally inverts the universal/local bit.
+    ///
+    #[inline]
+    pub const fn to_modified_eui64(self) -> u64 {
+        self.to_eui64() ^ (0x02 << 56)
+    }
+
+    ///
+    /// Reverses to_eui64. Returns None if the EUI-64 does not contain ff:fe in the middle.
+    ///
+    #[inline]
+    pub const fn from_eui64(eui64: u64) -> Option<Self> {
+        match eui64.to_be_bytes() {
+            [a, b, c, 0xFF, 0xFE, d, e, f] => Some(Self::new([a, b, c, d, e, f])),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Reverses to_modified_eui64. Returns None if the EUI-64 does not contain ff:fe in the middle.
+    ///
+    #[inline]
+    pub const fn from_modified_eui64(eui64: u64) -> Option<Self> {
+        Self::from_eui64(eui64 ^ (0x02 << 56))
+    }
+
+    ///
+    /// Returns a value that displays the address in the given format.
+    ///
+    #[inline]
+    pub const fn display_as(self, format: MacAddrFormat) -> MacAddrDisplay {
+        MacAddrDisplay { addr: self, format }
+    }
+}
+
+impl From<u48> for MacAddr {
+    #[inline]
+    fn from(value: u48) -> Self {
+        MacAddr(value)
+    }
+}
+
+impl From<MacAddr> for u48 {
+    #[inline]
+    fn from(value: MacAddr) -> Self {
+        value.0
+    }
+}
+
+impl From<[u8; 6]> for MacAddr {
+    #[inline]
+    fn from(value: [u8; 6]) -> Self {
+        MacAddr::new(value)
+    }
+}
+
+impl From<MacAddr> for [u8; 6] {
+    #[inline]
+    fn from(value: MacAddr) -> Self {
+        value.octets()
+    }
+}
+
+#[inline]
+fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+impl FromStr for MacAddr {
+    type Err = ParseMacAddrError;
+
+    ///
+    /// Parses aa:bb:cc:dd:ee:ff, aa-bb-cc-dd-ee-ff, aabb.ccdd.eeff and aabbccddeeff.
+    /// Hexadecimal digits are case insensitive.
+    ///
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        let is_separator: fn(usize) -> bool = match bytes.len() {
+            17 => {
+                let separator = bytes[2];
+                if separator != b':' && separator != b'-' {
+                    return Err(ParseMacAddrError::InvalidFormat);
+                }
+
+                if (5..17).step_by(3).any(|i| bytes[i] != separator) {
+                    return Err(ParseMacAddrError::InvalidFormat);
+                }
+
+                |i| i % 3 == 2
+            }
+            14 => {
+                if bytes[4] != b'.' || bytes[9] != b'.' {
+                    return Err(ParseMacAddrError::InvalidFormat);
+                }
+
+                |i| i % 5 == 4
+            }
+            12 => |_| false,
+            _ => return Err(ParseMacAddrError::InvalidFormat),
+        };
+
+        let mut n = 0u64;
+        for (position, c) in bytes.iter().enumerate() {
+            if is_separator(position) {
+                continue;
+            }
+
+            let digit = hex_value(*c).ok_or(ParseMacAddrError::InvalidDigit { position })?;
+            n = (n << 4) | digit as u64;
+        }
+
+        //12 hex digits always fit into 48 bits.
+        Ok(MacAddr(u48::from_num(n)))
+    }
+}
+
+///
+/// Displays a MAC address in one of the MacAddrFormat formats, see MacAddr::display_as.
+///
+#[derive(Copy, Clone, Debug)]
+pub struct MacAddrDisplay {
+    addr: MacAddr,
+    format: MacAddrFormat,
+}
+
+impl fmt::Display for MacAddrDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (digits, separator, group): (&[u8; 16], u8, usize) = match self.format {
+            MacAddrFormat::Colon => (b"0123456789abcdef", b':', 2),
+            MacAddrFormat::Hyphen => (b"0123456789ABCDEF", b'-', 2),
+            MacAddrFormat::Dot => (b"0123456789abcdef", b'.', 4),
+            MacAddrFormat::Plain => (b"0123456789abcdef", 0, 12),
+        };
+
+        let mut buf = [0u8; 17];
+        let mut len = 0;
+        for (i, octet) in self.addr.octets().iter().enumerate() {
+            if i > 0 && (i * 2) % group == 0 {
+                buf[len] = separator;
+                len += 1;
+            }
+
+            buf[len] = digits[(octet >> 4) as usize];
+            buf[len + 1] = digits[(octet & 0xF) as usize];
+            len += 2;
+        }
+
+        //Only ascii characters were written.
+        f.pad(core::str::from_utf8(&buf[..len]).unwrap())
+    }
+}
+
+impl fmt::Display for MacAddr {
+    ///
+    /// Formats the address as aa:bb:cc:dd:ee:ff.
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display_as(MacAddrFormat::Colon), f)
+    }
+}
+
+impl fmt::Debug for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MacAddr({})", self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MacAddr {
+    ///
+    /// Human readable formats use the aa:bb:cc:dd:ee:ff string, binary formats the 6 octets.
+    ///
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
+        }
+
+        crate::serde_as::as_be_bytes::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MacAddr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            return crate::serde_as::as_be_bytes::deserialize(deserializer).map(MacAddr);
+        }
+
+        struct MacAddrVisitor;
+
+        impl serde::de::Visitor<'_> for MacAddrVisitor {
+            type Value = MacAddr;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a MAC address string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<MacAddr, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(MacAddrVisitor)
+    }
+}
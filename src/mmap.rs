@@ -0,0 +1,445 @@
+//!
+//! Memory mapped files viewed as arrays of unaligned integers.
+//!
+//! MappedArray maps a file read only and derefs to `&[T]`, MappedArrayMut maps it read write and
+//! also derefs to `&mut [T]`. An optional header at the start of the file is skipped, the rest of the
+//! file must be a multiple of `size_of::<T>()` long.
+//!
+//! Dereferencing yields the elements in native endian byte order. Files written in a fixed byte order
+//! are accessed through EndianView and EndianViewMut, which convert every element on access
+//! when the byte order of the file differs from the host.
+//!
+//! ```
+//! use uintx::mmap::{Endianness, MappedArray};
+//! use uintx::u40;
+//!
+//! let path = std::env::temp_dir().join(format!("uintx_mmap_doc_{}", std::process::id()));
+//! let mut data = b"HDR".to_vec();
+//! data.extend_from_slice(&u40::from(7u8).to_be_bytes());
+//! std::fs::write(&path, data).unwrap();
+//!
+//! let offsets = unsafe { MappedArray::<u40>::open_with_header(&path, 3) }.unwrap();
+//! assert_eq!(offsets.header(), b"HDR");
+//! assert_eq!(offsets.len(), 1);
+//! assert_eq!(offsets.view(Endianness::Big).get(0), Some(u40::from(7u8)));
+//! # drop(offsets);
+//! # std::fs::remove_file(&path).unwrap();
+//! ```
+//!
+use crate::UnalignedUint;
+use memmap2::{Mmap, MmapMut};
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+
+///
+/// Byte order of the elements in a file.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    ///
+    /// Least significant byte first.
+    ///
+    Little,
+    ///
+    /// Most significant byte first.
+    ///
+    Big,
+}
+
+impl Endianness {
+    ///
+    /// Byte order of the target.
+    ///
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Self = Endianness::Little;
+
+    ///
+    /// Byte order of the target.
+    ///
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Self = Endianness::Big;
+
+    #[inline]
+    fn decode<T: UnalignedUint>(self, raw: T) -> T {
+        match self {
+            Endianness::Little => T::from_le_bytes(raw.to_ne_bytes()),
+            Endianness::Big => T::from_be_bytes(raw.to_ne_bytes()),
+        }
+    }
+
+    #[inline]
+    fn encode<T: UnalignedUint>(self, value: T) -> T {
+        match self {
+            Endianness::Little => T::from_ne_bytes(value.to_le_bytes()),
+            Endianness::Big => T::from_ne_bytes(value.to_be_bytes()),
+        }
+    }
+}
+
+///
+/// Checks that the data after the header has a length that is a multiple of T::BYTES
+/// and returns the number of elements.
+///
+fn element_count<T: UnalignedUint>(file_len: usize, header_len: usize) -> io::Result<usize> {
+    if file_len < header_len {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("file is {} bytes long but the header is {} bytes long", file_len, header_len),
+        ));
+    }
+
+    let data_len = file_len - header_len;
+    if !data_len.is_multiple_of(T::BYTES) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} bytes after the header are not a multiple of the element size {}", data_len, T::BYTES),
+        ));
+    }
+
+    Ok(data_len / T::BYTES)
+}
+
+///
+/// A read only memory mapped file viewed as a slice of unaligned integers in native endian byte order.
+///
+pub struct MappedArray<T: UnalignedUint> {
+    map: Mmap,
+    header_len: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T: UnalignedUint> MappedArray<T> {
+    ///
+    /// Maps the file at path.
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated by this or any other process while it is mapped,
+    /// see `memmap2::Mmap::map`.
+    ///
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::open_with_header(path, 0)
+    }
+
+    ///
+    /// Maps the file at path and skips the first header_len bytes.
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated by this or any other process while it is mapped,
+    /// see `memmap2::Mmap::map`.
+    ///
+    pub unsafe fn open_with_header<P: AsRef<Path>>(path: P, header_len: usize) -> io::Result<Self> {
+        Self::map(&File::open(path)?, header_len)
+    }
+
+    ///
+    /// Maps an already opened file and skips the first header_len bytes.
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated by this or any other process while it is mapped,
+    /// see `memmap2::Mmap::map`.
+    ///
+    pub unsafe fn map(file: &File, header_len: usize) -> io::Result<Self> {
+        let map = Mmap::map(file)?;
+        element_count::<T>(map.len(), header_len)?;
+        Ok(MappedArray { map, header_len, marker: PhantomData })
+    }
+
+    ///
+    /// The skipped bytes at the start of the file.
+    ///
+    #[inline]
+    pub fn header(&self) -> &[u8] {
+        &self.map[..self.header_len]
+    }
+
+    ///
+    /// The elements as a slice.
+    ///
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        T::slice_from_bytes(&self.map[self.header_len..]).unwrap()
+    }
+
+    ///
+    /// Views the elements as stored in the given byte order.
+    ///
+    #[inline]
+    pub fn view(&self, endianness: Endianness) -> EndianView<'_, T> {
+        EndianView::new(self.as_slice(), endianness)
+    }
+}
+
+impl<T: UnalignedUint> Deref for MappedArray<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+///
+/// A read write memory mapped file viewed as a slice of unaligned integers in native endian byte order.
+/// Changes are written back to the file by the operating system, flush forces this.
+///
+pub struct MappedArrayMut<T: UnalignedUint> {
+    map: MmapMut,
+    header_len: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T: UnalignedUint> MappedArrayMut<T> {
+    ///
+    /// Opens the file at path for reading and writing and maps it.
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated by any other process while it is mapped,
+    /// see `memmap2::MmapMut::map_mut`.
+    ///
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::open_with_header(path, 0)
+    }
+
+    ///
+    /// Opens the file at path for reading and writing, maps it and skips the first header_len bytes.
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated by any other process while it is mapped,
+    /// see `memmap2::MmapMut::map_mut`.
+    ///
+    pub unsafe fn open_with_header<P: AsRef<Path>>(path: P, header_len: usize) -> io::Result<Self> {
+        Self::map(&OpenOptions::new().read(true).write(true).open(path)?, header_len)
+    }
+
+    ///
+    /// Maps an already opened file, which must be opened for reading and writing, and skips the first header_len bytes.
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated by any other process while it is mapped,
+    /// see `memmap2::MmapMut::map_mut`.
+    ///
+    pub unsafe fn map(file: &File, header_len: usize) -> io::Result<Self> {
+        let map = MmapMut::map_mut(file)?;
+        element_count::<T>(map.len(), header_len)?;
+        Ok(MappedArrayMut { map, header_len, marker: PhantomData })
+    }
+
+    ///
+    /// The skipped bytes at the start of the file.
+    ///
+    #[inline]
+    pub fn header(&self) -> &[u8] {
+        &self.map[..self.header_len]
+    }
+
+    ///
+    /// The skipped bytes at the start of the file.
+    ///
+    #[inline]
+    pub fn header_mut(&mut self) -> &mut [u8] {
+        &mut self.map[..self.header_len]
+    }
+
+    ///
+    /// The elements as a slice.
+    ///
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        T::slice_from_bytes(&self.map[self.header_len..]).unwrap()
+    }
+
+    ///
+    /// The elements as a mutable slice.
+    ///
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        T::slice_from_bytes_mut(&mut self.map[self.header_len..]).unwrap()
+    }
+
+    ///
+    /// Views the elements as stored in the given byte order.
+    ///
+    #[inline]
+    pub fn view(&self, endianness: Endianness) -> EndianView<'_, T> {
+        EndianView::new(self.as_slice(), endianness)
+    }
+
+    ///
+    /// Views the elements as stored in the given byte order for reading and writing.
+    ///
+    #[inline]
+    pub fn view_mut(&mut self, endianness: Endianness) -> EndianViewMut<'_, T> {
+        EndianViewMut::new(self.as_mut_slice(), endianness)
+    }
+
+    ///
+    /// Writes all changes to the file and waits until this is done.
+    ///
+    pub fn flush(&self) -> io::Result<()> {
+        self.map.flush()
+    }
+
+    ///
+    /// Makes the mapping read only.
+    ///
+    pub fn make_read_only(self) -> io::Result<MappedArray<T>> {
+        Ok(MappedArray { map: self.map.make_read_only()?, header_len: self.header_len, marker: PhantomData })
+    }
+}
+
+impl<T: UnalignedUint> Deref for MappedArrayMut<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: UnalignedUint> DerefMut for MappedArrayMut<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+///
+/// Read access to elements that are stored in a fixed byte order.
+/// Elements are converted on access if the byte order differs from the target.
+///
+#[derive(Copy, Clone, Debug)]
+pub struct EndianView<'a, T: UnalignedUint> {
+    data: &'a [T],
+    endianness: Endianness,
+}
+
+impl<'a, T: UnalignedUint> EndianView<'a, T> {
+    ///
+    /// Views data whose elements are stored in the given byte order.
+    ///
+    #[inline]
+    pub fn new(data: &'a [T], endianness: Endianness) -> Self {
+        EndianView { data, endianness }
+    }
+
+    ///
+    /// Number of elements.
+    ///
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    ///
+    /// True if there are no elements.
+    ///
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    ///
+    /// Returns the element at index or None if index is out of bounds.
+    ///
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.data.get(index).map(|raw| self.endianness.decode(*raw))
+    }
+
+    ///
+    /// Iterates over the converted elements.
+    ///
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = T> + ExactSizeIterator + 'a {
+        let endianness = self.endianness;
+        self.data.iter().map(move |raw| endianness.decode(*raw))
+    }
+
+    ///
+    /// Returns the elements as a slice if they need no conversion.
+    ///
+    #[inline]
+    pub fn as_native(&self) -> Option<&'a [T]> {
+        if self.endianness == Endianness::NATIVE {
+            Some(self.data)
+        } else {
+            None
+        }
+    }
+}
+
+///
+/// Read and write access to elements that are stored in a fixed byte order.
+/// Elements are converted on access if the byte order differs from the target.
+///
+#[derive(Debug)]
+pub struct EndianViewMut<'a, T: UnalignedUint> {
+    data: &'a mut [T],
+    endianness: Endianness,
+}
+
+impl<'a, T: UnalignedUint> EndianViewMut<'a, T> {
+    ///
+    /// Views data whose elements are stored in the given byte order.
+    ///
+    #[inline]
+    pub fn new(data: &'a mut [T], endianness: Endianness) -> Self {
+        EndianViewMut { data, endianness }
+    }
+
+    ///
+    /// Read only view of the same elements.
+    ///
+    #[inline]
+    pub fn as_view(&self) -> EndianView<'_, T> {
+        EndianView::new(self.data, self.endianness)
+    }
+
+    ///
+    /// Number of elements.
+    ///
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    ///
+    /// True if there are no elements.
+    ///
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    ///
+    /// Returns the element at index or None if index is out of bounds.
+    ///
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.data.get(index).map(|raw| self.endianness.decode(*raw))
+    }
+
+    ///
+    /// Stores value at index.
+    /// Panics if index is out of bounds.
+    ///
+    #[inline]
+    pub fn set(&mut self, index: usize, value: T) {
+        self.data[index] = self.endianness.encode(value);
+    }
+
+    ///
+    /// Returns the elements as a mutable slice if they need no conversion.
+    ///
+    #[inline]
+    pub fn as_native_mut(&mut self) -> Option<&mut [T]> {
+        if self.endianness == Endianness::NATIVE {
+            Some(self.data)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,580 @@
+//!
+//! A growable vector of unaligned integers that stores its elements back to back as bytes.
+//!
+//! PackedVec adds the bulk operations that are cumbersome with a plain `Vec<u24>`:
+//! checked narrowing from wider primitives, numeric sorting and searching by helper values,
+//! and direct access to the bytes for I/O. It only requires the alloc crate.
+//!
+//! A padded PackedVec keeps `size_of::<Helper>() - BYTES` hidden zero bytes after the last element,
+//! so reading the helper type from the address of any element stays inside the allocation.
+//! This makes it safe to call the unsafe_fetch functions on pointers to the elements of a padded vector.
+//!
+//! ```
+//! use uintx::packed_vec::PackedVec;
+//! use uintx::u24;
+//!
+//! let mut blocks = PackedVec::<u24>::new();
+//! blocks.try_extend_from_nums(&[30u32, 10, 20]).unwrap();
+//! assert!(blocks.try_extend_from_nums(&[0x100_0000u32]).is_err());
+//! blocks.sort();
+//! assert_eq!(blocks.binary_search_num(20), Ok(1));
+//! assert_eq!(blocks.as_bytes().len(), 9);
+//! assert_eq!(blocks.into_iter().collect::<Vec<u32>>(), [10, 20, 30]);
+//! ```
+//!
+use crate::UnalignedUint;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::num::TryFromIntError;
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+use core::slice::SliceIndex;
+
+fn try_from_int_error() -> TryFromIntError {
+    u8::try_from(256u16).unwrap_err()
+}
+
+#[inline]
+fn narrow<T: UnalignedUint, N: Into<u128>>(value: N) -> Result<T, TryFromIntError> {
+    let value = value.into();
+    if value > T::MAX_VALUE.into() {
+        return Err(try_from_int_error());
+    }
+
+    Ok(T::from_u128_truncated(value))
+}
+
+///
+/// Recomputes the length and restores the tail padding when it is dropped, also during unwinding.
+///
+struct PaddingGuard<'a> {
+    bytes: &'a mut Vec<u8>,
+    len: &'a mut usize,
+    element: usize,
+    padding: usize,
+}
+
+impl Drop for PaddingGuard<'_> {
+    fn drop(&mut self) {
+        *self.len = self.bytes.len() / self.element;
+        self.bytes.truncate(*self.len * self.element);
+        self.bytes.resize(self.bytes.len() + self.padding, 0);
+    }
+}
+
+///
+/// Growable vector of unaligned integers backed by a single byte buffer.
+///
+#[derive(Clone)]
+pub struct PackedVec<T: UnalignedUint> {
+    ///
+    /// The elements in native endian byte order followed by `padding` zero bytes.
+    ///
+    bytes: Vec<u8>,
+    len: usize,
+    padding: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T: UnalignedUint> PackedVec<T> {
+    ///
+    /// Creates an empty vector without tail padding.
+    ///
+    pub const fn new() -> Self {
+        PackedVec { bytes: Vec::new(), len: 0, padding: 0, marker: PhantomData }
+    }
+
+    ///
+    /// Creates an empty vector without tail padding that can hold capacity elements without reallocating.
+    ///
+    pub fn with_capacity(capacity: usize) -> Self {
+        PackedVec { bytes: Vec::with_capacity(capacity * T::BYTES), len: 0, padding: 0, marker: PhantomData }
+    }
+
+    ///
+    /// Creates an empty vector that keeps hidden zero bytes after the last element,
+    /// so the helper type can be read from the address of every element.
+    ///
+    pub fn new_padded() -> Self {
+        Self::with_capacity_padded(0)
+    }
+
+    ///
+    /// Creates an empty padded vector that can hold capacity elements without reallocating.
+    ///
+    pub fn with_capacity_padded(capacity: usize) -> Self {
+        let padding = size_of::<T::Helper>() - T::BYTES;
+        let mut bytes = Vec::with_capacity(capacity * T::BYTES + padding);
+        bytes.resize(padding, 0);
+        PackedVec { bytes, len: 0, padding, marker: PhantomData }
+    }
+
+    ///
+    /// Number of hidden bytes after the last element.
+    /// This is 0 for unpadded vectors.
+    ///
+    #[inline]
+    pub const fn padding(&self) -> usize {
+        self.padding
+    }
+
+    ///
+    /// True if this vector keeps hidden tail padding.
+    ///
+    #[inline]
+    pub const fn is_padded(&self) -> bool {
+        self.padding != 0
+    }
+
+    ///
+    /// Number of elements.
+    ///
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    ///
+    /// True if the vector contains no elements.
+    ///
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///
+    /// Number of elements the vector can hold without reallocating.
+    ///
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        (self.bytes.capacity() - self.padding) / T::BYTES
+    }
+
+    ///
+    /// Reserves capacity for at least additional more elements.
+    ///
+    pub fn reserve(&mut self, additional: usize) {
+        self.bytes.reserve(additional * T::BYTES);
+    }
+
+    ///
+    /// Reserves capacity for exactly additional more elements.
+    ///
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.bytes.reserve_exact(additional * T::BYTES);
+    }
+
+    ///
+    /// Shrinks the capacity as much as possible.
+    ///
+    pub fn shrink_to_fit(&mut self) {
+        self.bytes.shrink_to_fit();
+    }
+
+    #[inline]
+    fn data_len(&self) -> usize {
+        self.len * T::BYTES
+    }
+
+    ///
+    /// Runs f on the buffer without the tail padding and restores the padding afterward,
+    /// even if f panics.
+    ///
+    #[inline]
+    fn with_unpadded<R>(&mut self, f: impl FnOnce(&mut Vec<u8>) -> R) -> R {
+        let data_len = self.data_len();
+        self.bytes.truncate(data_len);
+        let guard = PaddingGuard {
+            bytes: &mut self.bytes,
+            len: &mut self.len,
+            element: T::BYTES,
+            padding: self.padding,
+        };
+        f(&mut *guard.bytes)
+    }
+
+    ///
+    /// The elements as a slice.
+    ///
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        T::slice_from_bytes(self.as_bytes()).unwrap()
+    }
+
+    ///
+    /// The elements as a mutable slice.
+    ///
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        T::slice_from_bytes_mut(self.as_bytes_mut()).unwrap()
+    }
+
+    ///
+    /// The bytes of all elements in native endian byte order, excluding the tail padding.
+    ///
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.data_len()]
+    }
+
+    ///
+    /// The bytes of all elements in native endian byte order, excluding the tail padding.
+    ///
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        let data_len = self.data_len();
+        &mut self.bytes[..data_len]
+    }
+
+    ///
+    /// Pointer to the first element.
+    /// For padded vectors size_of::<Helper>() bytes can be read from the address of every element.
+    ///
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.bytes.as_ptr().cast()
+    }
+
+    ///
+    /// Appends count zero elements and returns their bytes,
+    /// for example to be filled by `Read::read_exact`.
+    ///
+    pub fn extend_zeroed(&mut self, count: usize) -> &mut [u8] {
+        let start = self.data_len();
+        self.with_unpadded(|bytes| bytes.resize(start + count * T::BYTES, 0));
+        let end = self.data_len();
+        &mut self.bytes[start..end]
+    }
+
+    ///
+    /// Appends the elements encoded in bytes in native endian byte order.
+    /// Returns None and leaves the vector unchanged if the length of bytes is not a multiple of BYTES.
+    ///
+    pub fn extend_from_bytes(&mut self, bytes: &[u8]) -> Option<()> {
+        if !bytes.len().is_multiple_of(T::BYTES) {
+            return None;
+        }
+
+        self.with_unpadded(|data| data.extend_from_slice(bytes));
+        Some(())
+    }
+
+    ///
+    /// Appends an element.
+    ///
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.with_unpadded(|bytes| bytes.extend_from_slice(value.to_ne_bytes().as_ref()));
+    }
+
+    ///
+    /// Appends a helper value or any other unsigned value.
+    /// Returns an error and leaves the vector unchanged if the value does not fit into T.
+    ///
+    #[inline]
+    pub fn try_push_num<N: Into<u128>>(&mut self, value: N) -> Result<(), TryFromIntError> {
+        self.push(narrow(value)?);
+        Ok(())
+    }
+
+    ///
+    /// Removes the last element and returns it.
+    ///
+    pub fn pop(&mut self) -> Option<T> {
+        let last = *self.as_slice().last()?;
+        self.truncate(self.len - 1);
+        Some(last)
+    }
+
+    ///
+    /// Shortens the vector to len elements. Does nothing if the vector is not longer than len.
+    ///
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.with_unpadded(|bytes| bytes.truncate(len * T::BYTES));
+        }
+    }
+
+    ///
+    /// Removes all elements.
+    ///
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    ///
+    /// Resizes the vector to new_len elements, new elements are set to value.
+    ///
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+
+        self.reserve(new_len - self.len);
+        for _ in self.len..new_len {
+            self.push(value);
+        }
+    }
+
+    ///
+    /// Appends all elements of a slice.
+    ///
+    pub fn extend_from_slice(&mut self, values: &[T]) {
+        self.with_unpadded(|bytes| bytes.extend_from_slice(T::slice_as_bytes(values)));
+    }
+
+    ///
+    /// Appends all values of a slice of helper values or other unsigned values.
+    /// Returns an error and leaves the vector unchanged if any value does not fit into T.
+    ///
+    pub fn try_extend_from_nums<N: Into<u128> + Copy>(&mut self, values: &[N]) -> Result<(), TryFromIntError> {
+        if values.iter().any(|v| (*v).into() > T::MAX_VALUE.into()) {
+            return Err(try_from_int_error());
+        }
+
+        self.with_unpadded(|bytes| {
+            bytes.reserve(values.len() * T::BYTES);
+            for v in values {
+                bytes.extend_from_slice(T::from_u128_truncated((*v).into()).to_ne_bytes().as_ref());
+            }
+        });
+        Ok(())
+    }
+
+    ///
+    /// Returns the element at index as a helper value.
+    ///
+    #[inline]
+    pub fn get_num(&self, index: usize) -> Option<T::Helper> {
+        self.as_slice().get(index).map(|v| v.as_num())
+    }
+
+    ///
+    /// Sorts the elements in ascending numeric order.
+    ///
+    pub fn sort(&mut self) {
+        crate::radix::radix_sort_unstable(self.as_mut_slice());
+    }
+
+    ///
+    /// Binary searches a sorted vector for a helper value.
+    /// See `slice::binary_search` for the meaning of the result.
+    ///
+    pub fn binary_search_num(&self, value: T::Helper) -> Result<usize, usize> {
+        self.as_slice().binary_search_by(|v| v.as_num().cmp(&value))
+    }
+
+    ///
+    /// Iterates over the elements as helper values.
+    ///
+    #[inline]
+    pub fn iter_nums(&self) -> Nums<'_, T> {
+        Nums(self.as_slice().iter())
+    }
+
+    ///
+    /// Copies the elements into a `Vec<T>`.
+    ///
+    pub fn to_vec(&self) -> Vec<T> {
+        self.as_slice().to_vec()
+    }
+}
+
+impl<T: UnalignedUint> Default for PackedVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: UnalignedUint> Deref for PackedVec<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: UnalignedUint> DerefMut for PackedVec<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: UnalignedUint, I: SliceIndex<[T]>> Index<I> for PackedVec<T> {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &I::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T: UnalignedUint, I: SliceIndex<[T]>> IndexMut<I> for PackedVec<T> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
+//Padding is not part of the value.
+impl<T: UnalignedUint> PartialEq for PackedVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<T: UnalignedUint> Eq for PackedVec<T> {}
+
+impl<T: UnalignedUint> Hash for PackedVec<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T: UnalignedUint> fmt::Debug for PackedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T: UnalignedUint> From<&[T]> for PackedVec<T> {
+    fn from(values: &[T]) -> Self {
+        let mut result = Self::with_capacity(values.len());
+        result.extend_from_slice(values);
+        result
+    }
+}
+
+impl<T: UnalignedUint> From<Vec<T>> for PackedVec<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self::from(values.as_slice())
+    }
+}
+
+impl<T: UnalignedUint> From<PackedVec<T>> for Vec<T> {
+    fn from(values: PackedVec<T>) -> Self {
+        values.to_vec()
+    }
+}
+
+impl<T: UnalignedUint> FromIterator<T> for PackedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut result = Self::new();
+        result.extend(iter);
+        result
+    }
+}
+
+impl<T: UnalignedUint> Extend<T> for PackedVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.with_unpadded(|bytes| {
+            bytes.reserve(iter.size_hint().0.saturating_mul(T::BYTES));
+            for v in iter {
+                bytes.extend_from_slice(v.to_ne_bytes().as_ref());
+            }
+        });
+    }
+}
+
+impl<'a, T: UnalignedUint> Extend<&'a T> for PackedVec<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+///
+/// Iterator over the elements of a PackedVec as helper values.
+///
+#[derive(Clone, Debug)]
+pub struct Nums<'a, T: UnalignedUint>(core::slice::Iter<'a, T>);
+
+impl<T: UnalignedUint> Iterator for Nums<'_, T> {
+    type Item = T::Helper;
+
+    #[inline]
+    fn next(&mut self) -> Option<T::Helper> {
+        self.0.next().map(|v| v.as_num())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T: UnalignedUint> DoubleEndedIterator for Nums<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T::Helper> {
+        self.0.next_back().map(|v| v.as_num())
+    }
+}
+
+impl<T: UnalignedUint> ExactSizeIterator for Nums<'_, T> {}
+
+impl<'a, T: UnalignedUint> IntoIterator for &'a PackedVec<T> {
+    type Item = T::Helper;
+    type IntoIter = Nums<'a, T>;
+
+    fn into_iter(self) -> Nums<'a, T> {
+        self.iter_nums()
+    }
+}
+
+///
+/// Owning iterator over the elements of a PackedVec as helper values.
+///
+#[derive(Clone, Debug)]
+pub struct IntoIter<T: UnalignedUint> {
+    vec: PackedVec<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<T: UnalignedUint> Iterator for IntoIter<T> {
+    type Item = T::Helper;
+
+    #[inline]
+    fn next(&mut self) -> Option<T::Helper> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.front += 1;
+        self.vec.get_num(self.front - 1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: UnalignedUint> DoubleEndedIterator for IntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T::Helper> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        self.vec.get_num(self.back)
+    }
+}
+
+impl<T: UnalignedUint> ExactSizeIterator for IntoIter<T> {}
+
+impl<T: UnalignedUint> IntoIterator for PackedVec<T> {
+    type Item = T::Helper;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        let back = self.len;
+        IntoIter { vec: self, front: 0, back }
+    }
+}
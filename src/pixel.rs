@@ -0,0 +1,713 @@
+//!
+//! Typed pixel formats stored in the unaligned integer types.
+//!
+//! Every format is a repr(transparent) wrapper with an alignment of 1,
+//! so pixel buffers can be viewed in place with the slice views of the Pixel trait.
+//! Channels are stored in the order given by the name of the format,
+//! Rgb888 is stored as the bytes r, g, b and Bgr888 as b, g, r.
+//!
+//! All formats convert into each other with From. Conversions between two formats
+//! that are not Rgb888 go through Rgb888.
+//!
+use crate::unaligned_uint::private;
+use crate::{u24, u48};
+use core::fmt;
+
+///
+/// Common interface of all pixel formats.
+///
+pub trait Pixel: private::Sealed + Copy + Default + Eq + core::hash::Hash + fmt::Debug + Send + Sync + 'static {
+    ///
+    /// Type of the alpha value used by premultiply and unpremultiply.
+    ///
+    type Alpha: Copy;
+
+    ///
+    /// Size of a pixel in bytes.
+    ///
+    const BYTES: usize;
+
+    ///
+    /// Amount of channels of a pixel.
+    ///
+    const CHANNELS: usize;
+
+    ///
+    /// Multiplies every channel with alpha / Alpha::MAX.
+    ///
+    fn premultiply(self, alpha: Self::Alpha) -> Self;
+
+    ///
+    /// Reverses premultiply by dividing every channel by alpha / Alpha::MAX.
+    /// Channels are clamped to their maximum. An alpha of 0 yields a pixel with all channels 0.
+    ///
+    fn unpremultiply(self, alpha: Self::Alpha) -> Self;
+
+    ///
+    /// Views a slice of pixels as the bytes it consists of.
+    ///
+    #[inline]
+    fn slice_as_bytes(slice: &[Self]) -> &[u8] {
+        private::slice_as_bytes(slice)
+    }
+
+    ///
+    /// Views a mutable slice of pixels as the bytes it consists of.
+    ///
+    #[inline]
+    fn slice_as_bytes_mut(slice: &mut [Self]) -> &mut [u8] {
+        private::slice_as_bytes_mut(slice)
+    }
+
+    ///
+    /// Views a byte slice as a slice of pixels.
+    /// Returns None if the length of the byte slice is not a multiple of BYTES.
+    ///
+    #[inline]
+    fn slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+        private::slice_from_bytes(bytes)
+    }
+
+    ///
+    /// Views a mutable byte slice as a slice of pixels.
+    /// Returns None if the length of the byte slice is not a multiple of BYTES.
+    ///
+    #[inline]
+    fn slice_from_bytes_mut(bytes: &mut [u8]) -> Option<&mut [Self]> {
+        private::slice_from_bytes_mut(bytes)
+    }
+}
+
+///
+/// Converts every pixel of src into the format of dst.
+///
+/// Panics if both slices have a different length.
+///
+pub fn convert_scanline<S: Pixel, D: Pixel + From<S>>(src: &[S], dst: &mut [D]) {
+    assert_eq!(src.len(), dst.len(), "source and destination scanline have a different length");
+    for (d, s) in dst.iter_mut().zip(src) {
+        *d = D::from(*s);
+    }
+}
+
+///
+/// Converts a scanline of raw bytes in the format S into raw bytes in the format D.
+/// Returns the amount of converted pixels.
+///
+/// Panics if src is not a multiple of S::BYTES or dst does not have room for exactly as many pixels.
+///
+pub fn convert_scanline_bytes<S: Pixel, D: Pixel + From<S>>(src: &[u8], dst: &mut [u8]) -> usize {
+    let src = S::slice_from_bytes(src).expect("source length is not a multiple of the pixel size");
+    let dst = D::slice_from_bytes_mut(dst).expect("destination length is not a multiple of the pixel size");
+    convert_scanline(src, dst);
+    src.len()
+}
+
+///
+/// Premultiplies every pixel with the alpha value at the same index.
+///
+/// Panics if both slices have a different length.
+///
+pub fn premultiply_scanline<P: Pixel>(pixels: &mut [P], alpha: &[P::Alpha]) {
+    assert_eq!(pixels.len(), alpha.len(), "scanline and alpha channel have a different length");
+    for (p, a) in pixels.iter_mut().zip(alpha) {
+        *p = p.premultiply(*a);
+    }
+}
+
+///
+/// Unpremultiplies every pixel with the alpha value at the same index.
+///
+/// Panics if both slices have a different length.
+///
+pub fn unpremultiply_scanline<P: Pixel>(pixels: &mut [P], alpha: &[P::Alpha]) {
+    assert_eq!(pixels.len(), alpha.len(), "scanline and alpha channel have a different length");
+    for (p, a) in pixels.iter_mut().zip(alpha) {
+        *p = p.unpremultiply(*a);
+    }
+}
+
+#[inline]
+const fn premultiply_channel(channel: u32, alpha: u32, alpha_max: u32) -> u32 {
+    (channel * alpha + alpha_max / 2) / alpha_max
+}
+
+#[inline]
+const fn unpremultiply_channel(channel: u32, alpha: u32, alpha_max: u32, channel_max: u32) -> u32 {
+    if alpha == 0 {
+        return 0;
+    }
+
+    let n = (channel * alpha_max + alpha / 2) / alpha;
+    if n > channel_max {
+        channel_max
+    } else {
+        n
+    }
+}
+
+macro_rules! pixel_impl {
+    ($name:ident, $alpha:ty, $channels:literal, $bytes:literal, [$($field:ident),*]) => {
+        impl private::Sealed for $name {}
+
+        impl Pixel for $name {
+            type Alpha = $alpha;
+            const BYTES: usize = $bytes;
+            const CHANNELS: usize = $channels;
+
+            #[inline]
+            fn premultiply(self, alpha: $alpha) -> Self {
+                let max = <$alpha>::MAX as u32;
+                Self::new($(premultiply_channel(self.$field() as u32, alpha as u32, max) as _),*)
+            }
+
+            #[inline]
+            fn unpremultiply(self, alpha: $alpha) -> Self {
+                let max = <$alpha>::MAX as u32;
+                Self::new($(unpremultiply_channel(self.$field() as u32, alpha as u32, max, Self::CHANNEL_MAX as u32) as _),*)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $(.field(stringify!($field), &self.$field()))*
+                    .finish()
+            }
+        }
+    };
+}
+
+macro_rules! via_rgb888 {
+    ($a:ident, $b:ident) => {
+        impl From<$a> for $b {
+            #[inline]
+            fn from(value: $a) -> Self {
+                $b::from(Rgb888::from(value))
+            }
+        }
+
+        impl From<$b> for $a {
+            #[inline]
+            fn from(value: $b) -> Self {
+                $a::from(Rgb888::from(value))
+            }
+        }
+    };
+}
+
+///
+/// 8 bit red, green and blue stored in the byte order r, g, b.
+///
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Rgb888(u24);
+
+impl Rgb888 {
+    ///
+    /// Maximum value of a channel.
+    ///
+    pub const CHANNEL_MAX: u8 = u8::MAX;
+
+    #[inline]
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Rgb888(u24::from_ne_bytes([r, g, b]))
+    }
+
+    #[inline]
+    pub const fn r(self) -> u8 {
+        self.0.to_ne_bytes()[0]
+    }
+
+    #[inline]
+    pub const fn g(self) -> u8 {
+        self.0.to_ne_bytes()[1]
+    }
+
+    #[inline]
+    pub const fn b(self) -> u8 {
+        self.0.to_ne_bytes()[2]
+    }
+
+    #[inline]
+    pub fn set_r(&mut self, r: u8) {
+        *self = Self::new(r, self.g(), self.b());
+    }
+
+    #[inline]
+    pub fn set_g(&mut self, g: u8) {
+        *self = Self::new(self.r(), g, self.b());
+    }
+
+    #[inline]
+    pub fn set_b(&mut self, b: u8) {
+        *self = Self::new(self.r(), self.g(), b);
+    }
+}
+
+pixel_impl!(Rgb888, u8, 3, 3, [r, g, b]);
+
+impl From<u24> for Rgb888 {
+    #[inline]
+    fn from(value: u24) -> Self {
+        Rgb888(value)
+    }
+}
+
+impl From<Rgb888> for u24 {
+    #[inline]
+    fn from(value: Rgb888) -> Self {
+        value.0
+    }
+}
+
+///
+/// 8 bit blue, green and red stored in the byte order b, g, r.
+///
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Bgr888(u24);
+
+impl Bgr888 {
+    ///
+    /// Maximum value of a channel.
+    ///
+    pub const CHANNEL_MAX: u8 = u8::MAX;
+
+    #[inline]
+    pub const fn new(b: u8, g: u8, r: u8) -> Self {
+        Bgr888(u24::from_ne_bytes([b, g, r]))
+    }
+
+    #[inline]
+    pub const fn b(self) -> u8 {
+        self.0.to_ne_bytes()[0]
+    }
+
+    #[inline]
+    pub const fn g(self) -> u8 {
+        self.0.to_ne_bytes()[1]
+    }
+
+    #[inline]
+    pub const fn r(self) -> u8 {
+        self.0.to_ne_bytes()[2]
+    }
+
+    #[inline]
+    pub fn set_b(&mut self, b: u8) {
+        *self = Self::new(b, self.g(), self.r());
+    }
+
+    #[inline]
+    pub fn set_g(&mut self, g: u8) {
+        *self = Self::new(self.b(), g, self.r());
+    }
+
+    #[inline]
+    pub fn set_r(&mut self, r: u8) {
+        *self = Self::new(self.b(), self.g(), r);
+    }
+}
+
+pixel_impl!(Bgr888, u8, 3, 3, [b, g, r]);
+
+impl From<u24> for Bgr888 {
+    #[inline]
+    fn from(value: u24) -> Self {
+        Bgr888(value)
+    }
+}
+
+impl From<Bgr888> for u24 {
+    #[inline]
+    fn from(value: Bgr888) -> Self {
+        value.0
+    }
+}
+
+impl From<Bgr888> for Rgb888 {
+    #[inline]
+    fn from(value: Bgr888) -> Self {
+        Rgb888::new(value.r(), value.g(), value.b())
+    }
+}
+
+impl From<Rgb888> for Bgr888 {
+    #[inline]
+    fn from(value: Rgb888) -> Self {
+        Bgr888::new(value.b(), value.g(), value.r())
+    }
+}
+
+///
+/// 5 bit red, 6 bit green and 5 bit blue packed into a little endian u16.
+/// Red occupies the most significant bits.
+///
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Rgb565([u8; 2]);
+
+impl Rgb565 {
+    ///
+    /// Maximum value of the red and blue channel. Green has one more bit.
+    ///
+    pub const CHANNEL_MAX: u8 = 0x1F;
+
+    ///
+    /// Creates the pixel from the raw channel values, bits that do not fit are discarded.
+    ///
+    #[inline]
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        let n = ((r as u16 & 0x1F) << 11) | ((g as u16 & 0x3F) << 5) | (b as u16 & 0x1F);
+        Rgb565(n.to_le_bytes())
+    }
+
+    #[inline]
+    pub const fn to_u16(self) -> u16 {
+        u16::from_le_bytes(self.0)
+    }
+
+    #[inline]
+    pub const fn from_u16(n: u16) -> Self {
+        Rgb565(n.to_le_bytes())
+    }
+
+    ///
+    /// The 5 bit red channel.
+    ///
+    #[inline]
+    pub const fn r(self) -> u8 {
+        (self.to_u16() >> 11) as u8
+    }
+
+    ///
+    /// The 6 bit green channel.
+    ///
+    #[inline]
+    pub const fn g(self) -> u8 {
+        ((self.to_u16() >> 5) & 0x3F) as u8
+    }
+
+    ///
+    /// The 5 bit blue channel.
+    ///
+    #[inline]
+    pub const fn b(self) -> u8 {
+        (self.to_u16() & 0x1F) as u8
+    }
+
+    #[inline]
+    pub fn set_r(&mut self, r: u8) {
+        *self = Self::new(r, self.g(), self.b());
+    }
+
+    #[inline]
+    pub fn set_g(&mut self, g: u8) {
+        *self = Self::new(self.r(), g, self.b());
+    }
+
+    #[inline]
+    pub fn set_b(&mut self, b: u8) {
+        *self = Self::new(self.r(), self.g(), b);
+    }
+}
+
+impl private::Sealed for Rgb565 {}
+
+impl Pixel for Rgb565 {
+    type Alpha = u8;
+    const BYTES: usize = 2;
+    const CHANNELS: usize = 3;
+
+    #[inline]
+    fn premultiply(self, alpha: u8) -> Self {
+        let (a, max) = (alpha as u32, u8::MAX as u32);
+        Self::new(
+            premultiply_channel(self.r() as u32, a, max) as u8,
+            premultiply_channel(self.g() as u32, a, max) as u8,
+            premultiply_channel(self.b() as u32, a, max) as u8,
+        )
+    }
+
+    #[inline]
+    fn unpremultiply(self, alpha: u8) -> Self {
+        let (a, max) = (alpha as u32, u8::MAX as u32);
+        Self::new(
+            unpremultiply_channel(self.r() as u32, a, max, 0x1F) as u8,
+            unpremultiply_channel(self.g() as u32, a, max, 0x3F) as u8,
+            unpremultiply_channel(self.b() as u32, a, max, 0x1F) as u8,
+        )
+    }
+}
+
+impl fmt::Debug for Rgb565 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rgb565").field("r", &self.r()).field("g", &self.g()).field("b", &self.b()).finish()
+    }
+}
+
+impl From<u16> for Rgb565 {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Rgb565::from_u16(value)
+    }
+}
+
+impl From<Rgb565> for u16 {
+    #[inline]
+    fn from(value: Rgb565) -> Self {
+        value.to_u16()
+    }
+}
+
+impl From<Rgb565> for Rgb888 {
+    ///
+    /// Expands the channels by replicating their most significant bits,
+    /// so 0 maps to 0 and the channel maximum maps to 255.
+    ///
+    #[inline]
+    fn from(value: Rgb565) -> Self {
+        let (r, g, b) = (value.r(), value.g(), value.b());
+        Rgb888::new((r << 3) | (r >> 2), (g << 2) | (g >> 4), (b << 3) | (b >> 2))
+    }
+}
+
+impl From<Rgb888> for Rgb565 {
+    ///
+    /// Keeps the most significant bits of every channel.
+    ///
+    #[inline]
+    fn from(value: Rgb888) -> Self {
+        Rgb565::new(value.r() >> 3, value.g() >> 2, value.b() >> 3)
+    }
+}
+
+///
+/// 16 bit red, green and blue stored as three native endian u16.
+///
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Rgb161616(u48);
+
+impl Rgb161616 {
+    ///
+    /// Maximum value of a channel.
+    ///
+    pub const CHANNEL_MAX: u16 = u16::MAX;
+
+    #[inline]
+    pub fn new(r: u16, g: u16, b: u16) -> Self {
+        Rgb161616(u48::from([r, g, b]))
+    }
+
+    #[inline]
+    pub const fn r(self) -> u16 {
+        self.0.as_u16_array()[0]
+    }
+
+    #[inline]
+    pub const fn g(self) -> u16 {
+        self.0.as_u16_array()[1]
+    }
+
+    #[inline]
+    pub const fn b(self) -> u16 {
+        self.0.as_u16_array()[2]
+    }
+
+    #[inline]
+    pub fn set_r(&mut self, r: u16) {
+        *self = Self::new(r, self.g(), self.b());
+    }
+
+    #[inline]
+    pub fn set_g(&mut self, g: u16) {
+        *self = Self::new(self.r(), g, self.b());
+    }
+
+    #[inline]
+    pub fn set_b(&mut self, b: u16) {
+        *self = Self::new(self.r(), self.g(), b);
+    }
+}
+
+pixel_impl!(Rgb161616, u16, 3, 6, [r, g, b]);
+
+impl From<u48> for Rgb161616 {
+    #[inline]
+    fn from(value: u48) -> Self {
+        Rgb161616(value)
+    }
+}
+
+impl From<Rgb161616> for u48 {
+    #[inline]
+    fn from(value: Rgb161616) -> Self {
+        value.0
+    }
+}
+
+impl From<Rgb888> for Rgb161616 {
+    ///
+    /// Scales every channel by 257 so 255 maps to 65535.
+    ///
+    #[inline]
+    fn from(value: Rgb888) -> Self {
+        Rgb161616::new(value.r() as u16 * 257, value.g() as u16 * 257, value.b() as u16 * 257)
+    }
+}
+
+impl From<Rgb161616> for Rgb888 {
+    ///
+    /// Rounds every channel to the nearest 8 bit value.
+    ///
+    #[inline]
+    fn from(value: Rgb161616) -> Self {
+        let narrow = |c: u16| ((c as u32 + 128) / 257) as u8;
+        Rgb888::new(narrow(value.r()), narrow(value.g()), narrow(value.b()))
+    }
+}
+
+///
+/// 8 bit cyan, magenta, yellow, black, orange and green ink stored in the byte order c, m, y, k, o, g.
+///
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Cmykog48(u48);
+
+impl Cmykog48 {
+    ///
+    /// Maximum value of a channel.
+    ///
+    pub const CHANNEL_MAX: u8 = u8::MAX;
+
+    #[inline]
+    pub const fn new(c: u8, m: u8, y: u8, k: u8, o: u8, g: u8) -> Self {
+        Cmykog48(u48::from_ne_bytes([c, m, y, k, o, g]))
+    }
+
+    #[inline]
+    pub const fn c(self) -> u8 {
+        self.0.to_ne_bytes()[0]
+    }
+
+    #[inline]
+    pub const fn m(self) -> u8 {
+        self.0.to_ne_bytes()[1]
+    }
+
+    #[inline]
+    pub const fn y(self) -> u8 {
+        self.0.to_ne_bytes()[2]
+    }
+
+    #[inline]
+    pub const fn k(self) -> u8 {
+        self.0.to_ne_bytes()[3]
+    }
+
+    #[inline]
+    pub const fn o(self) -> u8 {
+        self.0.to_ne_bytes()[4]
+    }
+
+    #[inline]
+    pub const fn g(self) -> u8 {
+        self.0.to_ne_bytes()[5]
+    }
+
+    #[inline]
+    pub fn set_c(&mut self, c: u8) {
+        self.set_channel(0, c);
+    }
+
+    #[inline]
+    pub fn set_m(&mut self, m: u8) {
+        self.set_channel(1, m);
+    }
+
+    #[inline]
+    pub fn set_y(&mut self, y: u8) {
+        self.set_channel(2, y);
+    }
+
+    #[inline]
+    pub fn set_k(&mut self, k: u8) {
+        self.set_channel(3, k);
+    }
+
+    #[inline]
+    pub fn set_o(&mut self, o: u8) {
+        self.set_channel(4, o);
+    }
+
+    #[inline]
+    pub fn set_g(&mut self, g: u8) {
+        self.set_channel(5, g);
+    }
+
+    #[inline]
+    fn set_channel(&mut self, index: usize, value: u8) {
+        let mut bytes = self.0.to_ne_bytes();
+        bytes[index] = value;
+        self.0 = u48::from_ne_bytes(bytes);
+    }
+}
+
+pixel_impl!(Cmykog48, u8, 6, 6, [c, m, y, k, o, g]);
+
+impl From<u48> for Cmykog48 {
+    #[inline]
+    fn from(value: u48) -> Self {
+        Cmykog48(value)
+    }
+}
+
+impl From<Cmykog48> for u48 {
+    #[inline]
+    fn from(value: Cmykog48) -> Self {
+        value.0
+    }
+}
+
+impl From<Rgb888> for Cmykog48 {
+    ///
+    /// Naive separation without color management. Black is taken from the brightest channel,
+    /// orange and green ink are not used.
+    ///
+    fn from(value: Rgb888) -> Self {
+        let (r, g, b) = (value.r() as u32, value.g() as u32, value.b() as u32);
+        let max = r.max(g).max(b);
+        if max == 0 {
+            return Cmykog48::new(0, 0, 0, 255, 0, 0);
+        }
+
+        //(255 - channel - k) * 255 / (255 - k) with k = 255 - max.
+        let ink = |channel: u32| (((max - channel) * 255 + max / 2) / max) as u8;
+        Cmykog48::new(ink(r), ink(g), ink(b), (255 - max) as u8, 0, 0)
+    }
+}
+
+impl From<Cmykog48> for Rgb888 {
+    ///
+    /// Naive conversion without color management. Orange ink is treated as yellow with half magenta,
+    /// green ink as cyan with yellow.
+    ///
+    fn from(value: Cmykog48) -> Self {
+        let (o, g) = (value.o() as u32, value.g() as u32);
+        let c = (value.c() as u32 + g).min(255);
+        let m = (value.m() as u32 + o / 2).min(255);
+        let y = (value.y() as u32 + o + g).min(255);
+        let white = 255 - value.k() as u32;
+        let light = |ink: u32| (((255 - ink) * white + 127) / 255) as u8;
+        Rgb888::new(light(c), light(m), light(y))
+    }
+}
+
+via_rgb888!(Bgr888, Rgb565);
+via_rgb888!(Bgr888, Rgb161616);
+via_rgb888!(Bgr888, Cmykog48);
+via_rgb888!(Rgb565, Rgb161616);
+via_rgb888!(Rgb565, Cmykog48);
+via_rgb888!(Rgb161616, Cmykog48);
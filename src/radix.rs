@@ -0,0 +1,210 @@
+//!
+//! Byte wise radix sort for slices of unaligned integers.
+//!
+//! The integers are just BYTES bytes in native endian byte order, so sorting them one byte at a time
+//! is much faster for large slices than comparing whole elements.
+//! Passes over bytes that are equal in every element are skipped.
+//!
+//! * radix_sort and radix_sort_by_key are stable LSD sorts. They need a scratch buffer as large as the slice,
+//!   which is allocated (requires alloc) or passed in by the *_with_scratch variants.
+//! * radix_sort_unstable and radix_sort_by_key_unstable are in place MSD sorts that need no scratch buffer.
+//!
+//! ```
+//! use uintx::radix::{radix_sort_by_key_with_scratch, radix_sort_unstable};
+//! use uintx::u40;
+//!
+//! let mut blocks = [u40::from(300u32), u40::MAX, u40::from(2u8)];
+//! radix_sort_unstable(&mut blocks);
+//! assert_eq!(blocks, [u40::from(2u8), u40::from(300u32), u40::MAX]);
+//!
+//! let mut entries = [(u40::from(5u8), 'a'), (u40::from(1u8), 'b'), (u40::from(5u8), 'c')];
+//! let mut scratch = entries;
+//! radix_sort_by_key_with_scratch(&mut entries, &mut scratch, |e| e.0);
+//! assert_eq!(entries.map(|e| e.1), ['b', 'a', 'c']);
+//! ```
+//!
+use crate::UnalignedUint;
+
+///
+/// Slices up to this length are sorted by comparison instead of distributing them into buckets.
+///
+const SMALL_SLICE: usize = 32;
+
+///
+/// Index of the byte with significance digit (0 is the least significant byte) in native endian byte order.
+///
+#[inline(always)]
+const fn byte_index(bytes: usize, digit: usize) -> usize {
+    if cfg!(target_endian = "little") {
+        digit
+    } else {
+        bytes - 1 - digit
+    }
+}
+
+#[inline(always)]
+fn digit_of<K: UnalignedUint>(key: K, digit: usize) -> usize {
+    key.to_ne_bytes().as_ref()[byte_index(K::BYTES, digit)] as usize
+}
+
+///
+/// Stable LSD radix sort of data by the key returned by key, using scratch as the second buffer.
+///
+fn lsd<E: Copy, K: UnalignedUint>(data: &mut [E], scratch: &mut [E], mut key: impl FnMut(&E) -> K) {
+    assert!(
+        scratch.len() >= data.len(),
+        "scratch buffer is too small: the len is {} but the slice has {} elements",
+        scratch.len(),
+        data.len()
+    );
+
+    let len = data.len();
+    if len < 2 {
+        return;
+    }
+
+    let scratch = &mut scratch[..len];
+    //Tracks which of the two buffers holds the partially sorted elements.
+    let mut in_scratch = false;
+    for digit in 0..K::BYTES {
+        let (src, dst) = if in_scratch { (&mut *scratch, &mut *data) } else { (&mut *data, &mut *scratch) };
+
+        let mut offsets = [0usize; 256];
+        for e in src.iter() {
+            offsets[digit_of(key(e), digit)] += 1;
+        }
+
+        if offsets.contains(&len) {
+            //Every element has the same byte, the pass would not change the order.
+            continue;
+        }
+
+        let mut sum = 0;
+        for offset in offsets.iter_mut() {
+            let count = *offset;
+            *offset = sum;
+            sum += count;
+        }
+
+        for e in src.iter() {
+            let d = digit_of(key(e), digit);
+            dst[offsets[d]] = *e;
+            offsets[d] += 1;
+        }
+
+        in_scratch = !in_scratch;
+    }
+
+    if in_scratch {
+        data.copy_from_slice(scratch);
+    }
+}
+
+///
+/// In place MSD radix sort (american flag sort) of data by the key returned by key, starting at digit.
+///
+fn msd<E, K: UnalignedUint>(data: &mut [E], digit: usize, key: &mut impl FnMut(&E) -> K) {
+    if data.len() <= SMALL_SLICE {
+        data.sort_unstable_by_key(|e| key(e));
+        return;
+    }
+
+    let mut counts = [0usize; 256];
+    for e in data.iter() {
+        counts[digit_of(key(e), digit)] += 1;
+    }
+
+    let mut heads = [0usize; 256];
+    let mut tails = [0usize; 256];
+    let mut sum = 0;
+    for d in 0..256 {
+        heads[d] = sum;
+        sum += counts[d];
+        tails[d] = sum;
+    }
+
+    if !counts.contains(&data.len()) {
+        for d in 0..256 {
+            while heads[d] < tails[d] {
+                let target = digit_of(key(&data[heads[d]]), digit);
+                if target == d {
+                    heads[d] += 1;
+                } else {
+                    data.swap(heads[d], heads[target]);
+                    heads[target] += 1;
+                }
+            }
+        }
+    }
+
+    if digit == 0 {
+        return;
+    }
+
+    let mut start = 0;
+    for end in tails {
+        if end - start > 1 {
+            msd(&mut data[start..end], digit - 1, key);
+        }
+        start = end;
+    }
+}
+
+///
+/// Sorts the slice in ascending numeric order.
+/// Allocates a scratch buffer as large as the slice.
+///
+#[cfg(feature = "alloc")]
+pub fn radix_sort<T: UnalignedUint>(data: &mut [T]) {
+    let mut scratch = alloc::vec![T::MIN; data.len()];
+    radix_sort_with_scratch(data, &mut scratch);
+}
+
+///
+/// Sorts the slice in ascending numeric order using scratch as temporary storage.
+/// Panics if scratch is shorter than data.
+///
+pub fn radix_sort_with_scratch<T: UnalignedUint>(data: &mut [T], scratch: &mut [T]) {
+    lsd(data, scratch, |e| *e);
+}
+
+///
+/// Sorts the slice in ascending numeric order in place without allocating.
+///
+pub fn radix_sort_unstable<T: UnalignedUint>(data: &mut [T]) {
+    if data.len() > 1 {
+        msd(data, T::BYTES - 1, &mut |e: &T| *e);
+    }
+}
+
+///
+/// Sorts the slice by the numeric order of the keys, keeping the order of elements with equal keys.
+/// Allocates a scratch buffer as large as the slice. key is called several times for every element.
+///
+#[cfg(feature = "alloc")]
+pub fn radix_sort_by_key<E: Copy, K: UnalignedUint>(data: &mut [E], key: impl FnMut(&E) -> K) {
+    let mut scratch = data.to_vec();
+    radix_sort_by_key_with_scratch(data, &mut scratch, key);
+}
+
+///
+/// Sorts the slice by the numeric order of the keys, keeping the order of elements with equal keys.
+/// scratch is used as temporary storage, its contents are overwritten. Panics if scratch is shorter than data.
+///
+pub fn radix_sort_by_key_with_scratch<E: Copy, K: UnalignedUint>(
+    data: &mut [E],
+    scratch: &mut [E],
+    key: impl FnMut(&E) -> K,
+) {
+    lsd(data, scratch, key);
+}
+
+///
+/// Sorts the slice by the numeric order of the keys in place without allocating.
+/// The order of elements with equal keys is not preserved. key is called several times for every element.
+///
+pub fn radix_sort_by_key_unstable<E, K: UnalignedUint>(data: &mut [E], mut key: impl FnMut(&E) -> K) {
+    if data.len() > 1 {
+        msd(data, K::BYTES - 1, &mut key);
+    }
+}
@@ -0,0 +1,340 @@
+//!
+//! Packed binary records built from primitives and unaligned integers.
+//!
+//! With the derive feature `#[derive(PackedRecord)]` generates SIZE, from_bytes and to_bytes for a struct
+//! whose fields implement PackedField. Fields are stored back to back without padding,
+//! the byte order of each field is chosen with the `#[le]` (default) and `#[be]` attributes.
+//! Putting `#[be]` on the struct makes big endian the default for all of its fields.
+//!
+//! RecordSlice and RecordSliceMut view a byte buffer as a sequence of records.
+//! They decode and encode records on access, so they work with any byte order and alignment.
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use uintx::record::{PackedRecord, RecordSlice};
+//! use uintx::{u24, u40};
+//!
+//! #[derive(PackedRecord, Copy, Clone, Debug, PartialEq)]
+//! struct Entry {
+//!     kind: u8,
+//!     #[be]
+//!     length: u24,
+//!     offset: u40,
+//! }
+//!
+//! let entry = Entry { kind: 1, length: u24::from(2u8), offset: u40::from(3u8) };
+//! assert_eq!(Entry::SIZE, 9);
+//! assert_eq!(entry.to_bytes(), [1, 0, 0, 2, 3, 0, 0, 0, 0]);
+//! assert_eq!(Entry::from_bytes(&entry.to_bytes()), entry);
+//!
+//! let table = [entry.to_bytes(), entry.to_bytes()].concat();
+//! let view = RecordSlice::<Entry>::new(&table).unwrap();
+//! assert_eq!(view.get(1), Some(entry));
+//! # }
+//! ```
+//!
+use crate::{UInt, UnalignedUint};
+use core::marker::PhantomData;
+
+#[cfg(feature = "derive")]
+pub use uintx_derive::PackedRecord;
+
+///
+/// A value with a fixed size encoding that can be used as a field of a packed record.
+///
+/// The byte slices passed to all methods have a length of exactly SIZE, implementations may panic otherwise.
+///
+pub trait PackedField: Sized {
+    ///
+    /// Size of the encoded value in bytes.
+    ///
+    const SIZE: usize;
+
+    ///
+    /// Decodes the value from little endian bytes.
+    ///
+    fn read_le(bytes: &[u8]) -> Self;
+
+    ///
+    /// Decodes the value from big endian bytes.
+    ///
+    fn read_be(bytes: &[u8]) -> Self;
+
+    ///
+    /// Encodes the value as little endian bytes.
+    ///
+    fn write_le(&self, bytes: &mut [u8]);
+
+    ///
+    /// Encodes the value as big endian bytes.
+    ///
+    fn write_be(&self, bytes: &mut [u8]);
+}
+
+///
+/// A struct that derives PackedRecord. Records are PackedFields themselves, so they can be nested.
+/// Nested records keep the byte order of their own fields.
+///
+pub trait PackedRecord: PackedField {}
+
+macro_rules! packed_field_primitive {
+    ($($ty:ty),*) => {
+        $(
+            impl PackedField for $ty {
+                const SIZE: usize = core::mem::size_of::<$ty>();
+
+                #[inline]
+                fn read_le(bytes: &[u8]) -> Self {
+                    <$ty>::from_le_bytes(bytes.try_into().expect("byte slice does not have the size of the field"))
+                }
+
+                #[inline]
+                fn read_be(bytes: &[u8]) -> Self {
+                    <$ty>::from_be_bytes(bytes.try_into().expect("byte slice does not have the size of the field"))
+                }
+
+                #[inline]
+                fn write_le(&self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_le_bytes());
+                }
+
+                #[inline]
+                fn write_be(&self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_be_bytes());
+                }
+            }
+        )*
+    };
+}
+
+packed_field_primitive!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl<T: UnalignedUint> PackedField for T {
+    const SIZE: usize = T::BYTES;
+
+    #[inline]
+    fn read_le(bytes: &[u8]) -> Self {
+        let mut buf = T::Bytes::default();
+        buf.as_mut().copy_from_slice(bytes);
+        T::from_le_bytes(buf)
+    }
+
+    #[inline]
+    fn read_be(bytes: &[u8]) -> Self {
+        let mut buf = T::Bytes::default();
+        buf.as_mut().copy_from_slice(bytes);
+        T::from_be_bytes(buf)
+    }
+
+    #[inline]
+    fn write_le(&self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(self.to_le_bytes().as_ref());
+    }
+
+    #[inline]
+    fn write_be(&self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(self.to_be_bytes().as_ref());
+    }
+}
+
+impl<const BYTES: usize> PackedField for UInt<BYTES> {
+    const SIZE: usize = BYTES;
+
+    #[inline]
+    fn read_le(bytes: &[u8]) -> Self {
+        UInt::from_le_bytes(bytes.try_into().expect("byte slice does not have the size of the field"))
+    }
+
+    #[inline]
+    fn read_be(bytes: &[u8]) -> Self {
+        UInt::from_be_bytes(bytes.try_into().expect("byte slice does not have the size of the field"))
+    }
+
+    #[inline]
+    fn write_le(&self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(&self.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_be(&self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(&self.to_be_bytes());
+    }
+}
+
+///
+/// Arrays are stored element after element, every element uses the byte order of the array.
+///
+impl<T: PackedField, const N: usize> PackedField for [T; N] {
+    const SIZE: usize = T::SIZE * N;
+
+    #[inline]
+    fn read_le(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), Self::SIZE, "byte slice does not have the size of the field");
+        core::array::from_fn(|i| T::read_le(&bytes[i * T::SIZE..(i + 1) * T::SIZE]))
+    }
+
+    #[inline]
+    fn read_be(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), Self::SIZE, "byte slice does not have the size of the field");
+        core::array::from_fn(|i| T::read_be(&bytes[i * T::SIZE..(i + 1) * T::SIZE]))
+    }
+
+    #[inline]
+    fn write_le(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), Self::SIZE, "byte slice does not have the size of the field");
+        for (value, chunk) in self.iter().zip(bytes.chunks_exact_mut(T::SIZE.max(1))) {
+            value.write_le(chunk);
+        }
+    }
+
+    #[inline]
+    fn write_be(&self, bytes: &mut [u8]) {
+        assert_eq!(bytes.len(), Self::SIZE, "byte slice does not have the size of the field");
+        for (value, chunk) in self.iter().zip(bytes.chunks_exact_mut(T::SIZE.max(1))) {
+            value.write_be(chunk);
+        }
+    }
+}
+
+///
+/// Read only view of a byte buffer as a sequence of records.
+///
+pub struct RecordSlice<'a, R> {
+    bytes: &'a [u8],
+    _record: PhantomData<R>,
+}
+
+impl<R> Clone for RecordSlice<'_, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R> Copy for RecordSlice<'_, R> {}
+
+impl<'a, R: PackedRecord> RecordSlice<'a, R> {
+    ///
+    /// Returns None if the length of bytes is not a multiple of the record size or the record size is 0.
+    ///
+    pub fn new(bytes: &'a [u8]) -> Option<Self> {
+        if R::SIZE == 0 || !bytes.len().is_multiple_of(R::SIZE) {
+            return None;
+        }
+
+        Some(RecordSlice { bytes, _record: PhantomData })
+    }
+
+    ///
+    /// Amount of records.
+    ///
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bytes.len() / R::SIZE
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    ///
+    /// Decodes the record at index, returns None if index is out of bounds.
+    ///
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<R> {
+        let start = index.checked_mul(R::SIZE)?;
+        let end = start.checked_add(R::SIZE)?;
+        let bytes = self.bytes.get(start..end)?;
+        Some(R::read_le(bytes))
+    }
+
+    ///
+    /// Decodes all records in order.
+    ///
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = R> + 'a
+    where
+        R: 'a,
+    {
+        self.bytes.chunks_exact(R::SIZE).map(R::read_le)
+    }
+
+    ///
+    /// The underlying bytes.
+    ///
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+///
+/// Mutable view of a byte buffer as a sequence of records.
+///
+pub struct RecordSliceMut<'a, R> {
+    bytes: &'a mut [u8],
+    _record: PhantomData<R>,
+}
+
+impl<'a, R: PackedRecord> RecordSliceMut<'a, R> {
+    ///
+    /// Returns None if the length of bytes is not a multiple of the record size or the record size is 0.
+    ///
+    pub fn new(bytes: &'a mut [u8]) -> Option<Self> {
+        if R::SIZE == 0 || !bytes.len().is_multiple_of(R::SIZE) {
+            return None;
+        }
+
+        Some(RecordSliceMut { bytes, _record: PhantomData })
+    }
+
+    ///
+    /// Amount of records.
+    ///
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bytes.len() / R::SIZE
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    ///
+    /// Decodes the record at index, returns None if index is out of bounds.
+    ///
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<R> {
+        self.as_slice().get(index)
+    }
+
+    ///
+    /// Encodes record at index.
+    ///
+    /// Panics if index is out of bounds.
+    ///
+    #[inline]
+    pub fn set(&mut self, index: usize, record: &R) {
+        let len = self.len();
+        assert!(index < len, "index {} out of bounds for {} records", index, len);
+        record.write_le(&mut self.bytes[index * R::SIZE..(index + 1) * R::SIZE]);
+    }
+
+    ///
+    /// Reborrows the view as a read only view.
+    ///
+    #[inline]
+    pub fn as_slice(&self) -> RecordSlice<'_, R> {
+        RecordSlice { bytes: self.bytes, _record: PhantomData }
+    }
+
+    ///
+    /// The underlying bytes.
+    ///
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self.bytes
+    }
+}
@@ -0,0 +1,122 @@
+//!
+//! rkyv Archive/Serialize/Deserialize implementation shared by all types.
+//!
+//! Every type archives as ArchivedLe, which holds the little endian bytes of the value.
+//! Like the types themselves it has an alignment of 1 and every bit pattern is valid,
+//! so archives are identical on little and big endian hosts and validation never fails.
+//!
+use crate::UnalignedUint;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use rkyv::bytecheck::CheckBytes;
+use rkyv::rancor::Fallible;
+use rkyv::traits::NoUndef;
+use rkyv::{Place, Portable};
+
+///
+/// The archived form of an unaligned integer: its bytes in little endian byte order.
+///
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct ArchivedLe<T: UnalignedUint>(T::Bytes);
+
+impl<T: UnalignedUint> ArchivedLe<T> {
+    ///
+    /// Converts a value into its archived form.
+    ///
+    #[inline]
+    pub fn from_native(value: T) -> Self {
+        ArchivedLe(value.to_le_bytes())
+    }
+
+    ///
+    /// Converts the archived form back into the value.
+    ///
+    #[inline]
+    pub fn to_native(self) -> T {
+        T::from_le_bytes(self.0)
+    }
+
+    ///
+    /// The archived value as a helper value.
+    ///
+    #[inline]
+    pub fn as_num(self) -> T::Helper {
+        self.to_native().as_num()
+    }
+}
+
+//Safety: ArchivedLe is a repr(transparent) byte array, its layout is the same on every target.
+unsafe impl<T: UnalignedUint> Portable for ArchivedLe<T> {}
+
+//Safety: Byte arrays have no padding.
+unsafe impl<T: UnalignedUint> NoUndef for ArchivedLe<T> {}
+
+//Safety: Every bit pattern is a valid value.
+unsafe impl<T: UnalignedUint, C: Fallible + ?Sized> CheckBytes<C> for ArchivedLe<T> {
+    #[inline]
+    unsafe fn check_bytes(_value: *const Self, _context: &mut C) -> Result<(), C::Error> {
+        Ok(())
+    }
+}
+
+impl<T: UnalignedUint> From<T> for ArchivedLe<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::from_native(value)
+    }
+}
+
+impl<T: UnalignedUint> PartialEq for ArchivedLe<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: UnalignedUint> Eq for ArchivedLe<T> {}
+
+impl<T: UnalignedUint> PartialEq<T> for ArchivedLe<T> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.to_native() == *other
+    }
+}
+
+impl<T: UnalignedUint> PartialOrd for ArchivedLe<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: UnalignedUint> Ord for ArchivedLe<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_native().cmp(&other.to_native())
+    }
+}
+
+impl<T: UnalignedUint> Hash for ArchivedLe<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_native().hash(state);
+    }
+}
+
+impl<T: UnalignedUint> fmt::Debug for ArchivedLe<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_native(), f)
+    }
+}
+
+impl<T: UnalignedUint> fmt::Display for ArchivedLe<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_native(), f)
+    }
+}
+
+#[inline]
+pub(crate) fn resolve<T: UnalignedUint>(value: &T, out: Place<ArchivedLe<T>>) {
+    out.write(ArchivedLe::from_native(*value));
+}
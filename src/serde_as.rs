@@ -0,0 +1,115 @@
+//!
+//! Adapters for `#[serde(with = "...")]` that pin the wire format of a field
+//! independently of whether the format is human readable.
+//!
+//! ```
+//! # use uintx::u48;
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Frame {
+//!     #[serde(with = "uintx::serde_as::as_be_bytes")]
+//!     destination: u48,
+//!     #[serde(with = "uintx::serde_as::as_hex_string")]
+//!     source: u48,
+//! }
+//! ```
+//!
+
+///
+/// Serializes the value as big endian bytes via serialize_bytes.
+///
+/// Deserialization accepts a byte buffer or a sequence of exactly BYTES bytes.
+///
+pub mod as_be_bytes {
+    use crate::serde_support::read_bytes_seq;
+    use crate::UnalignedUint;
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::de::{self, Deserializer, SeqAccess, Visitor};
+    use serde::Serializer;
+
+    pub fn serialize<T: UnalignedUint, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(value.to_be_bytes().as_ref())
+    }
+
+    pub fn deserialize<'de, T: UnalignedUint, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_bytes(BeBytesVisitor::<T>(PhantomData))
+    }
+
+    struct BeBytesVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: UnalignedUint> Visitor<'de> for BeBytesVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{} big endian bytes", T::BYTES)
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+            if v.len() != T::BYTES {
+                return Err(E::invalid_length(v.len(), &self));
+            }
+
+            let mut bytes = T::Bytes::default();
+            bytes.as_mut().copy_from_slice(v);
+            Ok(T::from_be_bytes(bytes))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<T, A::Error> {
+            Ok(T::from_be_bytes(read_bytes_seq::<T, A>(seq, &self)?))
+        }
+    }
+}
+
+///
+/// Serializes the value as a zero padded lowercase hexadecimal string without prefix.
+/// A u24 with the value 0xABC becomes "000abc".
+///
+/// Deserialization accepts upper and lowercase digits with or without a 0x prefix and checks the range.
+///
+pub mod as_hex_string {
+    use crate::UnalignedUint;
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::de::{self, Deserializer, Unexpected, Visitor};
+    use serde::Serializer;
+
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    pub fn serialize<T: UnalignedUint, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut buf = [0u8; 32];
+        let len = T::BYTES * 2;
+        for (idx, byte) in value.to_be_bytes().as_ref().iter().enumerate() {
+            buf[idx * 2] = DIGITS[(byte >> 4) as usize];
+            buf[idx * 2 + 1] = DIGITS[(byte & 0xF) as usize];
+        }
+
+        //Only ascii digits were written.
+        serializer.serialize_str(core::str::from_utf8(&buf[..len]).unwrap())
+    }
+
+    pub fn deserialize<'de, T: UnalignedUint, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_str(HexVisitor::<T>(PhantomData))
+    }
+
+    struct HexVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: UnalignedUint> Visitor<'de> for HexVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a hexadecimal string with at most {} digits", T::BYTES * 2)
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+            let digits = v.strip_prefix("0x").or_else(|| v.strip_prefix("0X")).unwrap_or(v);
+            if digits.is_empty() || digits.starts_with('+') {
+                return Err(E::invalid_value(Unexpected::Str(v), &self));
+            }
+
+            match u128::from_str_radix(digits, 16) {
+                Ok(n) => crate::serde_support::checked(n),
+                Err(_) => Err(E::invalid_value(Unexpected::Str(v), &self)),
+            }
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//!
+//! Generic Serialize/Deserialize implementation shared by all types.
+//!
+//! Human readable formats (json, toml, ...) see the number.
+//! u24 is a u32, u40 to u56 are a u64 and all wider types are a u128.
+//! Deserialization from human readable formats also accepts decimal strings and hex strings prefixed with 0x.
+//! Binary formats see a newtype struct wrapping the little endian bytes.
+//! This is identical to the output of the previous derived implementation on little endian hosts.
+//!
+use crate::UnalignedUint;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, Deserializer, SeqAccess, Unexpected, Visitor};
+use serde::ser::{SerializeTuple, Serializer};
+
+pub(crate) fn serialize<T: UnalignedUint, S: Serializer>(
+    value: &T,
+    name: &'static str,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        return match core::mem::size_of::<T::Helper>() {
+            4 => serializer.serialize_u32(value.as_u128() as u32),
+            8 => serializer.serialize_u64(value.as_u128() as u64),
+            _ => serializer.serialize_u128(value.as_u128()),
+        };
+    }
+
+    serializer.serialize_newtype_struct(name, &LeBytes(*value))
+}
+
+pub(crate) fn deserialize<'de, T: UnalignedUint, D: Deserializer<'de>>(
+    name: &'static str,
+    deserializer: D,
+) -> Result<T, D::Error> {
+    if deserializer.is_human_readable() {
+        //deserialize_any would make serde_json parse integers larger than u64 as f64.
+        if core::mem::size_of::<T::Helper>() == 16 {
+            return deserializer.deserialize_u128(NumberVisitor::<T>(PhantomData));
+        }
+
+        return deserializer.deserialize_any(NumberVisitor::<T>(PhantomData));
+    }
+
+    deserializer.deserialize_newtype_struct(name, LeBytesVisitor::<T>(PhantomData))
+}
+
+///
+/// Checks that the value fits into T and produces a helpful error otherwise.
+///
+pub(crate) fn checked<T: UnalignedUint, E: de::Error>(value: u128) -> Result<T, E> {
+    if value > T::MAX.as_u128() {
+        return Err(E::custom(format_args!(
+            "integer {} is out of range, expected an integer between 0 and {}",
+            value,
+            T::MAX
+        )));
+    }
+
+    Ok(T::from_u128_truncated(value))
+}
+
+///
+/// Parses a decimal string or a hexadecimal string prefixed with 0x.
+///
+pub(crate) fn parse_str<T: UnalignedUint, E: de::Error>(v: &str) -> Result<T, E> {
+    let parsed = match v.strip_prefix("0x").or_else(|| v.strip_prefix("0X")) {
+        Some(hex) => u128::from_str_radix(hex, 16),
+        None => v.parse::<u128>(),
+    };
+
+    match parsed {
+        Ok(n) => checked(n),
+        Err(_) => Err(E::invalid_value(Unexpected::Str(v), &NumberVisitor::<T>(PhantomData))),
+    }
+}
+
+struct LeBytes<T>(T);
+
+impl<T: UnalignedUint> serde::Serialize for LeBytes<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes_tuple(self.0.to_le_bytes().as_ref(), serializer)
+    }
+}
+
+fn serialize_bytes_tuple<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(bytes.len())?;
+    for byte in bytes {
+        tuple.serialize_element(byte)?;
+    }
+    tuple.end()
+}
+
+///
+/// Reads exactly T::BYTES bytes from a sequence.
+///
+pub(crate) fn read_bytes_seq<'de, T: UnalignedUint, A: SeqAccess<'de>>(
+    mut seq: A,
+    expected: &dyn de::Expected,
+) -> Result<T::Bytes, A::Error> {
+    let mut bytes = T::Bytes::default();
+    for (idx, byte) in bytes.as_mut().iter_mut().enumerate() {
+        *byte = match seq.next_element()? {
+            Some(b) => b,
+            None => return Err(de::Error::invalid_length(idx, expected)),
+        };
+    }
+
+    if seq.next_element::<u8>()?.is_some() {
+        return Err(de::Error::invalid_length(T::BYTES + 1, expected));
+    }
+
+    Ok(bytes)
+}
+
+struct NumberVisitor<T>(PhantomData<T>);
+
+impl<'de, T: UnalignedUint> Visitor<'de> for NumberVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an integer between 0 and {}", T::MAX)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        checked(v as u128)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<T, E> {
+        checked(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        if v < 0 {
+            return Err(E::invalid_value(Unexpected::Signed(v), &self));
+        }
+
+        checked(v as u128)
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<T, E> {
+        if v < 0 {
+            return Err(E::custom(format_args!(
+                "integer {} is negative, expected an integer between 0 and {}",
+                v,
+                T::MAX
+            )));
+        }
+
+        checked(v as u128)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        parse_str(v)
+    }
+}
+
+struct LeBytesVisitor<T>(PhantomData<T>);
+
+impl<'de, T: UnalignedUint> Visitor<'de> for LeBytesVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} little endian bytes", T::BYTES)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_tuple(T::BYTES, self)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<T, A::Error> {
+        Ok(T::from_le_bytes(read_bytes_seq::<T, A>(seq, &self)?))
+    }
+}
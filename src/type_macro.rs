@@ -92,24 +92,14 @@ macro_rules! common_traits_impl {
 
         impl PartialOrd<$source> for $source {
             fn partial_cmp(&self, other: &$source) -> Option<core::cmp::Ordering> {
-                self.0.partial_cmp(&other.0)
+                Some(self.cmp(other))
             }
         }
 
-        impl PartialEq<$helper> for $source {
-            fn eq(&self, other: &$helper) -> bool {
-                self.as_num().eq(other)
-            }
-        }
-
-        impl PartialOrd<$helper> for $source {
-            fn partial_cmp(&self, other: &$helper) -> Option<core::cmp::Ordering> {
-                self.as_num().partial_cmp(other)
-            }
-        }
         impl Ord for $source {
             fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-                self.0.cmp(&other.0)
+                //The bytes are in native endian order, so only the numeric value orders correctly.
+                self.as_num().cmp(&other.as_num())
             }
         }
 
@@ -827,6 +817,15 @@ macro_rules! common_fn_impl {
             return Self::from_num(n);
         }
 
+        ///
+        /// Converts the number like `as usize`, which truncates on targets where usize has fewer bits than the type.
+        /// Use usize::try_from or the index module for a checked conversion.
+        ///
+        #[inline]
+        pub const fn as_usize(self) -> usize {
+            self.as_num() as usize
+        }
+
         ///
         /// Swaps the byte order of the number
         ///
@@ -1138,7 +1137,7 @@ macro_rules! common_fn_impl {
         pub const fn rotate_right(self, n: u32) -> Self {
             let nsz = n as usize % Self::NUM_BITS;
             let accumulator = self.as_num();
-            let remainder = accumulator >> n;
+            let remainder = accumulator >> nsz;
             let shift_off = accumulator << (Self::NUM_BITS - nsz);
             let assembled = remainder | shift_off;
             return Self::from_num(assembled);
@@ -1151,7 +1150,7 @@ macro_rules! common_fn_impl {
         pub const fn rotate_left(self, n: u32) -> Self {
             let nsz = n as usize % Self::NUM_BITS;
             let accumulator = self.as_num();
-            let remainder = accumulator << n;
+            let remainder = accumulator << nsz;
             let shift_off = accumulator >> (Self::NUM_BITS - nsz);
             let assembled = remainder | shift_off;
             return Self::from_num(assembled);
@@ -1217,9 +1216,10 @@ macro_rules! common_fn_impl {
         #[inline]
         pub const fn reverse_bits(self) -> Self {
             let mut swap = self.swap_bytes();
-            let n = 0;
+            let mut n = 0;
             while n < swap.0.len() {
                 swap.0[n] = swap.0[n].reverse_bits();
+                n += 1;
             }
 
             return swap;
@@ -1255,7 +1255,13 @@ macro_rules! common_fn_impl {
         #[inline]
         pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
             return match self.as_num().checked_mul(rhs.as_num()) {
-                Some(x) => Some(Self::from_num(x)),
+                Some(x) => {
+                    if x & !Self::MAX_VALUE != 0 {
+                        return None;
+                    }
+
+                    return Some(Self::from_num(x));
+                }
                 None => None,
             };
         }
@@ -1302,7 +1308,7 @@ macro_rules! common_fn_impl {
         #[inline]
         pub const fn next_multiple_of(self, rhs: Self) -> Self {
             let result = self.as_num().next_multiple_of(rhs.as_num());
-            debug_assert!(result > Self::MAX_VALUE, "Overflow");
+            debug_assert!(result <= Self::MAX_VALUE, "Overflow");
             return Self::from_num(result);
         }
 
@@ -1311,7 +1317,7 @@ macro_rules! common_fn_impl {
         ///
         #[inline]
         pub const fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
-            return match self.as_num().checked_mul(rhs.as_num()) {
+            return match self.as_num().checked_next_multiple_of(rhs.as_num()) {
                 Some(result) => {
                     if result & !Self::MAX_VALUE != 0 {
                         return None;
@@ -1338,7 +1344,7 @@ macro_rules! common_fn_impl {
         #[inline]
         pub const fn next_power_of_two(self) -> Self {
             let result = self.as_num().next_power_of_two();
-            debug_assert!(result > Self::MAX_VALUE, "Overflow");
+            debug_assert!(result <= Self::MAX_VALUE, "Overflow");
             return Self::from_num(result);
         }
 
@@ -1361,26 +1367,20 @@ macro_rules! common_fn_impl {
 
         ///
         /// Shift the number to the right checking for overflow.
+        /// If rhs is not smaller than NUM_BITS the result of wrapping_shr is returned together with true.
         ///
         #[inline]
         pub const fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
-            if rhs >= Self::NUM_BITS as u32 {
-                return (Self::from_num(self.as_num() >> (Self::NUM_BITS - 1)), true);
-            }
-
-            return (Self::from_num(self.as_num() >> rhs), false);
+            return (self.wrapping_shr(rhs), rhs >= Self::NUM_BITS as u32);
         }
 
         ///
         /// Shift the number to the left checking for overflow.
+        /// If rhs is not smaller than NUM_BITS the result of wrapping_shl is returned together with true.
         ///
         #[inline]
         pub const fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
-            if rhs >= Self::NUM_BITS as u32 {
-                return (Self::from_num(self.as_num() << (Self::NUM_BITS - 1)), true);
-            }
-
-            return (Self::from_num(self.as_num() << rhs), false);
+            return (self.wrapping_shl(rhs), rhs >= Self::NUM_BITS as u32);
         }
 
         ///
@@ -1486,19 +1486,21 @@ macro_rules! common_fn_impl {
         }
 
         ///
-        /// shift right operation that ignores overflow
+        /// shift right operation that ignores overflow.
+        /// The shift amount is taken modulo NUM_BITS.
         ///
         #[inline]
         pub const fn wrapping_shr(self, rhs: u32) -> Self {
-            return Self::from_num(self.as_num().wrapping_shr(rhs));
+            return Self::from_num(self.as_num() >> (rhs % Self::NUM_BITS as u32));
         }
 
         ///
-        /// shift left operation that ignores overflow
+        /// shift left operation that ignores overflow.
+        /// The shift amount is taken modulo NUM_BITS.
         ///
         #[inline]
         pub const fn wrapping_shl(self, rhs: u32) -> Self {
-            return Self::from_num(self.as_num().wrapping_shl(rhs));
+            return Self::from_num(self.as_num() << (rhs % Self::NUM_BITS as u32));
         }
 
         ///
@@ -1800,6 +1802,241 @@ macro_rules! common_fn_impl {
         pub const fn pow(self, exp: u32) -> Self {
             Self::from_num_checked(self.as_num().pow(exp))
         }
+
+        ///
+        /// Calculates the square root of the number rounded down.
+        ///
+        #[inline]
+        pub const fn isqrt(self) -> Self {
+            Self::from_num(self.as_num().isqrt())
+        }
+
+        ///
+        /// Calculates the square root of the number rounded down.
+        /// This never returns None since the number is unsigned,
+        /// it only exists for parity with the api of the signed primitive types.
+        ///
+        #[inline]
+        pub const fn checked_isqrt(self) -> Option<Self> {
+            Some(self.isqrt())
+        }
+
+        ///
+        /// Calculates the greatest common divisor of both numbers.
+        /// The gcd of 0 and n is n.
+        ///
+        #[inline]
+        pub const fn gcd(self, other: Self) -> Self {
+            let mut a = self.as_num();
+            let mut b = other.as_num();
+            if a == 0 {
+                return other;
+            }
+
+            if b == 0 {
+                return self;
+            }
+
+            let shift = (a | b).trailing_zeros();
+            a >>= a.trailing_zeros();
+            loop {
+                b >>= b.trailing_zeros();
+                if a > b {
+                    let tmp = a;
+                    a = b;
+                    b = tmp;
+                }
+
+                b -= a;
+                if b == 0 {
+                    return Self::from_num(a << shift);
+                }
+            }
+        }
+
+        ///
+        /// Calculates the least common multiple of both numbers.
+        /// The lcm of 0 and n is 0.
+        /// Overflow is handled like it is in mul.
+        ///
+        #[inline]
+        pub const fn lcm(self, other: Self) -> Self {
+            if self.as_num() == 0 || other.as_num() == 0 {
+                return Self::MIN;
+            }
+
+            Self::from_num_checked(self.as_num() / self.gcd(other).as_num() * other.as_num())
+        }
+
+        ///
+        /// Calculates the least common multiple of both numbers.
+        /// Returns None if the result does not fit into the type.
+        ///
+        #[inline]
+        pub const fn checked_lcm(self, other: Self) -> Option<Self> {
+            if self.as_num() == 0 || other.as_num() == 0 {
+                return Some(Self::MIN);
+            }
+
+            Self::from_num(self.as_num() / self.gcd(other).as_num()).checked_mul(other)
+        }
+
+        ///
+        /// Calculates (self * rhs) % modulus without overflowing for any input.
+        /// This function will panic if modulus is 0.
+        ///
+        #[inline]
+        pub const fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+            let m = modulus.as_num();
+            let a = self.as_num() % m;
+            let b = rhs.as_num() % m;
+            if let Some(product) = a.checked_mul(b) {
+                return Self::from_num(product % m);
+            }
+
+            //Horner scheme over the bytes of b starting with the most significant byte.
+            //All values are smaller than 2^NUM_BITS and the helper type has at least 8 spare bits,
+            //so neither the shift nor the multiplication by a single byte can overflow.
+            let mut acc: $helper = 0;
+            let mut idx = $size;
+            while idx > 0 {
+                idx -= 1;
+                let digit = (b >> (idx * 8) as u32) & 0xFF;
+                acc = ((acc << 8) % m + (a * digit) % m) % m;
+            }
+
+            Self::from_num(acc)
+        }
+
+        ///
+        /// Calculates (self ^ exp) % modulus without overflowing for any input.
+        /// This function will panic if modulus is 0.
+        ///
+        #[inline]
+        pub const fn pow_mod(self, exp: Self, modulus: Self) -> Self {
+            let mut base = self.rem(modulus);
+            let mut result = Self::from_num(1 % modulus.as_num());
+            let mut e = exp.as_num();
+            while e > 0 {
+                if e & 1 == 1 {
+                    result = result.mul_mod(base, modulus);
+                }
+
+                base = base.mul_mod(base, modulus);
+                e >>= 1;
+            }
+
+            result
+        }
+
+        ///
+        /// Calculates the modular multiplicative inverse of self.
+        /// The result multiplied with self is 1 modulo the modulus.
+        /// Returns None if modulus is 0 or self and modulus are not coprime.
+        ///
+        #[inline]
+        pub const fn inv_mod(self, modulus: Self) -> Option<Self> {
+            let m = modulus.as_num();
+            if m == 0 {
+                return None;
+            }
+
+            //Extended euclidean algorithm, the coefficients are kept in the range 0..m.
+            let mut r0 = m;
+            let mut r1 = self.as_num() % m;
+            let mut t0: $helper = 0;
+            let mut t1: $helper = 1 % m;
+            while r1 != 0 {
+                let q = r0 / r1;
+                let r2 = r0 - q * r1;
+                let qt = Self::from_num(q % m).mul_mod(Self::from_num(t1), modulus).as_num();
+                let t2 = if t0 >= qt { t0 - qt } else { t0 + (m - qt) };
+                r0 = r1;
+                r1 = r2;
+                t0 = t1;
+                t1 = t2;
+            }
+
+            if r0 != 1 {
+                return None;
+            }
+
+            Some(Self::from_num(t0))
+        }
+    };
+}
+
+#[doc(hidden)]
+macro_rules! bit_access_impl {
+    ($source:ty, $helper:ty) => {
+        ///
+        /// Returns the bits in range shifted down to bit 0.
+        /// Bit 0 is the least significant bit.
+        ///
+        /// Panics if the range is reversed or ends beyond NUM_BITS.
+        ///
+        #[inline]
+        pub const fn get_bits(self, range: core::ops::Range<u32>) -> $helper {
+            assert!(range.start <= range.end && range.end as usize <= Self::NUM_BITS, "bit range out of bounds");
+            //The helper always has spare bits, so the shift cannot overflow.
+            let mask = ((1 as $helper) << (range.end - range.start)) - 1;
+            (self.as_num() >> range.start) & mask
+        }
+
+        ///
+        /// Returns a copy with the bits in range replaced by value.
+        ///
+        /// Panics if the range is reversed, ends beyond NUM_BITS or value does not fit into the range.
+        ///
+        #[inline]
+        pub const fn with_bits(self, range: core::ops::Range<u32>, value: $helper) -> Self {
+            assert!(range.start <= range.end && range.end as usize <= Self::NUM_BITS, "bit range out of bounds");
+            let mask = ((1 as $helper) << (range.end - range.start)) - 1;
+            assert!(value & !mask == 0, "value does not fit into the bit range");
+            Self::from_num((self.as_num() & !(mask << range.start)) | (value << range.start))
+        }
+
+        ///
+        /// Replaces the bits in range by value.
+        ///
+        /// Panics if the range is reversed, ends beyond NUM_BITS or value does not fit into the range.
+        ///
+        #[inline]
+        pub const fn set_bits(&mut self, range: core::ops::Range<u32>, value: $helper) {
+            *self = self.with_bits(range, value);
+        }
+
+        ///
+        /// Returns the bit at index. Bit 0 is the least significant bit.
+        ///
+        /// Panics if index is not smaller than NUM_BITS.
+        ///
+        #[inline]
+        pub const fn get_bit(self, index: u32) -> bool {
+            assert!((index as usize) < Self::NUM_BITS, "bit index out of bounds");
+            (self.as_num() >> index) & 1 == 1
+        }
+
+        ///
+        /// Sets the bit at index to value.
+        ///
+        /// Panics if index is not smaller than NUM_BITS.
+        ///
+        #[inline]
+        pub const fn set_bit(&mut self, index: u32, value: bool) {
+            *self = self.with_bits(index..index + 1, value as $helper);
+        }
+
+        ///
+        /// Inverts the bit at index.
+        ///
+        /// Panics if index is not smaller than NUM_BITS.
+        ///
+        #[inline]
+        pub const fn toggle_bit(&mut self, index: u32) {
+            assert!((index as usize) < Self::NUM_BITS, "bit index out of bounds");
+            *self = Self::from_num(self.as_num() ^ ((1 as $helper) << index));
+        }
     };
 }
 
@@ -2487,6 +2724,64 @@ macro_rules! num_traits_impl {
     };
 }
 
+#[cfg(feature = "num_integer_support")]
+#[doc(hidden)]
+macro_rules! num_integer_impl {
+    ($source:ty) => {
+        impl num_integer::Integer for $source {
+            fn div_floor(&self, other: &Self) -> Self {
+                Self::div(*self, *other)
+            }
+
+            fn mod_floor(&self, other: &Self) -> Self {
+                Self::rem(*self, *other)
+            }
+
+            fn gcd(&self, other: &Self) -> Self {
+                Self::gcd(*self, *other)
+            }
+
+            fn lcm(&self, other: &Self) -> Self {
+                Self::lcm(*self, *other)
+            }
+
+            fn is_multiple_of(&self, other: &Self) -> bool {
+                if other.as_num() == 0 {
+                    return self.as_num() == 0;
+                }
+
+                self.as_num() % other.as_num() == 0
+            }
+
+            fn is_even(&self) -> bool {
+                self.as_num() & 1 == 0
+            }
+
+            fn is_odd(&self) -> bool {
+                self.as_num() & 1 == 1
+            }
+
+            fn div_rem(&self, other: &Self) -> (Self, Self) {
+                (Self::div(*self, *other), Self::rem(*self, *other))
+            }
+        }
+
+        impl num_integer::Roots for $source {
+            fn nth_root(&self, n: u32) -> Self {
+                Self::from_num(num_integer::Roots::nth_root(&self.as_num(), n))
+            }
+
+            fn sqrt(&self) -> Self {
+                Self::isqrt(*self)
+            }
+
+            fn cbrt(&self) -> Self {
+                Self::from_num(num_integer::Roots::cbrt(&self.as_num()))
+            }
+        }
+    };
+}
+
 //
 // The guy forgot to implement Into trait for the wrapper of any 128 bit aligned types. :(
 // We got format tho and can format+parse. Slow but it works oh well...
@@ -2678,28 +2973,28 @@ macro_rules! intx_conv_impl {
 
         impl Into<$intx_type> for $uintx_type {
             fn into(self) -> $intx_type {
-                let helper: $uintx_intermediary = self.into();
+                let helper = <$uintx_intermediary>::from(self.as_num() as $intx_intermediary);
                 return <$intx_type>::from_ne_bytes(helper.to_ne_bytes());
             }
         }
 
         impl Into<$intx_type> for &$uintx_type {
             fn into(self) -> $intx_type {
-                let helper: $uintx_intermediary = self.into();
+                let helper = <$uintx_intermediary>::from(self.as_num() as $intx_intermediary);
                 return <$intx_type>::from_ne_bytes(helper.to_ne_bytes());
             }
         }
 
         impl Into<$intx_signed_type> for $uintx_type {
             fn into(self) -> $intx_signed_type {
-                let helper: $uintx_intermediary = self.into();
+                let helper = <$uintx_intermediary>::from(self.as_num() as $intx_intermediary);
                 return <$intx_signed_type>::from_ne_bytes(helper.to_ne_bytes());
             }
         }
 
         impl Into<$intx_signed_type> for &$uintx_type {
             fn into(self) -> $intx_signed_type {
-                let helper: $uintx_intermediary = self.into();
+                let helper = <$uintx_intermediary>::from(self.as_num() as $intx_intermediary);
                 return <$intx_signed_type>::from_ne_bytes(helper.to_ne_bytes());
             }
         }
@@ -2722,20 +3017,429 @@ macro_rules! from_to_impl_for_primitive {
             }
         }
 
-        impl Into<$target> for $source {
-            fn into(self) -> $target {
-                return self.as_num() as $target;
+    };
+}
+
+#[doc(hidden)]
+macro_rules! primitive_conv_impl {
+    //$lossless are the primitives that hold every value of $source, the $narrowing ones only get TryFrom.
+    ($source:ty, lossless: $($lossless:ty),*; narrowing: $($narrowing:ty),*) => {
+        $(
+            impl From<$source> for $lossless {
+                fn from(value: $source) -> Self {
+                    value.as_num() as $lossless
+                }
+            }
+
+            impl From<&$source> for $lossless {
+                fn from(value: &$source) -> Self {
+                    value.as_num() as $lossless
+                }
+            }
+        )*
+
+        $(
+            impl TryFrom<$source> for $narrowing {
+                type Error = core::num::TryFromIntError;
+
+                fn try_from(value: $source) -> Result<Self, Self::Error> {
+                    <$narrowing>::try_from(value.as_num())
+                }
+            }
+        )*
+    };
+}
+
+
+#[doc(hidden)]
+macro_rules! wrapper_binop_impl {
+    ($wrapper:ident, $source:ty, $op:ident, $fn:ident, $assign_op:ident, $assign_fn:ident, $method:expr) => {
+        impl core::ops::$op for crate::$wrapper<$source> {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: Self) -> Self {
+                crate::$wrapper($method(self.0, rhs.0))
+            }
+        }
+
+        impl core::ops::$op<&crate::$wrapper<$source>> for crate::$wrapper<$source> {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: &Self) -> Self {
+                crate::$wrapper($method(self.0, rhs.0))
+            }
+        }
+
+        impl core::ops::$assign_op for crate::$wrapper<$source> {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: Self) {
+                self.0 = $method(self.0, rhs.0);
+            }
+        }
+
+        impl core::ops::$assign_op<&crate::$wrapper<$source>> for crate::$wrapper<$source> {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: &Self) {
+                self.0 = $method(self.0, rhs.0);
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+macro_rules! checked_binop_impl {
+    ($source:ty, $op:ident, $fn:ident, $assign_op:ident, $assign_fn:ident, $rhs:ty, |$a:ident, $b:ident| $body:expr) => {
+        impl core::ops::$op<$rhs> for crate::Checked<$source> {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: $rhs) -> Self {
+                let rhs: crate::Checked<$source> = rhs.into();
+                crate::Checked(match (self.0, rhs.0) {
+                    (Some($a), Some($b)) => $body,
+                    _ => None,
+                })
+            }
+        }
+
+        impl core::ops::$assign_op<$rhs> for crate::Checked<$source> {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: $rhs) {
+                *self = core::ops::$op::$fn(*self, rhs);
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+macro_rules! wrapper_impl {
+    ($source:ty) => {
+        crate::wrapper_binop_impl!(Wrapping, $source, Add, add, AddAssign, add_assign, <$source>::wrapping_add);
+        crate::wrapper_binop_impl!(Wrapping, $source, Sub, sub, SubAssign, sub_assign, <$source>::wrapping_sub);
+        crate::wrapper_binop_impl!(Wrapping, $source, Mul, mul, MulAssign, mul_assign, <$source>::wrapping_mul);
+        crate::wrapper_binop_impl!(Wrapping, $source, Div, div, DivAssign, div_assign, <$source>::wrapping_div);
+        crate::wrapper_binop_impl!(Wrapping, $source, Rem, rem, RemAssign, rem_assign, <$source>::wrapping_rem);
+        crate::wrapper_binop_impl!(Wrapping, $source, BitAnd, bitand, BitAndAssign, bitand_assign, core::ops::BitAnd::bitand);
+        crate::wrapper_binop_impl!(Wrapping, $source, BitOr, bitor, BitOrAssign, bitor_assign, core::ops::BitOr::bitor);
+        crate::wrapper_binop_impl!(Wrapping, $source, BitXor, bitxor, BitXorAssign, bitxor_assign, core::ops::BitXor::bitxor);
+
+        crate::wrapper_binop_impl!(Saturating, $source, Add, add, AddAssign, add_assign, <$source>::saturating_add);
+        crate::wrapper_binop_impl!(Saturating, $source, Sub, sub, SubAssign, sub_assign, <$source>::saturating_sub);
+        crate::wrapper_binop_impl!(Saturating, $source, Mul, mul, MulAssign, mul_assign, <$source>::saturating_mul);
+        crate::wrapper_binop_impl!(Saturating, $source, Div, div, DivAssign, div_assign, <$source>::saturating_div);
+        //The remainder of unsigned numbers can not overflow.
+        crate::wrapper_binop_impl!(Saturating, $source, Rem, rem, RemAssign, rem_assign, <$source>::rem);
+        crate::wrapper_binop_impl!(Saturating, $source, BitAnd, bitand, BitAndAssign, bitand_assign, core::ops::BitAnd::bitand);
+        crate::wrapper_binop_impl!(Saturating, $source, BitOr, bitor, BitOrAssign, bitor_assign, core::ops::BitOr::bitor);
+        crate::wrapper_binop_impl!(Saturating, $source, BitXor, bitxor, BitXorAssign, bitxor_assign, core::ops::BitXor::bitxor);
+
+        impl core::ops::Neg for crate::Wrapping<$source> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                crate::Wrapping(self.0.wrapping_neg())
+            }
+        }
+
+        impl core::ops::Not for crate::Wrapping<$source> {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self {
+                crate::Wrapping(!self.0)
+            }
+        }
+
+        impl core::ops::Not for crate::Saturating<$source> {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self {
+                crate::Saturating(!self.0)
+            }
+        }
+
+        impl core::ops::Shl<u32> for crate::Wrapping<$source> {
+            type Output = Self;
+
+            #[inline]
+            fn shl(self, rhs: u32) -> Self {
+                crate::Wrapping(self.0.wrapping_shl(rhs))
+            }
+        }
+
+        impl core::ops::Shr<u32> for crate::Wrapping<$source> {
+            type Output = Self;
+
+            #[inline]
+            fn shr(self, rhs: u32) -> Self {
+                crate::Wrapping(self.0.wrapping_shr(rhs))
+            }
+        }
+
+        impl core::ops::ShlAssign<u32> for crate::Wrapping<$source> {
+            #[inline]
+            fn shl_assign(&mut self, rhs: u32) {
+                self.0 = self.0.wrapping_shl(rhs);
+            }
+        }
+
+        impl core::ops::ShrAssign<u32> for crate::Wrapping<$source> {
+            #[inline]
+            fn shr_assign(&mut self, rhs: u32) {
+                self.0 = self.0.wrapping_shr(rhs);
+            }
+        }
+
+        impl core::iter::Sum for crate::Wrapping<$source> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::default(), |a, b| a + b)
+            }
+        }
+
+        impl core::iter::Product for crate::Wrapping<$source> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(crate::Wrapping(<$source>::from(1u8)), |a, b| a * b)
+            }
+        }
+
+        impl core::iter::Sum for crate::Saturating<$source> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::default(), |a, b| a + b)
+            }
+        }
+
+        impl core::iter::Product for crate::Saturating<$source> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(crate::Saturating(<$source>::from(1u8)), |a, b| a * b)
+            }
+        }
+
+        crate::checked_binop_impl!($source, Add, add, AddAssign, add_assign, crate::Checked<$source>, |a, b| a.checked_add(b));
+        crate::checked_binop_impl!($source, Sub, sub, SubAssign, sub_assign, crate::Checked<$source>, |a, b| a.checked_sub(b));
+        crate::checked_binop_impl!($source, Mul, mul, MulAssign, mul_assign, crate::Checked<$source>, |a, b| a.checked_mul(b));
+        crate::checked_binop_impl!($source, Div, div, DivAssign, div_assign, crate::Checked<$source>, |a, b| a.checked_div(b));
+        crate::checked_binop_impl!($source, Rem, rem, RemAssign, rem_assign, crate::Checked<$source>, |a, b| a.checked_rem(b));
+        crate::checked_binop_impl!($source, BitAnd, bitand, BitAndAssign, bitand_assign, crate::Checked<$source>, |a, b| Some(a & b));
+        crate::checked_binop_impl!($source, BitOr, bitor, BitOrAssign, bitor_assign, crate::Checked<$source>, |a, b| Some(a | b));
+        crate::checked_binop_impl!($source, BitXor, bitxor, BitXorAssign, bitxor_assign, crate::Checked<$source>, |a, b| Some(a ^ b));
+        crate::checked_binop_impl!($source, Add, add, AddAssign, add_assign, $source, |a, b| a.checked_add(b));
+        crate::checked_binop_impl!($source, Sub, sub, SubAssign, sub_assign, $source, |a, b| a.checked_sub(b));
+        crate::checked_binop_impl!($source, Mul, mul, MulAssign, mul_assign, $source, |a, b| a.checked_mul(b));
+        crate::checked_binop_impl!($source, Div, div, DivAssign, div_assign, $source, |a, b| a.checked_div(b));
+        crate::checked_binop_impl!($source, Rem, rem, RemAssign, rem_assign, $source, |a, b| a.checked_rem(b));
+        crate::checked_binop_impl!($source, BitAnd, bitand, BitAndAssign, bitand_assign, $source, |a, b| Some(a & b));
+        crate::checked_binop_impl!($source, BitOr, bitor, BitOrAssign, bitor_assign, $source, |a, b| Some(a | b));
+        crate::checked_binop_impl!($source, BitXor, bitxor, BitXorAssign, bitxor_assign, $source, |a, b| Some(a ^ b));
+
+        impl core::ops::Neg for crate::Checked<$source> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                crate::Checked(self.0.and_then(<$source>::checked_neg))
+            }
+        }
+
+        impl core::ops::Not for crate::Checked<$source> {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self {
+                crate::Checked(self.0.map(|v| !v))
+            }
+        }
+
+        impl core::ops::Shl<u32> for crate::Checked<$source> {
+            type Output = Self;
+
+            #[inline]
+            fn shl(self, rhs: u32) -> Self {
+                crate::Checked(self.0.and_then(|v| v.checked_shl(rhs)))
+            }
+        }
+
+        impl core::ops::Shr<u32> for crate::Checked<$source> {
+            type Output = Self;
+
+            #[inline]
+            fn shr(self, rhs: u32) -> Self {
+                crate::Checked(self.0.and_then(|v| v.checked_shr(rhs)))
+            }
+        }
+
+        impl core::ops::ShlAssign<u32> for crate::Checked<$source> {
+            #[inline]
+            fn shl_assign(&mut self, rhs: u32) {
+                *self = *self << rhs;
+            }
+        }
+
+        impl core::ops::ShrAssign<u32> for crate::Checked<$source> {
+            #[inline]
+            fn shr_assign(&mut self, rhs: u32) {
+                *self = *self >> rhs;
+            }
+        }
+
+        impl core::iter::Sum for crate::Checked<$source> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::default(), |a, b| a + b)
+            }
+        }
+
+        impl core::iter::Product for crate::Checked<$source> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(crate::Checked::new(<$source>::from(1u8)), |a, b| a * b)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+macro_rules! usize_conv_impl {
+    //$lossless is the cfg predicate of the targets whose usize and isize hold every value of $source.
+    ($source:ty, $helper:ty, $($lossless:tt)*) => {
+        impl TryFrom<usize> for $source {
+            type Error = core::num::TryFromIntError;
+
+            fn try_from(value: usize) -> Result<Self, Self::Error> {
+                let n = <$helper>::try_from(value)?;
+                if n > Self::MAX_VALUE {
+                    //TryFromIntError can only be created by a failing conversion.
+                    return Err(u8::try_from(u16::MAX).unwrap_err());
+                }
+
+                Ok(Self::from_num(n))
+            }
+        }
+
+        impl TryFrom<isize> for $source {
+            type Error = core::num::TryFromIntError;
+
+            fn try_from(value: isize) -> Result<Self, Self::Error> {
+                let n = <$helper>::try_from(value)?;
+                if n > Self::MAX_VALUE {
+                    return Err(u8::try_from(u16::MAX).unwrap_err());
+                }
+
+                Ok(Self::from_num(n))
             }
         }
 
-        impl Into<$target> for &$source {
-            fn into(self) -> $target {
-                return self.as_num() as $target;
+        #[cfg($($lossless)*)]
+        impl From<$source> for usize {
+            fn from(value: $source) -> Self {
+                value.as_num() as usize
+            }
+        }
+
+        #[cfg($($lossless)*)]
+        impl From<&$source> for usize {
+            fn from(value: &$source) -> Self {
+                value.as_num() as usize
+            }
+        }
+
+        #[cfg($($lossless)*)]
+        impl From<$source> for isize {
+            fn from(value: $source) -> Self {
+                value.as_num() as isize
+            }
+        }
+
+        #[cfg($($lossless)*)]
+        impl From<&$source> for isize {
+            fn from(value: &$source) -> Self {
+                value.as_num() as isize
+            }
+        }
+
+        #[cfg(not($($lossless)*))]
+        impl TryFrom<$source> for usize {
+            type Error = core::num::TryFromIntError;
+
+            fn try_from(value: $source) -> Result<Self, Self::Error> {
+                usize::try_from(value.as_num())
+            }
+        }
+
+        #[cfg(not($($lossless)*))]
+        impl TryFrom<$source> for isize {
+            type Error = core::num::TryFromIntError;
+
+            fn try_from(value: $source) -> Result<Self, Self::Error> {
+                isize::try_from(value.as_num())
             }
         }
     };
 }
 
+#[doc(hidden)]
+macro_rules! primitive_cmp_impl {
+    ($source:ty, unsigned: $($primitive:ty),*) => {
+        $(
+            impl PartialEq<$primitive> for $source {
+                fn eq(&self, other: &$primitive) -> bool {
+                    self.as_num() as u128 == *other as u128
+                }
+            }
+
+            impl PartialEq<$source> for $primitive {
+                fn eq(&self, other: &$source) -> bool {
+                    other == self
+                }
+            }
+
+            impl PartialOrd<$primitive> for $source {
+                fn partial_cmp(&self, other: &$primitive) -> Option<core::cmp::Ordering> {
+                    (self.as_num() as u128).partial_cmp(&(*other as u128))
+                }
+            }
+
+            impl PartialOrd<$source> for $primitive {
+                fn partial_cmp(&self, other: &$source) -> Option<core::cmp::Ordering> {
+                    other.partial_cmp(self).map(core::cmp::Ordering::reverse)
+                }
+            }
+        )*
+    };
+    ($source:ty, signed: $($primitive:ty),*) => {
+        $(
+            impl PartialEq<$primitive> for $source {
+                fn eq(&self, other: &$primitive) -> bool {
+                    *other >= 0 && self.as_num() as u128 == *other as u128
+                }
+            }
+
+            impl PartialEq<$source> for $primitive {
+                fn eq(&self, other: &$source) -> bool {
+                    other == self
+                }
+            }
+
+            impl PartialOrd<$primitive> for $source {
+                fn partial_cmp(&self, other: &$primitive) -> Option<core::cmp::Ordering> {
+                    //Negative values are smaller than every unsigned value.
+                    if *other < 0 {
+                        return Some(core::cmp::Ordering::Greater);
+                    }
+
+                    (self.as_num() as u128).partial_cmp(&(*other as u128))
+                }
+            }
+
+            impl PartialOrd<$source> for $primitive {
+                fn partial_cmp(&self, other: &$source) -> Option<core::cmp::Ordering> {
+                    other.partial_cmp(self).map(core::cmp::Ordering::reverse)
+                }
+            }
+        )*
+    };
+}
 
 #[doc(hidden)]
 macro_rules! sh_impl_conv {
@@ -2860,6 +3564,256 @@ macro_rules! sh_impl {
     };
 }
 
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+#[doc(hidden)]
+macro_rules! arbitrary_impl {
+    ($source:ty) => {
+        #[cfg(feature = "arbitrary")]
+        impl<'a> arbitrary::Arbitrary<'a> for $source {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                crate::edge_values::arbitrary(u)
+            }
+
+            fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                crate::edge_values::arbitrary_size_hint::<$source>()
+            }
+        }
+
+        #[cfg(feature = "proptest")]
+        impl proptest::arbitrary::Arbitrary for $source {
+            type Parameters = ();
+            type Strategy = proptest::strategy::BoxedStrategy<$source>;
+
+            fn arbitrary_with(_args: ()) -> Self::Strategy {
+                crate::edge_values::strategy()
+            }
+        }
+
+        #[cfg(feature = "quickcheck")]
+        impl quickcheck::Arbitrary for $source {
+            fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                crate::edge_values::quickcheck_arbitrary(g)
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                crate::edge_values::quickcheck_shrink(*self)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "rkyv")]
+#[doc(hidden)]
+macro_rules! rkyv_impl {
+    ($source:ty) => {
+        impl rkyv::Archive for $source {
+            //Slices can be copied as a whole when the native byte order already is little endian.
+            const COPY_OPTIMIZATION: rkyv::traits::CopyOptimization<Self> =
+                unsafe { rkyv::traits::CopyOptimization::enable_if(cfg!(target_endian = "little")) };
+
+            type Archived = crate::ArchivedLe<$source>;
+            type Resolver = ();
+
+            #[inline]
+            fn resolve(&self, _: Self::Resolver, out: rkyv::Place<Self::Archived>) {
+                crate::rkyv_support::resolve(self, out);
+            }
+        }
+
+        impl<S: rkyv::rancor::Fallible + ?Sized> rkyv::Serialize<S> for $source {
+            #[inline]
+            fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+                Ok(())
+            }
+        }
+
+        impl<D: rkyv::rancor::Fallible + ?Sized> rkyv::Deserialize<$source, D> for crate::ArchivedLe<$source> {
+            #[inline]
+            fn deserialize(&self, _: &mut D) -> Result<$source, D::Error> {
+                Ok(self.to_native())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+macro_rules! serde_impl {
+    ($source:ty) => {
+        impl serde::Serialize for $source {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                crate::serde_support::serialize(self, stringify!($source), serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $source {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                crate::serde_support::deserialize(stringify!($source), deserializer)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+macro_rules! rand_impl {
+    ($source:ty) => {
+        impl rand::distributions::Distribution<$source> for rand::distributions::Standard {
+            #[inline]
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $source {
+                crate::distributions::sample_standard(rng)
+            }
+        }
+
+        impl rand::distributions::uniform::SampleUniform for $source {
+            type Sampler = crate::distributions::UniformUintx<$source>;
+        }
+    };
+}
+
+#[doc(hidden)]
+macro_rules! unaligned_uint_impl {
+    ($source:ty, $helper:ty, $size:literal) => {
+        impl crate::unaligned_uint::private::Sealed for $source {}
+
+        impl crate::UnalignedUint for $source {
+            type Helper = $helper;
+            type Bytes = [u8; $size];
+
+            const BYTES: usize = $size;
+            const NUM_BITS: usize = <$source>::NUM_BITS;
+            const MAX: Self = <$source>::MAX;
+            const MIN: Self = <$source>::MIN;
+            const MAX_VALUE: $helper = <$source>::MAX_VALUE;
+
+            #[inline]
+            fn as_num(self) -> $helper {
+                <$source>::as_num(self)
+            }
+
+            #[inline]
+            fn from_num_truncated(n: $helper) -> Self {
+                <$source>::from_num(n)
+            }
+
+            #[inline]
+            fn as_u128(self) -> u128 {
+                <$source>::as_num(self) as u128
+            }
+
+            #[inline]
+            fn from_u128_truncated(n: u128) -> Self {
+                <$source>::from_num(n as $helper)
+            }
+
+            #[inline]
+            fn from_le_bytes(data: [u8; $size]) -> Self {
+                <$source>::from_le_bytes(data)
+            }
+
+            #[inline]
+            fn from_be_bytes(data: [u8; $size]) -> Self {
+                <$source>::from_be_bytes(data)
+            }
+
+            #[inline]
+            fn from_ne_bytes(data: [u8; $size]) -> Self {
+                <$source>::from_ne_bytes(data)
+            }
+
+            #[inline]
+            fn to_le_bytes(self) -> [u8; $size] {
+                <$source>::to_le_bytes(self)
+            }
+
+            #[inline]
+            fn to_be_bytes(self) -> [u8; $size] {
+                <$source>::to_be_bytes(self)
+            }
+
+            #[inline]
+            fn to_ne_bytes(self) -> [u8; $size] {
+                <$source>::to_ne_bytes(self)
+            }
+
+            #[inline]
+            fn shl(self, rhs: u32) -> Self {
+                <$source>::shl(self, rhs)
+            }
+
+            #[inline]
+            fn shr(self, rhs: u32) -> Self {
+                <$source>::shr(self, rhs)
+            }
+
+            #[inline]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$source>::checked_add(self, rhs)
+            }
+
+            #[inline]
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$source>::checked_sub(self, rhs)
+            }
+
+            #[inline]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$source>::checked_mul(self, rhs)
+            }
+
+            #[inline]
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$source>::checked_div(self, rhs)
+            }
+
+            #[inline]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$source>::wrapping_add(self, rhs)
+            }
+
+            #[inline]
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                <$source>::wrapping_sub(self, rhs)
+            }
+
+            #[inline]
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$source>::wrapping_mul(self, rhs)
+            }
+
+            #[inline]
+            fn saturating_add(self, rhs: Self) -> Self {
+                <$source>::saturating_add(self, rhs)
+            }
+
+            #[inline]
+            fn saturating_sub(self, rhs: Self) -> Self {
+                <$source>::saturating_sub(self, rhs)
+            }
+
+            #[inline]
+            fn saturating_mul(self, rhs: Self) -> Self {
+                <$source>::saturating_mul(self, rhs)
+            }
+
+            #[inline]
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                <$source>::overflowing_add(self, rhs)
+            }
+
+            #[inline]
+            fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                <$source>::overflowing_sub(self, rhs)
+            }
+
+            #[inline]
+            fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                <$source>::overflowing_mul(self, rhs)
+            }
+        }
+    };
+}
+
 #[doc(hidden)]
 macro_rules! type_impl {
     ($source:ty, $helper:ty, $size:literal) => {
@@ -2888,6 +3842,7 @@ macro_rules! type_impl {
                 (core::mem::size_of::<$helper>() - $size) * 8;
 
             crate::common_fn_impl!($source, $helper, $size);
+            crate::bit_access_impl!($source, $helper);
 
             #[cfg(feature = "unsafe_fetch")]
             crate::unsafe_fetch_impl!($source, $helper);
@@ -2899,9 +3854,9 @@ macro_rules! type_impl {
             }
         }
 
-        impl Into<[u8; $size]> for $source {
-            fn into(self) -> [u8; $size] {
-                return self.0;
+        impl From<$source> for [u8; $size] {
+            fn from(value: $source) -> Self {
+                return value.0;
             }
         }
 
@@ -2911,16 +3866,16 @@ macro_rules! type_impl {
             }
         }
 
-        impl Into<[u8; $size]> for &$source {
-            fn into(self) -> [u8; $size] {
-                return self.0.clone();
+        impl From<&$source> for [u8; $size] {
+            fn from(value: &$source) -> Self {
+                return value.0.clone();
             }
         }
 
         #[cfg(feature = "half_support")]
-        impl Into<half::f16> for $source {
-            fn into(self) -> half::f16 {
-                return half::f16::from_f32(self.as_num() as f32);
+        impl From<$source> for half::f16 {
+            fn from(value: $source) -> Self {
+                return half::f16::from_f32(value.as_num() as f32);
             }
         }
 
@@ -2932,9 +3887,9 @@ macro_rules! type_impl {
         }
 
         #[cfg(feature = "half_support")]
-        impl Into<half::f16> for &$source {
-            fn into(self) -> half::f16 {
-                return half::f16::from_f32(self.as_num() as f32);
+        impl From<&$source> for half::f16 {
+            fn from(value: &$source) -> Self {
+                return half::f16::from_f32(value.as_num() as f32);
             }
         }
 
@@ -2946,6 +3901,22 @@ macro_rules! type_impl {
         }
 
         crate::common_traits_impl!($source, $helper, $size);
+        crate::unaligned_uint_impl!($source, $helper, $size);
+
+        #[cfg(feature = "serde")]
+        crate::serde_impl!($source);
+        #[cfg(feature = "rkyv")]
+        crate::rkyv_impl!($source);
+
+        #[cfg(feature = "rand")]
+        crate::rand_impl!($source);
+
+        #[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+        crate::arbitrary_impl!($source);
+
+        crate::wrapper_impl!($source);
+        crate::primitive_cmp_impl!($source, unsigned: u8, u16, u32, u64, u128, usize);
+        crate::primitive_cmp_impl!($source, signed: i8, i16, i32, i64, i128, isize);
 
         crate::from_to_impl_for_primitive!($source, $helper, u8);
         crate::from_to_impl_for_primitive!($source, $helper, u16);
@@ -4477,24 +5448,45 @@ macro_rules! type_impl {
 
         #[cfg(feature = "num_traits_support")]
         crate::num_traits_impl!($source);
+
+        #[cfg(feature = "num_integer_support")]
+        crate::num_integer_impl!($source);
     };
 }
 
 pub(crate) use type_impl;
 pub(crate) use type_conversion;
 pub(crate) use common_fn_impl;
+pub(crate) use bit_access_impl;
 pub(crate) use common_traits_impl;
+pub(crate) use unaligned_uint_impl;
 pub(crate) use sh_impl_conv;
 pub(crate) use sh_impl;
 pub(crate) use from_to_impl_for_primitive;
+pub(crate) use primitive_conv_impl;
+pub(crate) use primitive_cmp_impl;
+pub(crate) use usize_conv_impl;
+pub(crate) use wrapper_impl;
+pub(crate) use wrapper_binop_impl;
+pub(crate) use checked_binop_impl;
 
 #[cfg(feature = "intx_support")]
 pub(crate) use intx_conv_impl;
 #[cfg(feature = "num_traits_support")]
 pub(crate) use num_traits_impl;
+#[cfg(feature = "num_integer_support")]
+pub(crate) use num_integer_impl;
 #[cfg(feature = "ux_support")]
 pub(crate) use ux_conversion;
 #[cfg(feature = "ux_support")]
 pub(crate) use ux_conversion_via_format;
 #[cfg(feature = "unsafe_fetch")]
-pub(crate) use unsafe_fetch_impl;
\ No newline at end of file
+pub(crate) use unsafe_fetch_impl;
+#[cfg(feature = "serde")]
+pub(crate) use serde_impl;
+#[cfg(feature = "rkyv")]
+pub(crate) use rkyv_impl;
+#[cfg(feature = "rand")]
+pub(crate) use rand_impl;
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+pub(crate) use arbitrary_impl;
\ No newline at end of file
@@ -0,0 +1,1707 @@
+//!
+//! Unsigned integers of any byte width as a const generic type.
+//!
+//! `UInt<BYTES>` is stored as `[u8; BYTES]` in native byte order, just like the named types,
+//! so `UInt<3>` has the same layout as u24 and both convert into each other with From.
+//! Widths of up to 16 bytes compute through u128, wider widths use multi limb arithmetic on the bytes.
+//!
+//! ```
+//! use uintx::uint::UInt;
+//! use uintx::{u144, u24};
+//!
+//! let key: u144 = "5192296858534827628530496329220096".parse().unwrap();
+//! assert_eq!(key, u144::from_u128_truncated(1) << 112);
+//! assert_eq!(key.checked_mul(u144::from_u128_truncated(1 << 32)), None);
+//!
+//! let small = UInt::<3>::from(u24::from(7u8));
+//! assert_eq!(u24::from(small * UInt::from_u128_truncated(6)), u24::from(42u8));
+//! assert_eq!(small.widen::<18>(), u144::from_u128_truncated(7));
+//! ```
+//!
+use core::cmp::Ordering;
+use core::fmt;
+use core::num::{ParseIntError, TryFromIntError};
+use core::str::FromStr;
+
+///
+/// An unsigned integer that is exactly BYTES bytes wide.
+///
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct UInt<const BYTES: usize>(pub(crate) [u8; BYTES]);
+
+///
+/// 136 bit unsigned integer
+///
+#[allow(non_camel_case_types)]
+pub type u136 = UInt<17>;
+
+///
+/// 144 bit unsigned integer
+///
+#[allow(non_camel_case_types)]
+pub type u144 = UInt<18>;
+
+///
+/// 152 bit unsigned integer
+///
+#[allow(non_camel_case_types)]
+pub type u152 = UInt<19>;
+
+///
+/// 160 bit unsigned integer
+///
+#[allow(non_camel_case_types)]
+pub type u160 = UInt<20>;
+
+///
+/// 168 bit unsigned integer
+///
+#[allow(non_camel_case_types)]
+pub type u168 = UInt<21>;
+
+///
+/// 176 bit unsigned integer
+///
+#[allow(non_camel_case_types)]
+pub type u176 = UInt<22>;
+
+///
+/// 184 bit unsigned integer
+///
+#[allow(non_camel_case_types)]
+pub type u184 = UInt<23>;
+
+///
+/// 192 bit unsigned integer
+///
+#[allow(non_camel_case_types)]
+pub type u192 = UInt<24>;
+
+///
+/// 256 bit unsigned integer
+///
+#[allow(non_camel_case_types)]
+pub type u256 = UInt<32>;
+
+// All helpers below work on little endian byte arrays, index 0 is the least significant byte.
+
+const fn reverse<const N: usize>(mut a: [u8; N]) -> [u8; N] {
+    let mut i = 0;
+    while i < N / 2 {
+        let tmp = a[i];
+        a[i] = a[N - 1 - i];
+        a[N - 1 - i] = tmp;
+        i += 1;
+    }
+
+    a
+}
+
+///
+/// Only valid for N <= 16.
+///
+const fn le_to_u128<const N: usize>(a: &[u8; N]) -> u128 {
+    let mut n = 0u128;
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        n = (n << 8) | a[i] as u128;
+    }
+
+    n
+}
+
+///
+/// Truncates n to N bytes.
+///
+const fn u128_to_le<const N: usize>(n: u128) -> [u8; N] {
+    let mut a = [0u8; N];
+    let mut i = 0;
+    while i < N && i < 16 {
+        a[i] = (n >> (i * 8)) as u8;
+        i += 1;
+    }
+
+    a
+}
+
+const fn le_is_zero<const N: usize>(a: &[u8; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        if a[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+///
+/// Number of significant bytes, the index of the highest non zero byte plus one.
+///
+const fn le_len<const N: usize>(a: &[u8; N]) -> usize {
+    let mut len = N;
+    while len > 0 && a[len - 1] == 0 {
+        len -= 1;
+    }
+
+    len
+}
+
+const fn le_cmp<const N: usize>(a: &[u8; N], b: &[u8; N]) -> Ordering {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] < b[i] {
+            return Ordering::Less;
+        }
+        if a[i] > b[i] {
+            return Ordering::Greater;
+        }
+    }
+
+    Ordering::Equal
+}
+
+const fn le_add<const N: usize>(a: &[u8; N], b: &[u8; N]) -> ([u8; N], bool) {
+    let mut r = [0u8; N];
+    let mut carry = 0u16;
+    let mut i = 0;
+    while i < N {
+        let sum = a[i] as u16 + b[i] as u16 + carry;
+        r[i] = sum as u8;
+        carry = sum >> 8;
+        i += 1;
+    }
+
+    (r, carry != 0)
+}
+
+const fn le_sub<const N: usize>(a: &[u8; N], b: &[u8; N]) -> ([u8; N], bool) {
+    let mut r = [0u8; N];
+    let mut borrow = false;
+    let mut i = 0;
+    while i < N {
+        let (d1, b1) = a[i].overflowing_sub(b[i]);
+        let (d2, b2) = d1.overflowing_sub(borrow as u8);
+        r[i] = d2;
+        borrow = b1 || b2;
+        i += 1;
+    }
+
+    (r, borrow)
+}
+
+const fn le_mul<const N: usize>(a: &[u8; N], b: &[u8; N]) -> ([u8; N], bool) {
+    if N <= 16 {
+        let (product, overflow) = le_to_u128(a).overflowing_mul(le_to_u128(b));
+        let truncated = N < 16 && product >> (N * 8) != 0;
+        return (u128_to_le(product), overflow || truncated);
+    }
+
+    let a_len = le_len(a);
+    let b_len = le_len(b);
+    let mut r = [0u8; N];
+    let mut overflow = false;
+    let mut i = 0;
+    while i < a_len {
+        let mut carry = 0u32;
+        let mut j = 0;
+        while j < b_len {
+            let k = i + j;
+            let p = a[i] as u32 * b[j] as u32 + carry;
+            if k >= N {
+                //Every non zero contribution above the top byte is an overflow.
+                if p != 0 {
+                    overflow = true;
+                    carry = 0;
+                    break;
+                }
+
+                j += 1;
+                continue;
+            }
+
+            let p = p + r[k] as u32;
+            r[k] = p as u8;
+            carry = p >> 8;
+            j += 1;
+        }
+
+        if carry != 0 {
+            if i + j < N {
+                r[i + j] = carry as u8;
+            } else {
+                overflow = true;
+            }
+        }
+        i += 1;
+    }
+
+    (r, overflow)
+}
+
+///
+/// Division by a divisor that fits into 64 bits, d must not be 0.
+///
+const fn le_div_rem_u64<const N: usize>(a: &[u8; N], d: u64) -> ([u8; N], u64) {
+    let mut q = [0u8; N];
+    let mut rem = 0u128;
+    let mut i = le_len(a);
+    while i > 0 {
+        i -= 1;
+        let cur = (rem << 8) | a[i] as u128;
+        q[i] = (cur / d as u128) as u8;
+        rem = cur % d as u128;
+    }
+
+    (q, rem as u64)
+}
+
+///
+/// Panics if b is 0.
+///
+const fn le_div_rem<const N: usize>(a: &[u8; N], b: &[u8; N]) -> ([u8; N], [u8; N]) {
+    if le_is_zero(b) {
+        panic!("attempt to divide by zero");
+    }
+
+    if N <= 16 {
+        let a = le_to_u128(a);
+        let b = le_to_u128(b);
+        return (u128_to_le(a / b), u128_to_le(a % b));
+    }
+
+    if le_len(b) <= 8 {
+        let (q, r) = le_div_rem_u64(a, le_to_u128(b) as u64);
+        return (q, u128_to_le(r as u128));
+    }
+
+    //Binary long division, b is wider than 64 bits so there are few quotient bits.
+    let mut q = [0u8; N];
+    let mut r = [0u8; N];
+    let mut bit = le_len(a) * 8;
+    while bit > 0 {
+        bit -= 1;
+        let carry = r[N - 1] >> 7 != 0;
+        r = le_shl(&r, 1);
+        r[0] |= (a[bit / 8] >> (bit % 8)) & 1;
+        if carry || !matches!(le_cmp(&r, b), Ordering::Less) {
+            r = le_sub(&r, b).0;
+            q[bit / 8] |= 1 << (bit % 8);
+        }
+    }
+
+    (q, r)
+}
+
+///
+/// n must be smaller than N * 8.
+///
+const fn le_shl<const N: usize>(a: &[u8; N], n: u32) -> [u8; N] {
+    let bytes = n as usize / 8;
+    let bits = n % 8;
+    let mut r = [0u8; N];
+    let mut i = N;
+    while i > bytes {
+        i -= 1;
+        let src = i - bytes;
+        let mut v = a[src] << bits;
+        if bits != 0 && src > 0 {
+            v |= a[src - 1] >> (8 - bits);
+        }
+        r[i] = v;
+    }
+
+    r
+}
+
+///
+/// n must be smaller than N * 8.
+///
+const fn le_shr<const N: usize>(a: &[u8; N], n: u32) -> [u8; N] {
+    let bytes = n as usize / 8;
+    let bits = n % 8;
+    let mut r = [0u8; N];
+    let mut i = 0;
+    while i + bytes < N {
+        let src = i + bytes;
+        let mut v = a[src] >> bits;
+        if bits != 0 && src + 1 < N {
+            v |= a[src + 1] << (8 - bits);
+        }
+        r[i] = v;
+        i += 1;
+    }
+
+    r
+}
+
+fn parse_error_empty() -> ParseIntError {
+    "".parse::<u8>().unwrap_err()
+}
+
+fn parse_error_invalid_digit() -> ParseIntError {
+    "-".parse::<u8>().unwrap_err()
+}
+
+fn parse_error_overflow() -> ParseIntError {
+    "256".parse::<u8>().unwrap_err()
+}
+
+fn try_from_int_error() -> TryFromIntError {
+    u8::try_from(256u16).unwrap_err()
+}
+
+impl<const BYTES: usize> UInt<BYTES> {
+    ///
+    /// Size of the number in bytes.
+    ///
+    pub const BYTES: usize = BYTES;
+
+    ///
+    /// Size of the number in bits.
+    ///
+    pub const BITS: u32 = (BYTES * 8) as u32;
+
+    ///
+    /// 0
+    ///
+    pub const MIN: Self = Self([0; BYTES]);
+
+    ///
+    /// 2^BITS - 1
+    ///
+    pub const MAX: Self = Self([0xFF; BYTES]);
+
+    #[cfg(target_endian = "little")]
+    #[inline(always)]
+    const fn to_le(self) -> [u8; BYTES] {
+        self.0
+    }
+
+    #[cfg(target_endian = "big")]
+    #[inline(always)]
+    const fn to_le(self) -> [u8; BYTES] {
+        reverse(self.0)
+    }
+
+    #[cfg(target_endian = "little")]
+    #[inline(always)]
+    const fn from_le(le: [u8; BYTES]) -> Self {
+        Self(le)
+    }
+
+    #[cfg(target_endian = "big")]
+    #[inline(always)]
+    const fn from_le(le: [u8; BYTES]) -> Self {
+        Self(reverse(le))
+    }
+
+    ///
+    /// Parses a byte array into the number.
+    /// This fn assumes that the bytes are in little endian byte order
+    ///
+    #[inline]
+    pub const fn from_le_bytes(data: [u8; BYTES]) -> Self {
+        Self::from_le(data)
+    }
+
+    ///
+    /// Parses a byte array into the number.
+    /// This fn assumes that the bytes are in big endian byte order
+    ///
+    #[inline]
+    pub const fn from_be_bytes(data: [u8; BYTES]) -> Self {
+        Self::from_le(reverse(data))
+    }
+
+    ///
+    /// Parses a byte array into the number.
+    /// This fn assumes that the bytes are in native endian byte order
+    ///
+    #[inline]
+    pub const fn from_ne_bytes(data: [u8; BYTES]) -> Self {
+        Self(data)
+    }
+
+    ///
+    /// Returns the bytes of the number in little endian byte order
+    ///
+    #[inline]
+    pub const fn to_le_bytes(self) -> [u8; BYTES] {
+        self.to_le()
+    }
+
+    ///
+    /// Returns the bytes of the number in big endian byte order
+    ///
+    #[inline]
+    pub const fn to_be_bytes(self) -> [u8; BYTES] {
+        reverse(self.to_le())
+    }
+
+    ///
+    /// Returns the bytes of the number in native endian byte order
+    ///
+    #[inline]
+    pub const fn to_ne_bytes(self) -> [u8; BYTES] {
+        self.0
+    }
+
+    ///
+    /// Swaps the byte order of the number
+    ///
+    #[inline]
+    pub const fn swap_bytes(self) -> Self {
+        Self(reverse(self.0))
+    }
+
+    ///
+    /// Converts the number to big endian byte order. Calls swap_bytes on little endian systems.
+    ///
+    #[inline]
+    pub const fn to_be(self) -> Self {
+        Self::from_ne_bytes(self.to_be_bytes())
+    }
+
+    ///
+    /// Converts a number in big endian byte order to the native byte order. Calls swap_bytes on little endian systems.
+    ///
+    #[inline]
+    pub const fn from_be(x: Self) -> Self {
+        Self::from_be_bytes(x.to_ne_bytes())
+    }
+
+    ///
+    /// Returns a reference to the inner array that represents this type.
+    /// The order of bytes in the array is the native byte order.
+    ///
+    #[inline]
+    pub fn inner_ref(&self) -> &[u8; BYTES] {
+        &self.0
+    }
+
+    ///
+    /// Returns a mutable reference to the inner array that represents this type.
+    /// The order of bytes in the array is the native byte order.
+    ///
+    #[inline]
+    pub fn inner_ref_mut(&mut self) -> &mut [u8; BYTES] {
+        &mut self.0
+    }
+
+    ///
+    /// Creates the number from the lowest BYTES bytes of n.
+    ///
+    #[inline]
+    pub const fn from_u128_truncated(n: u128) -> Self {
+        Self::from_le(u128_to_le(n))
+    }
+
+    ///
+    /// Returns None if n does not fit into BYTES bytes.
+    ///
+    #[inline]
+    pub const fn checked_from_u128(n: u128) -> Option<Self> {
+        if BYTES < 16 && n >> (BYTES * 8) != 0 {
+            return None;
+        }
+
+        Some(Self::from_u128_truncated(n))
+    }
+
+    ///
+    /// Returns the lowest 16 bytes of the number.
+    ///
+    #[inline]
+    pub const fn to_u128_truncated(self) -> u128 {
+        let le = self.to_le();
+        let mut n = 0u128;
+        let mut i = if BYTES < 16 { BYTES } else { 16 };
+        while i > 0 {
+            i -= 1;
+            n = (n << 8) | le[i] as u128;
+        }
+
+        n
+    }
+
+    ///
+    /// Returns None if the number does not fit into a u128.
+    ///
+    #[inline]
+    pub const fn checked_to_u128(self) -> Option<u128> {
+        if le_len(&self.to_le()) > 16 {
+            return None;
+        }
+
+        Some(self.to_u128_truncated())
+    }
+
+    ///
+    /// Zero extends the number to a wider type. Fails to compile if M is smaller than BYTES.
+    ///
+    #[inline]
+    pub const fn widen<const M: usize>(self) -> UInt<M> {
+        const { assert!(M >= BYTES, "widen can not be used to make the number smaller") };
+        self.truncate()
+    }
+
+    ///
+    /// Converts the number to a type of any width, returns None if the value does not fit.
+    ///
+    #[inline]
+    pub const fn checked_resize<const M: usize>(self) -> Option<UInt<M>> {
+        if le_len(&self.to_le()) > M {
+            return None;
+        }
+
+        Some(self.truncate())
+    }
+
+    ///
+    /// Converts the number to a type of any width, discarding the high bytes that do not fit.
+    ///
+    #[inline]
+    pub const fn truncate<const M: usize>(self) -> UInt<M> {
+        let le = self.to_le();
+        let mut r = [0u8; M];
+        let mut i = 0;
+        while i < M && i < BYTES {
+            r[i] = le[i];
+            i += 1;
+        }
+
+        UInt::<M>::from_le(r)
+    }
+
+    ///
+    /// Numeric comparison usable in const context.
+    ///
+    #[inline]
+    pub const fn const_cmp(self, other: Self) -> Ordering {
+        le_cmp(&self.to_le(), &other.to_le())
+    }
+
+    #[inline]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (r, overflow) = le_add(&self.to_le(), &rhs.to_le());
+        (Self::from_le(r), overflow)
+    }
+
+    #[inline]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (r, false) => Some(r),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    #[inline]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match self.overflowing_add(rhs) {
+            (r, false) => r,
+            (_, true) => Self::MAX,
+        }
+    }
+
+    ///
+    /// Normal + operation, panics on overflow in debug builds and wraps in release builds.
+    ///
+    #[inline]
+    pub const fn add(self, rhs: Self) -> Self {
+        let (r, overflow) = self.overflowing_add(rhs);
+        debug_assert!(!overflow, "attempt to add with overflow");
+        r
+    }
+
+    #[inline]
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (r, overflow) = le_sub(&self.to_le(), &rhs.to_le());
+        (Self::from_le(r), overflow)
+    }
+
+    #[inline]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (r, false) => Some(r),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    #[inline]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        match self.overflowing_sub(rhs) {
+            (r, false) => r,
+            (_, true) => Self::MIN,
+        }
+    }
+
+    ///
+    /// Normal - operation, panics on overflow in debug builds and wraps in release builds.
+    ///
+    #[inline]
+    pub const fn sub(self, rhs: Self) -> Self {
+        let (r, overflow) = self.overflowing_sub(rhs);
+        debug_assert!(!overflow, "attempt to subtract with overflow");
+        r
+    }
+
+    #[inline]
+    pub const fn abs_diff(self, other: Self) -> Self {
+        match self.overflowing_sub(other) {
+            (r, false) => r,
+            (_, true) => other.wrapping_sub(self),
+        }
+    }
+
+    ///
+    /// Negation wrapping around, this is 0 - self.
+    ///
+    #[inline]
+    pub const fn wrapping_neg(self) -> Self {
+        Self::MIN.wrapping_sub(self)
+    }
+
+    ///
+    /// The flag is true for every number except 0.
+    ///
+    #[inline]
+    pub const fn overflowing_neg(self) -> (Self, bool) {
+        (self.wrapping_neg(), !self.is_zero())
+    }
+
+    ///
+    /// Returns Some(0) for 0 and None for every other number.
+    ///
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(self);
+        }
+
+        None
+    }
+
+    #[inline]
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (r, overflow) = le_mul(&self.to_le(), &rhs.to_le());
+        (Self::from_le(r), overflow)
+    }
+
+    #[inline]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (r, false) => Some(r),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+
+    #[inline]
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        match self.overflowing_mul(rhs) {
+            (r, false) => r,
+            (_, true) => Self::MAX,
+        }
+    }
+
+    ///
+    /// Normal * operation, panics on overflow in debug builds and wraps in release builds.
+    ///
+    #[inline]
+    pub const fn mul(self, rhs: Self) -> Self {
+        let (r, overflow) = self.overflowing_mul(rhs);
+        debug_assert!(!overflow, "attempt to multiply with overflow");
+        r
+    }
+
+    ///
+    /// Returns quotient and remainder. Panics if rhs is 0.
+    ///
+    #[inline]
+    pub const fn div_rem(self, rhs: Self) -> (Self, Self) {
+        let (q, r) = le_div_rem(&self.to_le(), &rhs.to_le());
+        (Self::from_le(q), Self::from_le(r))
+    }
+
+    ///
+    /// Normal / operation, panics if rhs is 0.
+    ///
+    #[inline]
+    pub const fn div(self, rhs: Self) -> Self {
+        self.div_rem(rhs).0
+    }
+
+    ///
+    /// Normal % operation, panics if rhs is 0.
+    ///
+    #[inline]
+    pub const fn rem(self, rhs: Self) -> Self {
+        self.div_rem(rhs).1
+    }
+
+    #[inline]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        Some(self.div(rhs))
+    }
+
+    #[inline]
+    pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        Some(self.rem(rhs))
+    }
+
+    ///
+    /// Returns MAX if rhs is 0. Otherwise performs a normal div operation.
+    ///
+    #[inline]
+    pub const fn saturating_div(self, rhs: Self) -> Self {
+        if rhs.is_zero() {
+            return Self::MAX;
+        }
+
+        self.div(rhs)
+    }
+
+    ///
+    /// Division never overflows for unsigned numbers, this is the same as div.
+    ///
+    #[inline]
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        self.div(rhs)
+    }
+
+    ///
+    /// Remainder never overflows for unsigned numbers, this is the same as rem.
+    ///
+    #[inline]
+    pub const fn wrapping_rem(self, rhs: Self) -> Self {
+        self.rem(rhs)
+    }
+
+    #[inline]
+    pub const fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        (self.div(rhs), false)
+    }
+
+    #[inline]
+    pub const fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        (self.rem(rhs), false)
+    }
+
+    ///
+    /// Euclidean division is the same as div for unsigned numbers. Panics if rhs is 0.
+    ///
+    #[inline]
+    pub const fn div_euclid(self, rhs: Self) -> Self {
+        self.div(rhs)
+    }
+
+    ///
+    /// Euclidean remainder is the same as rem for unsigned numbers. Panics if rhs is 0.
+    ///
+    #[inline]
+    pub const fn rem_euclid(self, rhs: Self) -> Self {
+        self.rem(rhs)
+    }
+
+    #[inline]
+    pub const fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+        self.checked_div(rhs)
+    }
+
+    #[inline]
+    pub const fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+        self.checked_rem(rhs)
+    }
+
+    #[inline]
+    pub const fn wrapping_div_euclid(self, rhs: Self) -> Self {
+        self.div(rhs)
+    }
+
+    #[inline]
+    pub const fn wrapping_rem_euclid(self, rhs: Self) -> Self {
+        self.rem(rhs)
+    }
+
+    #[inline]
+    pub const fn overflowing_div_euclid(self, rhs: Self) -> (Self, bool) {
+        (self.div(rhs), false)
+    }
+
+    #[inline]
+    pub const fn overflowing_rem_euclid(self, rhs: Self) -> (Self, bool) {
+        (self.rem(rhs), false)
+    }
+
+    ///
+    /// Division rounding up. Panics if rhs is 0.
+    ///
+    #[inline]
+    pub const fn div_ceil(self, rhs: Self) -> Self {
+        let (q, r) = self.div_rem(rhs);
+        if r.is_zero() {
+            return q;
+        }
+
+        q.add(Self::from_u128_truncated(1))
+    }
+
+    ///
+    /// Returns the smallest multiple of rhs that is greater or equal to self, None if rhs is 0 or the result does not fit.
+    ///
+    #[inline]
+    pub const fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        let r = self.rem(rhs);
+        if r.is_zero() {
+            return Some(self);
+        }
+
+        self.checked_add(rhs.sub(r))
+    }
+
+    ///
+    /// Returns the smallest multiple of rhs that is greater or equal to self.
+    /// Panics if rhs is 0, panics on overflow in debug builds and wraps in release builds.
+    ///
+    #[inline]
+    pub const fn next_multiple_of(self, rhs: Self) -> Self {
+        let r = self.rem(rhs);
+        if r.is_zero() {
+            return self;
+        }
+
+        self.add(rhs.sub(r))
+    }
+
+    #[inline]
+    pub const fn overflowing_pow(self, mut exp: u32) -> (Self, bool) {
+        let mut base = self;
+        let mut acc = Self::from_u128_truncated(1);
+        let mut overflow = false;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                let (r, o) = acc.overflowing_mul(base);
+                acc = r;
+                overflow |= o;
+            }
+
+            exp >>= 1;
+            if exp > 0 {
+                let (r, o) = base.overflowing_mul(base);
+                base = r;
+                overflow |= o;
+            }
+        }
+
+        (acc, overflow)
+    }
+
+    #[inline]
+    pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+        match self.overflowing_pow(exp) {
+            (r, false) => Some(r),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_pow(self, exp: u32) -> Self {
+        self.overflowing_pow(exp).0
+    }
+
+    #[inline]
+    pub const fn saturating_pow(self, exp: u32) -> Self {
+        match self.overflowing_pow(exp) {
+            (r, false) => r,
+            (_, true) => Self::MAX,
+        }
+    }
+
+    ///
+    /// Panics on overflow in debug builds and wraps in release builds.
+    ///
+    #[inline]
+    pub const fn pow(self, exp: u32) -> Self {
+        let (r, overflow) = self.overflowing_pow(exp);
+        debug_assert!(!overflow, "attempt to multiply with overflow");
+        r
+    }
+
+    ///
+    /// Shifts left by n % BITS, the flag is true if n is at least BITS.
+    ///
+    #[inline]
+    pub const fn overflowing_shl(self, n: u32) -> (Self, bool) {
+        if Self::BITS == 0 {
+            return (self, true);
+        }
+
+        (Self::from_le(le_shl(&self.to_le(), n % Self::BITS)), n >= Self::BITS)
+    }
+
+    #[inline]
+    pub const fn checked_shl(self, n: u32) -> Option<Self> {
+        match self.overflowing_shl(n) {
+            (r, false) => Some(r),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_shl(self, n: u32) -> Self {
+        self.overflowing_shl(n).0
+    }
+
+    ///
+    /// Normal << operation, panics if n >= BITS in debug builds.
+    ///
+    #[inline]
+    pub const fn shl(self, n: u32) -> Self {
+        let (r, overflow) = self.overflowing_shl(n);
+        debug_assert!(!overflow, "attempt to shift left with overflow");
+        r
+    }
+
+    ///
+    /// Shifts right by n % BITS, the flag is true if n is at least BITS.
+    ///
+    #[inline]
+    pub const fn overflowing_shr(self, n: u32) -> (Self, bool) {
+        if Self::BITS == 0 {
+            return (self, true);
+        }
+
+        (Self::from_le(le_shr(&self.to_le(), n % Self::BITS)), n >= Self::BITS)
+    }
+
+    #[inline]
+    pub const fn checked_shr(self, n: u32) -> Option<Self> {
+        match self.overflowing_shr(n) {
+            (r, false) => Some(r),
+            (_, true) => None,
+        }
+    }
+
+    #[inline]
+    pub const fn wrapping_shr(self, n: u32) -> Self {
+        self.overflowing_shr(n).0
+    }
+
+    ///
+    /// Normal >> operation, panics if n >= BITS in debug builds.
+    ///
+    #[inline]
+    pub const fn shr(self, n: u32) -> Self {
+        let (r, overflow) = self.overflowing_shr(n);
+        debug_assert!(!overflow, "attempt to shift right with overflow");
+        r
+    }
+
+    #[inline]
+    pub const fn rotate_left(self, n: u32) -> Self {
+        if Self::BITS == 0 {
+            return self;
+        }
+
+        let n = n % Self::BITS;
+        if n == 0 {
+            return self;
+        }
+
+        let le = self.to_le();
+        let a = le_shl(&le, n);
+        let b = le_shr(&le, Self::BITS - n);
+        Self::from_le(a).bitor_impl(Self::from_le(b))
+    }
+
+    #[inline]
+    pub const fn rotate_right(self, n: u32) -> Self {
+        if Self::BITS == 0 {
+            return self;
+        }
+
+        self.rotate_left(Self::BITS - n % Self::BITS)
+    }
+
+    #[inline]
+    const fn bitor_impl(self, rhs: Self) -> Self {
+        let mut r = self.0;
+        let mut i = 0;
+        while i < BYTES {
+            r[i] |= rhs.0[i];
+            i += 1;
+        }
+
+        Self(r)
+    }
+
+    #[inline]
+    const fn bitxor_impl(self, rhs: Self) -> Self {
+        let mut r = self.0;
+        let mut i = 0;
+        while i < BYTES {
+            r[i] ^= rhs.0[i];
+            i += 1;
+        }
+
+        Self(r)
+    }
+
+    #[inline]
+    pub const fn is_zero(self) -> bool {
+        le_is_zero(&self.0)
+    }
+
+    #[inline]
+    pub const fn count_ones(self) -> u32 {
+        let mut n = 0;
+        let mut i = 0;
+        while i < BYTES {
+            n += self.0[i].count_ones();
+            i += 1;
+        }
+
+        n
+    }
+
+    #[inline]
+    pub const fn count_zeros(self) -> u32 {
+        Self::BITS - self.count_ones()
+    }
+
+    #[inline]
+    pub const fn leading_zeros(self) -> u32 {
+        let le = self.to_le();
+        let len = le_len(&le);
+        if len == 0 {
+            return Self::BITS;
+        }
+
+        ((BYTES - len) * 8) as u32 + le[len - 1].leading_zeros()
+    }
+
+    #[inline]
+    pub const fn trailing_zeros(self) -> u32 {
+        let le = self.to_le();
+        let mut i = 0;
+        while i < BYTES {
+            if le[i] != 0 {
+                return (i * 8) as u32 + le[i].trailing_zeros();
+            }
+            i += 1;
+        }
+
+        Self::BITS
+    }
+
+    #[inline]
+    pub const fn leading_ones(self) -> u32 {
+        self.not().leading_zeros()
+    }
+
+    #[inline]
+    pub const fn trailing_ones(self) -> u32 {
+        self.not().trailing_zeros()
+    }
+
+    #[inline]
+    pub const fn is_power_of_two(self) -> bool {
+        self.count_ones() == 1
+    }
+
+    ///
+    /// Returns the smallest power of two that is greater or equal to self, None if it does not fit.
+    ///
+    #[inline]
+    pub const fn checked_next_power_of_two(self) -> Option<Self> {
+        if self.count_ones() <= 1 {
+            if self.is_zero() {
+                return Self::checked_from_u128(1);
+            }
+
+            return Some(self);
+        }
+
+        let bits = Self::BITS - self.leading_zeros();
+        Self::from_u128_truncated(1).checked_shl(bits)
+    }
+
+    ///
+    /// Returns the smallest power of two that is greater or equal to self.
+    /// Panics on overflow in debug builds and returns 0 in release builds.
+    ///
+    #[inline]
+    pub const fn next_power_of_two(self) -> Self {
+        match self.checked_next_power_of_two() {
+            Some(n) => n,
+            None => {
+                debug_assert!(false, "attempt to add with overflow");
+                Self::MIN
+            }
+        }
+    }
+
+    #[inline]
+    pub const fn checked_ilog2(self) -> Option<u32> {
+        if self.is_zero() {
+            return None;
+        }
+
+        Some(Self::BITS - 1 - self.leading_zeros())
+    }
+
+    ///
+    /// Panics if self is 0.
+    ///
+    #[inline]
+    pub const fn ilog2(self) -> u32 {
+        match self.checked_ilog2() {
+            Some(n) => n,
+            None => panic!("argument of integer logarithm must be positive"),
+        }
+    }
+
+    ///
+    /// Logarithm to the given base rounded down. Returns None if self is 0 or base is smaller than 2.
+    ///
+    #[inline]
+    pub const fn checked_ilog(self, base: Self) -> Option<u32> {
+        if self.is_zero() || base.leading_zeros() + 1 >= Self::BITS {
+            return None;
+        }
+
+        let mut n = 0;
+        let mut r = self.div(base);
+        while !r.is_zero() {
+            r = r.div(base);
+            n += 1;
+        }
+
+        Some(n)
+    }
+
+    ///
+    /// Panics if self is 0 or base is smaller than 2.
+    ///
+    #[inline]
+    pub const fn ilog(self, base: Self) -> u32 {
+        if base.leading_zeros() + 1 >= Self::BITS {
+            panic!("base of integer logarithm must be at least 2");
+        }
+
+        match self.checked_ilog(base) {
+            Some(n) => n,
+            None => panic!("argument of integer logarithm must be positive"),
+        }
+    }
+
+    #[inline]
+    pub const fn checked_ilog10(self) -> Option<u32> {
+        if self.is_zero() {
+            return None;
+        }
+
+        let mut n = 0;
+        let mut le = le_div_rem_u64(&self.to_le(), 10).0;
+        while !le_is_zero(&le) {
+            le = le_div_rem_u64(&le, 10).0;
+            n += 1;
+        }
+
+        Some(n)
+    }
+
+    ///
+    /// Panics if self is 0.
+    ///
+    #[inline]
+    pub const fn ilog10(self) -> u32 {
+        match self.checked_ilog10() {
+            Some(n) => n,
+            None => panic!("argument of integer logarithm must be positive"),
+        }
+    }
+
+    ///
+    /// Square root rounded down.
+    ///
+    #[inline]
+    pub const fn isqrt(self) -> Self {
+        if self.is_zero() {
+            return self;
+        }
+
+        //Newton iteration starting at a power of two that is not smaller than the root,
+        //the estimate decreases monotonically until it reaches the root.
+        let bits = Self::BITS - self.leading_zeros();
+        let mut x = Self::from_u128_truncated(1).shl(bits.div_ceil(2));
+        loop {
+            let y = x.add(self.div(x)).shr(1);
+            if !matches!(y.const_cmp(x), Ordering::Less) {
+                return x;
+            }
+
+            x = y;
+        }
+    }
+
+    ///
+    /// This never returns None since the number is unsigned,
+    /// it only exists for parity with the api of the signed primitive types.
+    ///
+    #[inline]
+    pub const fn checked_isqrt(self) -> Option<Self> {
+        Some(self.isqrt())
+    }
+
+    ///
+    /// Greatest common divisor of both numbers. The gcd of 0 and n is n.
+    ///
+    #[inline]
+    pub const fn gcd(self, other: Self) -> Self {
+        if self.is_zero() {
+            return other;
+        }
+
+        if other.is_zero() {
+            return self;
+        }
+
+        let shift = self.bitor_impl(other).trailing_zeros();
+        let mut a = self.shr(self.trailing_zeros());
+        let mut b = other;
+        loop {
+            b = b.shr(b.trailing_zeros());
+            if matches!(a.const_cmp(b), Ordering::Greater) {
+                let tmp = a;
+                a = b;
+                b = tmp;
+            }
+
+            b = b.sub(a);
+            if b.is_zero() {
+                return a.shl(shift);
+            }
+        }
+    }
+
+    ///
+    /// Least common multiple of both numbers. The lcm of 0 and n is 0.
+    /// Panics on overflow in debug builds and wraps in release builds.
+    ///
+    #[inline]
+    pub const fn lcm(self, other: Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::MIN;
+        }
+
+        self.div(self.gcd(other)).mul(other)
+    }
+
+    ///
+    /// Least common multiple of both numbers, None if it does not fit.
+    ///
+    #[inline]
+    pub const fn checked_lcm(self, other: Self) -> Option<Self> {
+        if self.is_zero() || other.is_zero() {
+            return Some(Self::MIN);
+        }
+
+        self.div(self.gcd(other)).checked_mul(other)
+    }
+
+    #[inline]
+    pub const fn reverse_bits(self) -> Self {
+        let mut r = reverse(self.0);
+        let mut i = 0;
+        while i < BYTES {
+            r[i] = r[i].reverse_bits();
+            i += 1;
+        }
+
+        Self(r)
+    }
+
+    #[inline]
+    pub const fn not(self) -> Self {
+        let mut r = self.0;
+        let mut i = 0;
+        while i < BYTES {
+            r[i] = !r[i];
+            i += 1;
+        }
+
+        Self(r)
+    }
+
+    ///
+    /// Parses a string returning a error if the number cannot be parsed or is too large for the type.
+    /// Panics if radix is not in the range 2..=36.
+    ///
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+        assert!((2..=36).contains(&radix), "from_str_radix: radix must lie in the range `[2, 36]`");
+
+        let digits = src.strip_prefix('+').unwrap_or(src);
+        if src.is_empty() {
+            return Err(parse_error_empty());
+        }
+
+        if digits.is_empty() {
+            return Err(parse_error_invalid_digit());
+        }
+
+        let radix_value = Self::checked_from_u128(radix as u128);
+        let mut n = Self::MIN;
+        for c in digits.chars() {
+            let digit = c.to_digit(radix).ok_or_else(parse_error_invalid_digit)?;
+            if n.is_zero() && digit == 0 {
+                continue;
+            }
+
+            n = radix_value
+                .and_then(|radix| n.checked_mul(radix))
+                .zip(Self::checked_from_u128(digit as u128))
+                .and_then(|(shifted, digit)| shifted.checked_add(digit))
+                .ok_or_else(parse_error_overflow)?;
+        }
+
+        Ok(n)
+    }
+
+    ///
+    /// Writes the digits of a power of two radix to the end of buf and returns them.
+    ///
+    fn fmt_pow2_digits<'a>(self, buf: &'a mut [u8], shift: u32, digits: &[u8; 16]) -> &'a str {
+        if self.is_zero() {
+            return "0";
+        }
+
+        let mask = Self::from_u128_truncated((1 << shift) - 1);
+        let mut n = self;
+        let mut pos = buf.len();
+        loop {
+            pos -= 1;
+            buf[pos] = digits[(n.bitand_impl(mask)).to_u128_truncated() as usize];
+            n = n.wrapping_shr(shift);
+            if n.is_zero() {
+                break;
+            }
+        }
+
+        //Only ascii digits were written.
+        core::str::from_utf8(&buf[pos..]).unwrap()
+    }
+
+    #[inline]
+    const fn bitand_impl(self, rhs: Self) -> Self {
+        let mut r = self.0;
+        let mut i = 0;
+        while i < BYTES {
+            r[i] &= rhs.0[i];
+            i += 1;
+        }
+
+        Self(r)
+    }
+}
+
+impl<const BYTES: usize> Default for UInt<BYTES> {
+    fn default() -> Self {
+        Self::MIN
+    }
+}
+
+impl<const BYTES: usize> PartialOrd for UInt<BYTES> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const BYTES: usize> Ord for UInt<BYTES> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.const_cmp(*other)
+    }
+}
+
+impl<const BYTES: usize> fmt::Display for UInt<BYTES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if BYTES <= 16 {
+            return fmt::Display::fmt(&self.to_u128_truncated(), f);
+        }
+
+        //A byte never needs more than 3 decimal digits.
+        let mut buf = [[0u8; 3]; BYTES];
+        let buf = buf.as_flattened_mut();
+        let mut pos = buf.len();
+        let mut le = self.to_le();
+        loop {
+            let (q, mut chunk) = le_div_rem_u64(&le, 10_000_000_000_000_000_000);
+            le = q;
+            let last = le_is_zero(&le);
+            for _ in 0..19 {
+                pos -= 1;
+                buf[pos] = b'0' + (chunk % 10) as u8;
+                chunk /= 10;
+                if last && chunk == 0 {
+                    break;
+                }
+            }
+
+            if last {
+                break;
+            }
+        }
+
+        //Only ascii digits were written.
+        f.pad_integral(true, "", core::str::from_utf8(&buf[pos..]).unwrap())
+    }
+}
+
+impl<const BYTES: usize> fmt::Debug for UInt<BYTES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const BYTES: usize> fmt::LowerHex for UInt<BYTES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [[0u8; 2]; BYTES];
+        let digits = self.fmt_pow2_digits(buf.as_flattened_mut(), 4, b"0123456789abcdef");
+        f.pad_integral(true, "0x", digits)
+    }
+}
+
+impl<const BYTES: usize> fmt::UpperHex for UInt<BYTES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [[0u8; 2]; BYTES];
+        let digits = self.fmt_pow2_digits(buf.as_flattened_mut(), 4, b"0123456789ABCDEF");
+        f.pad_integral(true, "0x", digits)
+    }
+}
+
+impl<const BYTES: usize> fmt::Octal for UInt<BYTES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [[0u8; 3]; BYTES];
+        let digits = self.fmt_pow2_digits(buf.as_flattened_mut(), 3, b"0123456789abcdef");
+        f.pad_integral(true, "0o", digits)
+    }
+}
+
+impl<const BYTES: usize> fmt::Binary for UInt<BYTES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [[0u8; 8]; BYTES];
+        let digits = self.fmt_pow2_digits(buf.as_flattened_mut(), 1, b"0123456789abcdef");
+        f.pad_integral(true, "0b", digits)
+    }
+}
+
+impl<const BYTES: usize> FromStr for UInt<BYTES> {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+impl<const BYTES: usize> core::ops::Not for UInt<BYTES> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        UInt::not(self)
+    }
+}
+
+impl<const BYTES: usize> core::ops::Not for &UInt<BYTES> {
+    type Output = UInt<BYTES>;
+
+    fn not(self) -> UInt<BYTES> {
+        UInt::not(*self)
+    }
+}
+
+macro_rules! uint_binop {
+    ($op:ident, $fn:ident, $assign_op:ident, $assign_fn:ident, $impl:path) => {
+        impl<const BYTES: usize> core::ops::$op for UInt<BYTES> {
+            type Output = Self;
+
+            fn $fn(self, rhs: Self) -> Self {
+                $impl(self, rhs)
+            }
+        }
+
+        impl<const BYTES: usize> core::ops::$op<&UInt<BYTES>> for UInt<BYTES> {
+            type Output = Self;
+
+            fn $fn(self, rhs: &Self) -> Self {
+                core::ops::$op::$fn(self, *rhs)
+            }
+        }
+
+        impl<const BYTES: usize> core::ops::$op<UInt<BYTES>> for &UInt<BYTES> {
+            type Output = UInt<BYTES>;
+
+            fn $fn(self, rhs: UInt<BYTES>) -> UInt<BYTES> {
+                core::ops::$op::$fn(*self, rhs)
+            }
+        }
+
+        impl<const BYTES: usize> core::ops::$op<&UInt<BYTES>> for &UInt<BYTES> {
+            type Output = UInt<BYTES>;
+
+            fn $fn(self, rhs: &UInt<BYTES>) -> UInt<BYTES> {
+                core::ops::$op::$fn(*self, *rhs)
+            }
+        }
+
+        impl<const BYTES: usize> core::ops::$assign_op for UInt<BYTES> {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = core::ops::$op::$fn(*self, rhs);
+            }
+        }
+
+        impl<const BYTES: usize> core::ops::$assign_op<&UInt<BYTES>> for UInt<BYTES> {
+            fn $assign_fn(&mut self, rhs: &Self) {
+                *self = core::ops::$op::$fn(*self, *rhs);
+            }
+        }
+    };
+}
+
+uint_binop!(Add, add, AddAssign, add_assign, UInt::add);
+uint_binop!(Sub, sub, SubAssign, sub_assign, UInt::sub);
+uint_binop!(Mul, mul, MulAssign, mul_assign, UInt::mul);
+uint_binop!(Div, div, DivAssign, div_assign, UInt::div);
+uint_binop!(Rem, rem, RemAssign, rem_assign, UInt::rem);
+uint_binop!(BitAnd, bitand, BitAndAssign, bitand_assign, UInt::bitand_impl);
+uint_binop!(BitOr, bitor, BitOrAssign, bitor_assign, UInt::bitor_impl);
+uint_binop!(BitXor, bitxor, BitXorAssign, bitxor_assign, UInt::bitxor_impl);
+
+macro_rules! uint_shift {
+    ($($rhs:ty),*) => {
+        $(
+            impl<const BYTES: usize> core::ops::Shl<$rhs> for UInt<BYTES> {
+                type Output = Self;
+
+                fn shl(self, rhs: $rhs) -> Self {
+                    //Values that do not fit into u32 are out of range for every width.
+                    UInt::shl(self, u32::try_from(rhs).unwrap_or(u32::MAX))
+                }
+            }
+
+            impl<const BYTES: usize> core::ops::Shr<$rhs> for UInt<BYTES> {
+                type Output = Self;
+
+                fn shr(self, rhs: $rhs) -> Self {
+                    UInt::shr(self, u32::try_from(rhs).unwrap_or(u32::MAX))
+                }
+            }
+
+            impl<const BYTES: usize> core::ops::ShlAssign<$rhs> for UInt<BYTES> {
+                fn shl_assign(&mut self, rhs: $rhs) {
+                    *self = core::ops::Shl::shl(*self, rhs);
+                }
+            }
+
+            impl<const BYTES: usize> core::ops::ShrAssign<$rhs> for UInt<BYTES> {
+                fn shr_assign(&mut self, rhs: $rhs) {
+                    *self = core::ops::Shr::shr(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+uint_shift!(u8, u16, u32, u64, usize, i32);
+
+macro_rules! uint_primitive_conversion {
+    ($($primitive:ty),*) => {
+        $(
+            impl<const BYTES: usize> TryFrom<$primitive> for UInt<BYTES> {
+                type Error = TryFromIntError;
+
+                fn try_from(value: $primitive) -> Result<Self, Self::Error> {
+                    Self::checked_from_u128(value as u128).ok_or_else(try_from_int_error)
+                }
+            }
+
+            impl<const BYTES: usize> TryFrom<UInt<BYTES>> for $primitive {
+                type Error = TryFromIntError;
+
+                fn try_from(value: UInt<BYTES>) -> Result<Self, Self::Error> {
+                    let n = value.checked_to_u128().ok_or_else(try_from_int_error)?;
+                    <$primitive>::try_from(n).map_err(|_| try_from_int_error())
+                }
+            }
+        )*
+    };
+}
+
+uint_primitive_conversion!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! uint_named_conversion {
+    ($($name:ident, $size:literal);*) => {
+        $(
+            impl From<crate::$name> for UInt<$size> {
+                #[inline]
+                fn from(value: crate::$name) -> Self {
+                    //Both store the bytes in native byte order.
+                    UInt(value.0)
+                }
+            }
+
+            impl From<&crate::$name> for UInt<$size> {
+                #[inline]
+                fn from(value: &crate::$name) -> Self {
+                    UInt(value.0)
+                }
+            }
+
+            impl From<UInt<$size>> for crate::$name {
+                #[inline]
+                fn from(value: UInt<$size>) -> Self {
+                    crate::$name(value.0)
+                }
+            }
+
+            impl From<&UInt<$size>> for crate::$name {
+                #[inline]
+                fn from(value: &UInt<$size>) -> Self {
+                    crate::$name(value.0)
+                }
+            }
+        )*
+    };
+}
+
+uint_named_conversion!(u24, 3; u40, 5; u48, 6; u56, 7; u72, 9; u80, 10; u88, 11; u96, 12; u104, 13; u112, 14; u120, 15);
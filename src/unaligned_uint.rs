@@ -0,0 +1,305 @@
+pub(crate) mod private {
+    ///
+    /// Prevents implementations of UnalignedUint and Pixel outside of this crate.
+    /// The slice views rely on every implementor being a repr(transparent) byte array.
+    ///
+    pub trait Sealed: Sized {}
+
+    ///
+    /// Views a slice as the bytes it consists of.
+    ///
+    #[inline]
+    pub fn slice_as_bytes<T: Sealed>(slice: &[T]) -> &[u8] {
+        //Safety: Sealed guarantees that T is a repr(transparent) byte array.
+        unsafe { core::slice::from_raw_parts(slice.as_ptr().cast::<u8>(), core::mem::size_of_val(slice)) }
+    }
+
+    ///
+    /// Views a mutable slice as the bytes it consists of.
+    ///
+    #[inline]
+    pub fn slice_as_bytes_mut<T: Sealed>(slice: &mut [T]) -> &mut [u8] {
+        //Safety: Sealed guarantees that T is a repr(transparent) byte array.
+        unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast::<u8>(), core::mem::size_of_val(slice)) }
+    }
+
+    ///
+    /// Views a byte slice as a slice of T.
+    /// Returns None if the length of the byte slice is not a multiple of the size of T.
+    ///
+    #[inline]
+    pub fn slice_from_bytes<T: Sealed>(bytes: &[u8]) -> Option<&[T]> {
+        let size = core::mem::size_of::<T>();
+        if !bytes.len().is_multiple_of(size) {
+            return None;
+        }
+
+        //Safety: Sealed guarantees that T has an alignment of 1 and every bit pattern is a valid value.
+        Some(unsafe { core::slice::from_raw_parts(bytes.as_ptr().cast::<T>(), bytes.len() / size) })
+    }
+
+    ///
+    /// Views a mutable byte slice as a slice of T.
+    /// Returns None if the length of the byte slice is not a multiple of the size of T.
+    ///
+    #[inline]
+    pub fn slice_from_bytes_mut<T: Sealed>(bytes: &mut [u8]) -> Option<&mut [T]> {
+        let size = core::mem::size_of::<T>();
+        if !bytes.len().is_multiple_of(size) {
+            return None;
+        }
+
+        //Safety: Sealed guarantees that T has an alignment of 1 and every bit pattern is a valid value.
+        Some(unsafe { core::slice::from_raw_parts_mut(bytes.as_mut_ptr().cast::<T>(), bytes.len() / size) })
+    }
+}
+
+///
+/// Common interface of all unaligned unsigned integer types provided by this crate.
+///
+/// This trait exposes the functionality that is shared between u24, u40, ... u120 so that
+/// code can be written generic over the exact width of the integer.
+///
+/// Primitives that hold every value of a type implement From for it, narrower primitives only TryFrom:
+/// ```
+/// use uintx::u24;
+///
+/// let x = u24::from(0x12_3456u32);
+/// assert_eq!(u32::from(x), 0x12_3456);
+/// assert!(u16::try_from(x).is_err());
+/// assert_eq!(u16::try_from(u24::from(7u8)), Ok(7));
+/// ```
+///
+/// So a narrowing conversion does not compile:
+/// ```compile_fail
+/// let _ = u8::from(uintx::u24::MIN);
+/// ```
+///
+pub trait UnalignedUint:
+    private::Sealed
+    + Copy
+    + Default
+    + Eq
+    + Ord
+    + core::hash::Hash
+    + core::fmt::Debug
+    + core::fmt::Display
+    + Send
+    + Sync
+    + 'static
+{
+    ///
+    /// The next largest aligned type that is used for arithmetic operations.
+    ///
+    type Helper: Copy
+        + Default
+        + Eq
+        + Ord
+        + core::hash::Hash
+        + core::fmt::Debug
+        + core::fmt::Display
+        + Into<u128>
+        + Send
+        + Sync
+        + 'static;
+
+    ///
+    /// The byte array that represents this type in memory.
+    ///
+    type Bytes: Copy
+        + Default
+        + Eq
+        + core::fmt::Debug
+        + AsRef<[u8]>
+        + AsMut<[u8]>
+        + Send
+        + Sync
+        + 'static;
+
+    ///
+    /// Size of this type in bytes.
+    ///
+    const BYTES: usize;
+    ///
+    /// Size of this type in bits.
+    ///
+    const NUM_BITS: usize;
+    ///
+    /// Maximum value of the type.
+    ///
+    const MAX: Self;
+    ///
+    /// Minimum value of the type.
+    ///
+    const MIN: Self;
+    ///
+    /// Maximum numeric value of the type
+    ///
+    const MAX_VALUE: Self::Helper;
+
+    ///
+    /// Unwraps the type into the next largest aligned type.
+    ///
+    fn as_num(self) -> Self::Helper;
+
+    ///
+    /// Converts the next largest aligned type into this type.
+    /// All bits beyond NUM_BITS are discarded.
+    ///
+    fn from_num_truncated(n: Self::Helper) -> Self;
+
+    ///
+    /// Unwraps the type into a u128.
+    ///
+    fn as_u128(self) -> u128;
+
+    ///
+    /// Converts a u128 into this type.
+    /// All bits beyond NUM_BITS are discarded.
+    ///
+    fn from_u128_truncated(n: u128) -> Self;
+
+    ///
+    /// Parses a byte array into the number.
+    /// This fn assumes that the bytes are in little endian byte order
+    ///
+    fn from_le_bytes(data: Self::Bytes) -> Self;
+
+    ///
+    /// Parses a byte array into the number.
+    /// This fn assumes that the bytes are in big endian byte order
+    ///
+    fn from_be_bytes(data: Self::Bytes) -> Self;
+
+    ///
+    /// Parses a byte array into the number.
+    /// This fn assumes that the bytes are in native endian byte order
+    ///
+    fn from_ne_bytes(data: Self::Bytes) -> Self;
+
+    ///
+    /// Unwraps the type into a u8 array in little endian byte order.
+    ///
+    fn to_le_bytes(self) -> Self::Bytes;
+
+    ///
+    /// Unwraps the type into a u8 array in big endian byte order.
+    ///
+    fn to_be_bytes(self) -> Self::Bytes;
+
+    ///
+    /// Unwraps the type into a u8 array in native endian byte order.
+    ///
+    fn to_ne_bytes(self) -> Self::Bytes;
+
+    ///
+    /// shifts the number to the left.
+    ///
+    fn shl(self, rhs: u32) -> Self;
+
+    ///
+    /// shifts the number to the right.
+    ///
+    fn shr(self, rhs: u32) -> Self;
+
+    ///
+    /// Adds the numbers, returns None on overflow.
+    ///
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    ///
+    /// Subtracts the numbers, returns None on underflow.
+    ///
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    ///
+    /// Multiplies the numbers, returns None on overflow.
+    ///
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    ///
+    /// Divides the numbers, returns None if rhs is 0.
+    ///
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+
+    ///
+    /// Adds the numbers wrapping around on overflow.
+    ///
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    ///
+    /// Subtracts the numbers wrapping around on underflow.
+    ///
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    ///
+    /// Multiplies the numbers wrapping around on overflow.
+    ///
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    ///
+    /// Adds the numbers returning MAX on overflow.
+    ///
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    ///
+    /// Subtracts the numbers returning MIN on underflow.
+    ///
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    ///
+    /// Multiplies the numbers returning MAX on overflow.
+    ///
+    fn saturating_mul(self, rhs: Self) -> Self;
+
+    ///
+    /// Adds the numbers wrapping around on overflow and indicates if an overflow occurred.
+    ///
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+
+    ///
+    /// Subtracts the numbers wrapping around on underflow and indicates if an underflow occurred.
+    ///
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+
+    ///
+    /// Multiplies the numbers wrapping around on overflow and indicates if an overflow occurred.
+    ///
+    fn overflowing_mul(self, rhs: Self) -> (Self, bool);
+
+    ///
+    /// Views a slice of this type as the bytes it consists of.
+    /// The bytes of each element are in native endian byte order.
+    ///
+    #[inline]
+    fn slice_as_bytes(slice: &[Self]) -> &[u8] {
+        private::slice_as_bytes(slice)
+    }
+
+    ///
+    /// Views a mutable slice of this type as the bytes it consists of.
+    /// The bytes of each element are in native endian byte order.
+    ///
+    #[inline]
+    fn slice_as_bytes_mut(slice: &mut [Self]) -> &mut [u8] {
+        private::slice_as_bytes_mut(slice)
+    }
+
+    ///
+    /// Views a byte slice as a slice of this type.
+    /// Returns None if the length of the byte slice is not a multiple of BYTES.
+    ///
+    #[inline]
+    fn slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+        private::slice_from_bytes(bytes)
+    }
+
+    ///
+    /// Views a mutable byte slice as a slice of this type.
+    /// Returns None if the length of the byte slice is not a multiple of BYTES.
+    ///
+    #[inline]
+    fn slice_from_bytes_mut(bytes: &mut [u8]) -> Option<&mut [Self]> {
+        private::slice_from_bytes_mut(bytes)
+    }
+}
@@ -0,0 +1,285 @@
+//!
+//! Minimal RIFF/WAVE reader and writer for 24 bit PCM.
+//!
+//! Samples are stored either packed into 3 bytes or padded into a 32 bit container
+//! as described by WAVE_FORMAT_EXTENSIBLE. Samples are exchanged as interleaved u24 values,
+//! see the audio module for conversions into f32 and between interleaved and per channel buffers.
+//!
+use crate::io::{ReadUintxExt, WriteUintxExt};
+use crate::u24;
+use std::io;
+use std::io::Read;
+
+const FORMAT_PCM: u16 = 1;
+const FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+///
+/// KSDATAFORMAT_SUBTYPE_PCM without the leading format tag.
+///
+const PCM_GUID_TAIL: [u8; 14] = [0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71];
+///
+/// Largest fmt chunk that is accepted by the reader.
+///
+const MAX_FMT_SIZE: u32 = 256;
+
+///
+/// How a single sample is stored in the data chunk.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum WavContainer {
+    ///
+    /// 3 bytes per sample.
+    ///
+    #[default]
+    Packed24,
+    ///
+    /// 4 bytes per sample with the sample in the 3 most significant bytes.
+    /// When reading files with 32 valid bits the least significant byte is discarded.
+    ///
+    Padded32,
+}
+
+impl WavContainer {
+    ///
+    /// Size of a single sample in bytes.
+    ///
+    #[inline]
+    pub const fn bytes(self) -> usize {
+        match self {
+            WavContainer::Packed24 => 3,
+            WavContainer::Padded32 => 4,
+        }
+    }
+}
+
+///
+/// Format of a WAVE file.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WavSpec {
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub container: WavContainer,
+}
+
+fn invalid_data(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn invalid_input(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+fn read_tag<R: io::Read + ?Sized>(reader: &mut R) -> io::Result<[u8; 4]> {
+    let mut tag = [0u8; 4];
+    reader.read_exact(&mut tag)?;
+    Ok(tag)
+}
+
+fn read_u32<R: io::Read + ?Sized>(reader: &mut R) -> io::Result<u32> {
+    Ok(u32::from_le_bytes(read_tag(reader)?))
+}
+
+///
+/// Reads exactly size bytes without trusting size for the allocation.
+///
+fn read_chunk<R: io::Read>(reader: &mut R, size: u32) -> io::Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    reader.by_ref().take(size as u64).read_to_end(&mut buf)?;
+    if buf.len() != size as usize {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    //Chunks are aligned to 2 bytes.
+    if size & 1 == 1 {
+        reader.read_exact(&mut [0u8])?;
+    }
+
+    Ok(buf)
+}
+
+fn parse_fmt(fmt: &[u8]) -> io::Result<WavSpec> {
+    if fmt.len() < 16 {
+        return Err(invalid_data("fmt chunk is too small"));
+    }
+
+    let u16_at = |i: usize| u16::from_le_bytes([fmt[i], fmt[i + 1]]);
+    let tag = u16_at(0);
+    let channels = u16_at(2);
+    let sample_rate = u32::from_le_bytes([fmt[4], fmt[5], fmt[6], fmt[7]]);
+    let block_align = u16_at(12);
+    let bits = u16_at(14);
+
+    let valid_bits = match tag {
+        FORMAT_PCM => bits,
+        FORMAT_EXTENSIBLE => {
+            if fmt.len() < 40 {
+                return Err(invalid_data("fmt chunk is too small for WAVE_FORMAT_EXTENSIBLE"));
+            }
+
+            if u16_at(24) != FORMAT_PCM || fmt[26..40] != PCM_GUID_TAIL {
+                return Err(invalid_data("sub format is not PCM"));
+            }
+
+            u16_at(18)
+        }
+        _ => return Err(invalid_data("format is not PCM")),
+    };
+
+    let container = match (bits, valid_bits) {
+        (24, 24) => WavContainer::Packed24,
+        (32, 24) | (32, 32) => WavContainer::Padded32,
+        _ => return Err(invalid_data("only 24 bit samples in 24 or 32 bit containers are supported")),
+    };
+
+    if channels == 0 {
+        return Err(invalid_data("file has no channels"));
+    }
+
+    if block_align as usize != channels as usize * container.bytes() {
+        return Err(invalid_data("block align does not match the sample format"));
+    }
+
+    Ok(WavSpec { channels, sample_rate, container })
+}
+
+///
+/// Reads a WAVE file and returns its format and the interleaved samples.
+///
+/// Chunks other than fmt and data are skipped. Reading stops after the data chunk.
+/// Fails with io::ErrorKind::InvalidData if the file is not a supported 24 bit PCM file.
+///
+pub fn read_wav<R: io::Read>(reader: &mut R) -> io::Result<(WavSpec, Vec<u24>)> {
+    if read_tag(reader)? != *b"RIFF" {
+        return Err(invalid_data("missing RIFF header"));
+    }
+
+    read_u32(reader)?;
+    if read_tag(reader)? != *b"WAVE" {
+        return Err(invalid_data("RIFF file is not a WAVE file"));
+    }
+
+    let mut spec = None;
+    loop {
+        let tag = read_tag(reader)?;
+        let size = read_u32(reader)?;
+        match &tag {
+            b"fmt " => {
+                if size > MAX_FMT_SIZE {
+                    return Err(invalid_data("fmt chunk is too large"));
+                }
+
+                spec = Some(parse_fmt(&read_chunk(reader, size)?)?);
+            }
+            b"data" => {
+                let spec = spec.ok_or_else(|| invalid_data("data chunk before fmt chunk"))?;
+                let data = read_chunk(reader, size)?;
+                let block_align = spec.channels as usize * spec.container.bytes();
+                if !data.len().is_multiple_of(block_align) {
+                    return Err(invalid_data("data chunk does not contain whole frames"));
+                }
+
+                let mut samples = vec![u24::MIN; data.len() / spec.container.bytes()];
+                match spec.container {
+                    WavContainer::Packed24 => (&data[..]).read_u24_le_into(&mut samples)?,
+                    WavContainer::Padded32 => {
+                        for (sample, bytes) in samples.iter_mut().zip(data.chunks_exact(4)) {
+                            *sample = u24::from_le_bytes([bytes[1], bytes[2], bytes[3]]);
+                        }
+                    }
+                }
+
+                return Ok((spec, samples));
+            }
+            _ => {
+                let skip = size as u64 + (size & 1) as u64;
+                if io::copy(&mut reader.by_ref().take(skip), &mut io::sink())? != skip {
+                    return Err(io::ErrorKind::UnexpectedEof.into());
+                }
+            }
+        }
+    }
+}
+
+///
+/// Writes the interleaved samples as a WAVE file.
+///
+/// Packed24 files use the plain PCM format, Padded32 files use WAVE_FORMAT_EXTENSIBLE
+/// with 24 valid bits. Fails with io::ErrorKind::InvalidInput if the spec has no channels,
+/// the samples do not contain whole frames or the file would exceed 4 GiB.
+///
+pub fn write_wav<W: io::Write>(writer: &mut W, spec: &WavSpec, samples: &[u24]) -> io::Result<()> {
+    if spec.channels == 0 {
+        return Err(invalid_input("at least one channel is required"));
+    }
+
+    if !samples.len().is_multiple_of(spec.channels as usize) {
+        return Err(invalid_input("samples do not contain whole frames"));
+    }
+
+    let bytes = spec.container.bytes();
+    let block_align = spec.channels as usize * bytes;
+    let byte_rate = (spec.sample_rate as u64 * block_align as u64)
+        .try_into()
+        .map_err(|_| invalid_input("byte rate does not fit into 32 bits"))?;
+    let block_align: u16 = block_align.try_into().map_err(|_| invalid_input("too many channels"))?;
+
+    let fmt_size: u32 = match spec.container {
+        WavContainer::Packed24 => 16,
+        WavContainer::Padded32 => 40,
+    };
+
+    let data_size: u32 = (samples.len() as u64 * bytes as u64)
+        .try_into()
+        .map_err(|_| invalid_input("data does not fit into a WAVE file"))?;
+    let pad = data_size & 1;
+    let riff_size = data_size
+        .checked_add(pad)
+        .and_then(|n| n.checked_add(4 + 8 + fmt_size + 8))
+        .ok_or_else(|| invalid_input("data does not fit into a WAVE file"))?;
+
+    writer.write_all(b"RIFF")?;
+    writer.write_all(&riff_size.to_le_bytes())?;
+    writer.write_all(b"WAVE")?;
+
+    writer.write_all(b"fmt ")?;
+    writer.write_all(&fmt_size.to_le_bytes())?;
+    let tag = match spec.container {
+        WavContainer::Packed24 => FORMAT_PCM,
+        WavContainer::Padded32 => FORMAT_EXTENSIBLE,
+    };
+    writer.write_all(&tag.to_le_bytes())?;
+    writer.write_all(&spec.channels.to_le_bytes())?;
+    writer.write_all(&spec.sample_rate.to_le_bytes())?;
+    writer.write_all(&u32::to_le_bytes(byte_rate))?;
+    writer.write_all(&block_align.to_le_bytes())?;
+    writer.write_all(&(bytes as u16 * 8).to_le_bytes())?;
+    if spec.container == WavContainer::Padded32 {
+        //cbSize, valid bits per sample, channel mask, sub format.
+        writer.write_all(&22u16.to_le_bytes())?;
+        writer.write_all(&24u16.to_le_bytes())?;
+        writer.write_all(&0u32.to_le_bytes())?;
+        writer.write_all(&FORMAT_PCM.to_le_bytes())?;
+        writer.write_all(&PCM_GUID_TAIL)?;
+    }
+
+    writer.write_all(b"data")?;
+    writer.write_all(&data_size.to_le_bytes())?;
+    match spec.container {
+        WavContainer::Packed24 => writer.write_uintx_le_from(samples)?,
+        WavContainer::Padded32 => {
+            let mut data = Vec::with_capacity(data_size as usize);
+            for sample in samples {
+                data.push(0);
+                data.extend_from_slice(&sample.to_le_bytes());
+            }
+
+            writer.write_all(&data)?;
+        }
+    }
+
+    if pad == 1 {
+        writer.write_all(&[0])?;
+    }
+
+    Ok(())
+}
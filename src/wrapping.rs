@@ -0,0 +1,107 @@
+//!
+//! Wrappers that select the overflow behavior of the arithmetic operators.
+//!
+//! core::num::Wrapping and core::num::Saturating only implement their operators for the primitive types,
+//! so this crate provides its own wrappers for its types. The operators map onto the wrapping_*,
+//! saturating_* and checked_* methods of the wrapped type.
+//!
+//! ```
+//! use uintx::{u24, Checked, Saturating, Wrapping};
+//!
+//! let mut w = Wrapping(u24::MAX);
+//! w += Wrapping(u24::from(2u8));
+//! assert_eq!(w, Wrapping(u24::from(1u8)));
+//!
+//! let s = Saturating(u24::MAX) * Saturating(u24::from(2u8));
+//! assert_eq!(s.0, u24::MAX);
+//!
+//! let c = Checked::new(u24::MAX) + u24::from(1u8) - u24::from(1u8);
+//! assert_eq!(c.get(), None);
+//! ```
+//!
+use core::fmt;
+
+///
+/// Arithmetic operators wrap around on overflow.
+///
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Wrapping<T>(pub T);
+
+///
+/// Arithmetic operators saturate at MIN and MAX instead of overflowing.
+///
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Saturating<T>(pub T);
+
+///
+/// Arithmetic operators produce None on overflow or division by zero, every later operation keeps the None.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checked<T>(pub Option<T>);
+
+impl<T> Checked<T> {
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Checked(Some(value))
+    }
+
+    ///
+    /// Returns the value or None if an operation overflowed.
+    ///
+    #[inline]
+    pub fn get(self) -> Option<T> {
+        self.0
+    }
+
+    ///
+    /// True if any operation that produced this value overflowed.
+    ///
+    #[inline]
+    pub const fn is_overflow(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl<T: Default> Default for Checked<T> {
+    fn default() -> Self {
+        Checked(Some(T::default()))
+    }
+}
+
+impl<T> From<T> for Wrapping<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Wrapping(value)
+    }
+}
+
+impl<T> From<T> for Saturating<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Saturating(value)
+    }
+}
+
+impl<T> From<T> for Checked<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Checked(Some(value))
+    }
+}
+
+macro_rules! wrapper_fmt_impl {
+    ($wrapper:ident: $($fmt:ident),*) => {
+        $(
+            impl<T: fmt::$fmt> fmt::$fmt for $wrapper<T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::$fmt::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
+}
+
+wrapper_fmt_impl!(Wrapping: Debug, Display, LowerHex, UpperHex, Octal, Binary);
+wrapper_fmt_impl!(Saturating: Debug, Display, LowerHex, UpperHex, Octal, Binary);
@@ -16,10 +16,21 @@ cargo build --features unsafe_fetch,ux_support
 cargo build --features intx_support,ux_support
 
 cargo build --features num_traits_support
+cargo build --features num_integer_support
 cargo build --features ux_support
 cargo build --features intx_support
 cargo build --features unsafe_fetch
 cargo build --features half_support
+cargo build --features byteorder
+cargo build --features serde
+cargo build --features rand
+cargo build --features arbitrary
+cargo build --features proptest
+cargo build --features quickcheck
+cargo build --features derive
+cargo build --features alloc
+cargo build --features mmap
+cargo build --features rkyv
 
 cargo clean
 cargo +nightly miri test --features all
@@ -29,7 +40,7 @@ cargo test
 cargo test --release --features all
 cargo test --release
 cargo clean
-cargo test --features num_traits_support,ux_support,intx_support,unsafe_fetch,half_support,serde
+cargo test --features num_traits_support,num_integer_support,ux_support,intx_support,unsafe_fetch,half_support,serde,byteorder,rand,arbitrary,proptest,quickcheck
 cargo clean
 #s390x is big endian
 cross build --target s390x-unknown-linux-gnu
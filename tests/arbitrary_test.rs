@@ -0,0 +1,54 @@
+#![cfg(any(feature = "arbitrary", feature = "quickcheck"))]
+
+use uintx::{u24, u48};
+
+#[cfg(feature = "arbitrary")]
+#[test]
+pub fn test_arbitrary_edge_values() {
+    use arbitrary::{Arbitrary, Unstructured};
+    use uintx::u120;
+
+    //The first byte selects an edge value, the second byte which one.
+    let mut u = Unstructured::new(&[0, 1]);
+    assert_eq!(u24::arbitrary(&mut u).unwrap(), u24::MAX);
+
+    //Otherwise the bytes are taken as a little endian number.
+    let mut u = Unstructured::new(&[1, 0x33, 0x22, 0x11]);
+    assert_eq!(u24::arbitrary(&mut u).unwrap(), 0x112233u32);
+
+    //Running out of data yields zero instead of an error.
+    let mut u = Unstructured::new(&[]);
+    assert_eq!(u48::arbitrary(&mut u).unwrap(), u48::MIN);
+
+    let data: Vec<u8> = (0..=255u8).cycle().take(4096).collect();
+    let mut u = Unstructured::new(&data);
+    let mut seen_max = false;
+    while !u.is_empty() {
+        seen_max |= u120::arbitrary(&mut u).unwrap() == u120::MAX;
+    }
+    assert!(seen_max);
+}
+
+#[cfg(feature = "quickcheck")]
+#[test]
+pub fn test_quickcheck() {
+    use quickcheck::{Arbitrary, Gen};
+
+    let mut g = Gen::new(100);
+    let values: Vec<u24> = (0..1000).map(|_| u24::arbitrary(&mut g)).collect();
+    assert!(values.contains(&u24::MAX));
+    assert!(values.contains(&u24::MIN));
+    assert!(values.iter().any(|v| v.is_power_of_two() && *v > 0xFFFFu32));
+
+    let shrunk: Vec<u48> = u48::MAX.shrink().collect();
+    assert!(!shrunk.is_empty());
+    assert!(shrunk.iter().all(|v| *v < u48::MAX));
+    assert!(u48::MIN.shrink().next().is_none());
+}
+
+#[cfg(feature = "quickcheck")]
+quickcheck::quickcheck! {
+    fn quickcheck_swap_bytes_round_trip(n: u48) -> bool {
+        n.swap_bytes().swap_bytes() == n
+    }
+}
@@ -0,0 +1,220 @@
+use uintx::audio::{
+    deinterleave, f32_to_sample, interleave, sample_from_i32, sample_to_f32, sample_to_i32, samples_to_f32,
+    try_f32_to_sample, Dither, Quantizer, SAMPLE_MAX, SAMPLE_MIN,
+};
+use uintx::u24;
+
+#[test]
+pub fn test_sign_extension() {
+    assert_eq!(sample_to_i32(u24::from_le_bytes([0xFF, 0xFF, 0xFF])), -1);
+    assert_eq!(sample_to_i32(u24::from_le_bytes([0x00, 0x00, 0x80])), SAMPLE_MIN);
+    assert_eq!(sample_to_i32(u24::from_le_bytes([0xFF, 0xFF, 0x7F])), SAMPLE_MAX);
+    assert_eq!(sample_to_i32(u24::from_le_bytes([0x01, 0x00, 0x00])), 1);
+
+    for n in [SAMPLE_MIN, -1000, -1, 0, 1, 1000, SAMPLE_MAX] {
+        assert_eq!(sample_to_i32(sample_from_i32(n)), n);
+    }
+
+    assert_eq!(sample_to_i32(sample_from_i32(i32::MAX)), SAMPLE_MAX);
+    assert_eq!(sample_to_i32(sample_from_i32(i32::MIN)), SAMPLE_MIN);
+}
+
+#[test]
+pub fn test_float_conversion() {
+    assert_eq!(sample_to_f32(sample_from_i32(SAMPLE_MIN)), -1.0);
+    assert_eq!(sample_to_f32(sample_from_i32(0)), 0.0);
+    assert_eq!(sample_to_f32(sample_from_i32(1 << 22)), 0.5);
+    assert!(sample_to_f32(sample_from_i32(SAMPLE_MAX)) < 1.0);
+
+    assert_eq!(sample_to_i32(f32_to_sample(0.5)), 1 << 22);
+    assert_eq!(sample_to_i32(f32_to_sample(-1.0)), SAMPLE_MIN);
+    assert_eq!(sample_to_i32(f32_to_sample(1.0)), SAMPLE_MAX);
+    assert_eq!(sample_to_i32(f32_to_sample(-3.0)), SAMPLE_MIN);
+    assert_eq!(sample_to_i32(f32_to_sample(f32::NAN)), 0);
+    //Rounds to the nearest sample.
+    assert_eq!(sample_to_i32(f32_to_sample(2.6 / 8388608.0)), 3);
+    assert_eq!(sample_to_i32(f32_to_sample(-2.6 / 8388608.0)), -3);
+
+    assert_eq!(try_f32_to_sample(1.0), None);
+    assert_eq!(try_f32_to_sample(f32::NAN), None);
+    assert_eq!(try_f32_to_sample(-1.0).map(sample_to_i32), Some(SAMPLE_MIN));
+
+    let src = [sample_from_i32(-4194304), sample_from_i32(4194304)];
+    let mut dst = [0.0f32; 2];
+    samples_to_f32(&src, &mut dst);
+    assert_eq!(dst, [-0.5, 0.5]);
+
+    for n in (SAMPLE_MIN..=SAMPLE_MAX).step_by(997) {
+        let sample = sample_from_i32(n);
+        assert_eq!(f32_to_sample(sample_to_f32(sample)), sample);
+    }
+}
+
+#[test]
+pub fn test_quantizer() {
+    let mut plain = Quantizer::new(Dither::None);
+    assert_eq!(plain.quantize(0.25), f32_to_sample(0.25));
+    assert_eq!(plain.clipped_samples(), 0);
+    plain.quantize(1.5);
+    plain.quantize(-1.0);
+    assert_eq!(plain.clipped_samples(), 1);
+
+    //Dithered samples stay within one step of the exact value and average out.
+    for dither in [Dither::Rectangular, Dither::Triangular] {
+        let mut q = Quantizer::new(dither).with_seed(42);
+        let value = 100.25 / 8388608.0;
+        let mut sum = 0i64;
+        for _ in 0..10000 {
+            let n = sample_to_i32(q.quantize(value));
+            assert!((99..=101).contains(&n), "{:?} {}", dither, n);
+            sum += n as i64;
+        }
+
+        let mean = sum as f64 / 10000.0;
+        assert!((mean - 100.25).abs() < 0.05, "{:?} {}", dither, mean);
+    }
+
+    //Same seed, same noise.
+    let src = [0.1f32, 0.2, 0.3, 0.4];
+    let mut a = [u24::MIN; 4];
+    let mut b = [u24::MIN; 4];
+    Quantizer::new(Dither::Triangular).with_seed(7).quantize_slice(&src, &mut a);
+    Quantizer::new(Dither::Triangular).with_seed(7).quantize_slice(&src, &mut b);
+    assert_eq!(a, b);
+}
+
+#[test]
+pub fn test_interleave() {
+    let frames: Vec<u24> = (0u32..6).map(u24::from).collect();
+    let mut left = [u24::MIN; 3];
+    let mut right = [u24::MIN; 3];
+    deinterleave(&frames, &mut [&mut left, &mut right]);
+    assert_eq!(left, [u24::from(0u8), u24::from(2u8), u24::from(4u8)]);
+    assert_eq!(right, [u24::from(1u8), u24::from(3u8), u24::from(5u8)]);
+
+    let mut joined = [u24::MIN; 6];
+    interleave(&[&left, &right], &mut joined);
+    assert_eq!(&joined[..], &frames[..]);
+
+    let mut mono = [0i32; 2];
+    deinterleave(&[1, 2], &mut [&mut mono]);
+    assert_eq!(mono, [1, 2]);
+}
+
+#[test]
+#[should_panic]
+pub fn test_interleave_length_mismatch() {
+    let mut joined = [0u8; 5];
+    interleave(&[&[1u8, 2][..], &[3, 4][..]], &mut joined);
+}
+
+#[cfg(feature = "std")]
+mod wav {
+    use super::*;
+    use std::io::ErrorKind;
+    use uintx::wav::{read_wav, write_wav, WavContainer, WavSpec};
+
+    fn samples() -> Vec<u24> {
+        [0, 1, -1, SAMPLE_MIN, SAMPLE_MAX, 1234567].into_iter().map(sample_from_i32).collect()
+    }
+
+    #[test]
+    pub fn test_packed24() {
+        let spec = WavSpec { channels: 2, sample_rate: 48000, container: WavContainer::Packed24 };
+        let mut file = Vec::new();
+        write_wav(&mut file, &spec, &samples()).unwrap();
+        assert_eq!(file.len(), 44 + 18);
+        assert_eq!(&file[0..4], b"RIFF");
+        assert_eq!(u32::from_le_bytes(file[4..8].try_into().unwrap()) as usize, file.len() - 8);
+        //Samples are stored as 3 little endian bytes.
+        assert_eq!(&file[44..50], &[0, 0, 0, 1, 0, 0]);
+
+        let (read_spec, read) = read_wav(&mut &file[..]).unwrap();
+        assert_eq!(read_spec, spec);
+        assert_eq!(read, samples());
+    }
+
+    #[test]
+    pub fn test_padded32() {
+        let spec = WavSpec { channels: 3, sample_rate: 96000, container: WavContainer::Padded32 };
+        let mut file = Vec::new();
+        write_wav(&mut file, &spec, &samples()).unwrap();
+        assert_eq!(file.len(), 68 + 24);
+        assert_eq!(u16::from_le_bytes([file[20], file[21]]), 0xFFFE);
+        assert_eq!(&file[68..76], &[0, 0, 0, 0, 0, 1, 0, 0]);
+
+        let (read_spec, read) = read_wav(&mut &file[..]).unwrap();
+        assert_eq!(read_spec, spec);
+        assert_eq!(read, samples());
+    }
+
+    #[test]
+    pub fn test_odd_data_and_unknown_chunks() {
+        let spec = WavSpec { channels: 1, sample_rate: 8000, container: WavContainer::Packed24 };
+        let mut file = Vec::new();
+        write_wav(&mut file, &spec, &samples()[..1]).unwrap();
+        //Data chunk of 3 bytes is padded to 4.
+        assert_eq!(file.len(), 44 + 4);
+
+        //Insert an odd sized LIST chunk between fmt and data.
+        let mut with_list = file[..36].to_vec();
+        with_list.extend_from_slice(b"LIST");
+        with_list.extend_from_slice(&3u32.to_le_bytes());
+        with_list.extend_from_slice(&[1, 2, 3, 0]);
+        with_list.extend_from_slice(&file[36..]);
+        let (_, read) = read_wav(&mut &with_list[..]).unwrap();
+        assert_eq!(read, &samples()[..1]);
+    }
+
+    #[test]
+    pub fn test_32_bit_pcm() {
+        //Plain 32 bit PCM, the least significant byte is discarded.
+        let mut file = Vec::new();
+        file.extend_from_slice(b"RIFF");
+        file.extend_from_slice(&44u32.to_le_bytes());
+        file.extend_from_slice(b"WAVEfmt ");
+        file.extend_from_slice(&16u32.to_le_bytes());
+        for n in [1u16, 1] {
+            file.extend_from_slice(&n.to_le_bytes());
+        }
+        file.extend_from_slice(&44100u32.to_le_bytes());
+        file.extend_from_slice(&(44100u32 * 4).to_le_bytes());
+        for n in [4u16, 32] {
+            file.extend_from_slice(&n.to_le_bytes());
+        }
+        file.extend_from_slice(b"data");
+        file.extend_from_slice(&8u32.to_le_bytes());
+        file.extend_from_slice(&(-256i32 * 5).to_le_bytes());
+        file.extend_from_slice(&i32::MAX.to_le_bytes());
+
+        let (spec, read) = read_wav(&mut &file[..]).unwrap();
+        assert_eq!(spec.container, WavContainer::Padded32);
+        assert_eq!(read.iter().map(|s| sample_to_i32(*s)).collect::<Vec<_>>(), [-5, SAMPLE_MAX]);
+    }
+
+    #[test]
+    pub fn test_errors() {
+        let spec = WavSpec { channels: 2, sample_rate: 48000, container: WavContainer::Packed24 };
+        let mut file = Vec::new();
+        assert_eq!(write_wav(&mut file, &spec, &samples()[..3]).unwrap_err().kind(), ErrorKind::InvalidInput);
+        let no_channels = WavSpec { channels: 0, ..spec };
+        assert_eq!(write_wav(&mut file, &no_channels, &[]).unwrap_err().kind(), ErrorKind::InvalidInput);
+
+        write_wav(&mut file, &spec, &samples()).unwrap();
+        assert_eq!(read_wav(&mut &file[..50]).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+
+        let mut not_wave = file.clone();
+        not_wave[8..12].copy_from_slice(b"AVI ");
+        assert_eq!(read_wav(&mut &not_wave[..]).unwrap_err().kind(), ErrorKind::InvalidData);
+
+        //16 bit samples are not supported.
+        let mut pcm16 = file.clone();
+        pcm16[34] = 16;
+        assert_eq!(read_wav(&mut &pcm16[..]).unwrap_err().kind(), ErrorKind::InvalidData);
+
+        //Huge data chunk sizes do not allocate before the data was read.
+        let mut truncated = file[..44].to_vec();
+        truncated[40..44].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(read_wav(&mut &truncated[..]).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,135 @@
+use uintx::{bitfield, u24, u48, u96};
+
+#[test]
+pub fn test_get_bits() {
+    let n = u24::from(0b1010_1100_0011_1111u32);
+    assert_eq!(n.get_bits(0..4), 0b1111);
+    assert_eq!(n.get_bits(5..11), 0b10_0001);
+    assert_eq!(n.get_bits(0..24), n.as_num());
+    assert_eq!(n.get_bits(7..7), 0);
+    assert_eq!(n.get_bits(24..24), 0);
+    assert_eq!(u24::MAX.get_bits(20..24), 0xF);
+
+    let n = u96::MAX;
+    assert_eq!(n.get_bits(0..96), u96::MAX_VALUE);
+    assert_eq!(n.get_bits(90..96), 0b11_1111);
+
+    assert!(n.get_bit(95));
+    assert!(!u24::from(2u8).get_bit(0));
+    assert!(u24::from(2u8).get_bit(1));
+}
+
+#[test]
+pub fn test_with_bits() {
+    let n = u48::MIN.with_bits(5..11, 0x3F);
+    assert_eq!(n.as_num(), 0x3F << 5);
+    assert_eq!(n.with_bits(5..11, 0).as_num(), 0);
+    assert_eq!(u48::MAX.with_bits(8..16, 0).as_num(), 0xFFFF_FFFF_00FF);
+    assert_eq!(u48::MIN.with_bits(0..48, u48::MAX_VALUE), u48::MAX);
+
+    let mut n = u24::MIN;
+    n.set_bits(20..24, 0xA);
+    assert_eq!(n.as_num(), 0xA0_0000);
+    n.set_bit(0, true);
+    n.set_bit(23, false);
+    assert_eq!(n.as_num(), 0x20_0001);
+    n.toggle_bit(1);
+    n.toggle_bit(0);
+    assert_eq!(n.as_num(), 0x20_0002);
+
+    const N: u24 = u24::MIN.with_bits(4..8, 0xF);
+    assert_eq!(N.as_num(), 0xF0);
+}
+
+#[test]
+#[should_panic(expected = "value does not fit")]
+pub fn test_with_bits_value_too_large() {
+    u24::MIN.with_bits(0..4, 0x10);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+pub fn test_get_bits_out_of_bounds() {
+    u24::MIN.get_bits(20..25);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+pub fn test_set_bit_out_of_bounds() {
+    let mut n = u24::MIN;
+    n.set_bit(24, true);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Mode {
+    Off,
+    On,
+    Auto,
+}
+
+impl uintx::bits::BitfieldValue for Mode {
+    const BITS: u32 = 2;
+
+    fn from_bitfield(bits: u128) -> Self {
+        match bits {
+            0 => Mode::Off,
+            1 => Mode::On,
+            _ => Mode::Auto,
+        }
+    }
+
+    fn into_bitfield(self) -> u128 {
+        self as u128
+    }
+}
+
+bitfield! {
+    /// Rgb565 style pixel with a mode.
+    pub struct Packed(u24) {
+        pub blue, set_blue: u8 = 0..5;
+        pub green, set_green: u8 = 5..11;
+        pub red, set_red: u8 = 11..16;
+        pub mode, set_mode: Mode = 16..18;
+        pub flag, set_flag: bool = 23..24;
+    }
+}
+
+bitfield! {
+    struct Wide(u96) {
+        low, set_low: u64 = 0..64;
+        high, set_high: u24 = 64..88;
+    }
+}
+
+#[test]
+pub fn test_bitfield() {
+    let mut p = Packed::new();
+    p.set_red(31);
+    p.set_green(1);
+    p.set_blue(2);
+    p.set_mode(Mode::Auto);
+    p.set_flag(true);
+    assert_eq!((p.red(), p.green(), p.blue(), p.mode(), p.flag()), (31, 1, 2, Mode::Auto, true));
+    assert_eq!(u24::from(p).as_num(), 0x82_F822);
+    assert_eq!(Packed::from(u24::from(0xF800u32)).red(), 31);
+    assert_eq!(
+        format!("{:?}", p),
+        "Packed { blue: 2, green: 1, red: 31, mode: Auto, flag: true }"
+    );
+
+    p.set_mode(Mode::Off);
+    assert_eq!(p.mode(), Mode::Off);
+    assert_eq!(p.red(), 31);
+
+    let mut w = Wide::new();
+    w.set_low(u64::MAX);
+    w.set_high(u24::MAX);
+    assert_eq!(w.into_bits().as_num(), (1u128 << 88) - 1);
+    assert_eq!(Wide::from_bits(u96::MAX).high(), u24::MAX);
+}
+
+#[test]
+#[should_panic(expected = "value does not fit into field green")]
+pub fn test_bitfield_value_too_large() {
+    Packed::new().set_green(64);
+}
@@ -0,0 +1,139 @@
+use uintx::bits::{BitError, BitOrder, BitReader, BitWriter};
+use uintx::{u24, u40, u48, u120};
+
+#[test]
+pub fn test_read_msb_first() {
+    let data = [0b1010_1100u8, 0x12, 0x34, 0x56, 0b0111_0000];
+    let mut reader = BitReader::new(&data);
+    assert_eq!(reader.read_bits::<u24>(4).unwrap(), 0b1010);
+    assert_eq!(reader.read_bits::<u24>(4).unwrap(), 0b1100);
+    assert_eq!(reader.read_u24().unwrap(), 0x123456);
+    assert!(!reader.read_bit().unwrap());
+    assert_eq!(reader.read_bits::<u24>(3).unwrap(), 0b111);
+    assert_eq!(reader.position(), 36);
+    assert_eq!(reader.remaining(), 4);
+}
+
+#[test]
+pub fn test_read_unaligned_u24() {
+    //0xABCDEF shifted right by 3 bits with 101 in front.
+    let data = [0b1011_0101u8, 0b0111_1001, 0b1011_1101, 0b1110_0000];
+    let mut reader = BitReader::new(&data);
+    assert_eq!(reader.read_bits::<u24>(3).unwrap(), 0b101);
+    assert_eq!(reader.read_u24().unwrap(), 0xABCDEF);
+}
+
+#[test]
+pub fn test_read_lsb_first() {
+    let data = [0x56u8, 0x34, 0x12, 0b0000_0101];
+    let mut reader = BitReader::with_order(&data, BitOrder::LsbFirst);
+    assert_eq!(reader.read_u24().unwrap(), 0x123456);
+    assert_eq!(reader.read_bits::<u24>(1).unwrap(), 1);
+    assert_eq!(reader.read_bits::<u24>(2).unwrap(), 0b10);
+
+    let mut reader = BitReader::with_order(&data, BitOrder::LsbFirst);
+    assert_eq!(reader.read_bits::<u24>(4).unwrap(), 0x6);
+    assert_eq!(reader.read_bits::<u24>(20).unwrap(), 0x12345);
+}
+
+#[test]
+pub fn test_underrun() {
+    let data = [0u8; 5];
+    let mut reader = BitReader::new(&data);
+    reader.skip(3).unwrap();
+    assert_eq!(
+        reader.read_u40(),
+        Err(BitError::Underrun {
+            requested: 40,
+            available: 37
+        })
+    );
+    assert_eq!(reader.position(), 3);
+    assert_eq!(
+        reader.read_bits::<u24>(25),
+        Err(BitError::TooManyBits {
+            requested: 25,
+            max: 24
+        })
+    );
+    reader.align();
+    assert_eq!(reader.position(), 8);
+    assert!(reader.read_bits::<u40>(32).is_ok());
+    assert!(reader.read_bit().is_err());
+}
+
+#[test]
+pub fn test_write_msb_first() {
+    let mut data = [0xFFu8; 5];
+    let mut writer = BitWriter::new(&mut data);
+    writer.write_bits(u24::from(0b1010), 4).unwrap();
+    writer.write_bits(u24::from(0b1100), 4).unwrap();
+    writer.write_u24(u24::from(0x123456)).unwrap();
+    writer.write_bit(false).unwrap();
+    writer.align();
+    assert_eq!(writer.position(), 40);
+    assert_eq!(writer.bytes_written(), 5);
+    assert!(writer.write_bit(true).is_err());
+    assert_eq!(data, [0b1010_1100, 0x12, 0x34, 0x56, 0]);
+}
+
+#[test]
+pub fn test_write_lsb_first() {
+    let mut data = [0u8; 4];
+    let mut writer = BitWriter::with_order(&mut data, BitOrder::LsbFirst);
+    writer.write_bits(u24::from(0x6), 4).unwrap();
+    writer.write_bits(u24::from(0x12345), 20).unwrap();
+    writer.write_bits(u24::from(0b101), 3).unwrap();
+    assert_eq!(data, [0x56, 0x34, 0x12, 0b0000_0101]);
+}
+
+#[test]
+pub fn test_write_overflow() {
+    let mut data = [0u8; 3];
+    let mut writer = BitWriter::new(&mut data);
+    writer.write_bit(true).unwrap();
+    assert_eq!(
+        writer.write_u24(u24::MAX),
+        Err(BitError::Overflow {
+            requested: 24,
+            available: 23
+        })
+    );
+    assert_eq!(writer.position(), 1);
+}
+
+#[test]
+pub fn test_round_trip() {
+    for order in [BitOrder::MsbFirst, BitOrder::LsbFirst] {
+        let mut data = [0u8; 256];
+        let mut writer = BitWriter::with_order(&mut data, order);
+        for offset in 0..8u32 {
+            writer.write_bits(u24::from(offset), offset).unwrap();
+            writer.write_u40(u40::from(0x1122334455u64 + offset as u64)).unwrap();
+            writer.write_u48(u48::MAX).unwrap();
+            writer.write_bits(u120::MAX, 77).unwrap();
+        }
+        let written = writer.position();
+
+        let mut reader = BitReader::with_order(&data, order);
+        for offset in 0..8u32 {
+            assert_eq!(reader.read_bits::<u24>(offset).unwrap(), offset & ((1 << offset) - 1));
+            assert_eq!(reader.read_u40().unwrap(), 0x1122334455u64 + offset as u64);
+            assert_eq!(reader.read_u48().unwrap(), u48::MAX);
+            assert_eq!(reader.read_bits::<u120>(77).unwrap(), (1u128 << 77) - 1);
+        }
+        assert_eq!(reader.position(), written);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+pub fn test_write_vec() {
+    let mut data = vec![0xAAu8];
+    let mut writer = BitWriter::from_vec(&mut data);
+    writer.write_bits(u24::from(0b1), 1).unwrap();
+    writer.write_u24(u24::from(0x123456)).unwrap();
+    assert_eq!(writer.bytes_written(), 4);
+    writer.align();
+    assert_eq!(data, vec![0xAA, 0x89, 0x1A, 0x2B, 0x00]);
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "byteorder")]
+
+use byteorder::{BigEndian, ByteOrder, LittleEndian, NativeEndian};
+use uintx::byte_order::UintxByteOrder;
+use uintx::{u24, u48, u56, u72};
+
+#[test]
+pub fn test_read_write() {
+    let buf = [0x11u8, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99];
+    assert_eq!(BigEndian::read_uintx::<u24>(&buf), 0x112233);
+    assert_eq!(LittleEndian::read_uintx::<u24>(&buf), 0x332211);
+    assert_eq!(BigEndian::read_uintx::<u72>(&buf), 0x112233445566778899u128);
+    assert_eq!(LittleEndian::read_uintx::<u72>(&buf), 0x998877665544332211u128);
+    //Same result as the u32 based functions of byteorder
+    assert_eq!(BigEndian::read_uintx::<u24>(&buf), BigEndian::read_u24(&buf));
+    assert_eq!(LittleEndian::read_uintx::<u48>(&buf), LittleEndian::read_u48(&buf));
+
+    let mut out = [0u8; 7];
+    BigEndian::write_uintx(&mut out, u56::from(0x11223344556677u64));
+    assert_eq!(out, [0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77]);
+    LittleEndian::write_uintx(&mut out, u56::from(0x11223344556677u64));
+    assert_eq!(out, [0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11]);
+}
+
+fn round_trip<B: ByteOrder>() {
+    let values: Vec<u48> = (0..50u64).map(|n| u48::from(n * 0x010203040506)).collect();
+    let mut bytes = vec![0u8; values.len() * 6];
+    B::write_uintx_into(&values, &mut bytes);
+    for (idx, chunk) in bytes.chunks_exact(6).enumerate() {
+        assert_eq!(B::read_u48(chunk), values[idx].as_num());
+    }
+
+    let mut out = vec![u48::MIN; values.len()];
+    B::read_uintx_into(&bytes, &mut out);
+    assert_eq!(out, values);
+}
+
+#[test]
+pub fn test_slices() {
+    round_trip::<BigEndian>();
+    round_trip::<LittleEndian>();
+    round_trip::<NativeEndian>();
+}
+
+#[test]
+pub fn test_from_slice() {
+    let mut numbers = [u24::from_be_bytes([0x11, 0x22, 0x33]).to_be(); 2];
+    BigEndian::from_slice_uintx(&mut numbers);
+    assert_eq!(numbers, [u24::from(0x112233); 2]);
+
+    let mut numbers = [u24::from(0x112233); 2];
+    NativeEndian::from_slice_uintx(&mut numbers);
+    assert_eq!(numbers, [u24::from(0x112233); 2]);
+}
+
+#[test]
+#[should_panic]
+pub fn test_too_small() {
+    BigEndian::read_uintx::<u24>(&[0, 1]);
+}
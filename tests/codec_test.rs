@@ -0,0 +1,134 @@
+#![cfg(feature = "alloc")]
+
+use uintx::bits::BitError;
+use uintx::codec::{PackedBlocks, Transform, BLOCK_LEN};
+use uintx::{u120, u24, u40, u56, u72, UnalignedUint};
+
+fn check_round_trip<T: UnalignedUint>(values: &[T]) {
+    for transform in [Transform::Delta, Transform::FrameOfReference] {
+        let packed = PackedBlocks::encode(values, transform);
+        assert_eq!(packed.len(), values.len());
+        assert_eq!(packed.block_count(), values.len().div_ceil(BLOCK_LEN));
+        assert_eq!(packed.to_vec(), values);
+
+        for block in 0..packed.block_count() {
+            let start = block * BLOCK_LEN;
+            assert_eq!(packed.block_first(block), values[start]);
+            let mut out = vec![T::MIN; BLOCK_LEN];
+            let n = packed.decode_block(block, &mut out);
+            assert_eq!(n, packed.block_len(block));
+            assert_eq!(out[..n], values[start..start + n]);
+        }
+
+        //Odd buffer sizes exercise the internal buffering of the decoder.
+        for chunk in [1, 7, BLOCK_LEN, 300] {
+            let mut decoder = packed.decoder();
+            let mut decoded = Vec::new();
+            let mut buffer = vec![T::MIN; chunk];
+            loop {
+                assert_eq!(decoder.remaining(), values.len() - decoded.len());
+                let n = decoder.decode_into(&mut buffer);
+                if n == 0 {
+                    break;
+                }
+                decoded.extend_from_slice(&buffer[..n]);
+            }
+            assert_eq!(decoded, values);
+        }
+
+        assert_eq!(packed.decoder().collect::<Vec<_>>(), values);
+
+        let restored = PackedBlocks::<T>::from_bytes(packed.as_bytes().to_vec(), transform, values.len()).unwrap();
+        assert_eq!(restored, packed);
+    }
+}
+
+#[test]
+pub fn test_sorted_postings() {
+    let postings: Vec<u40> = (0..10_000u64).map(|i| u40::from_num_truncated(0x80_0000_0000 + i * 5 + i % 3)).collect();
+    check_round_trip(&postings);
+
+    let packed = PackedBlocks::encode(&postings, Transform::Delta);
+    //Deltas up to 7 need 3 bits instead of 40.
+    assert!(packed.as_bytes().len() < postings.len() * 3 / 8 + packed.block_count() * 6 + 1);
+    assert_eq!(packed.block_offset(1), 6 + (127 * 3usize).div_ceil(8));
+
+    let mut decoder = packed.decoder();
+    decoder.seek_block(78);
+    assert_eq!(decoder.next(), Some(postings[78 * BLOCK_LEN]));
+    assert_eq!(decoder.len(), postings.len() - 78 * BLOCK_LEN - 1);
+}
+
+#[test]
+pub fn test_edge_cases() {
+    check_round_trip::<u24>(&[]);
+    check_round_trip(&[u24::MAX]);
+    check_round_trip(&[u24::MAX; 129]);
+    check_round_trip(&[u56::MAX, u56::MIN, u56::MAX, u56::MIN]);
+    check_round_trip(&(0..255u32).map(|i| u72::from(u32::MAX - i)).collect::<Vec<_>>());
+    check_round_trip(&[u120::MIN, u120::MAX]);
+
+    //Constant blocks pack to the header alone.
+    let packed = PackedBlocks::encode(&[u40::MAX; BLOCK_LEN], Transform::FrameOfReference);
+    assert_eq!(packed.as_bytes().len(), 6);
+}
+
+#[test]
+pub fn test_from_bytes_errors() {
+    let values: Vec<u24> = (0..200u32).map(|i| u24::from(i * 1000)).collect();
+    let packed = PackedBlocks::encode(&values, Transform::Delta);
+    let bytes = packed.as_bytes().to_vec();
+
+    let short = bytes[..bytes.len() - 1].to_vec();
+    assert!(matches!(PackedBlocks::<u24>::from_bytes(short, Transform::Delta, 200), Err(BitError::Underrun { .. })));
+
+    let mut invalid = bytes.clone();
+    invalid[3] = 25;
+    assert!(matches!(
+        PackedBlocks::<u24>::from_bytes(invalid, Transform::Delta, 200),
+        Err(BitError::TooManyBits { requested: 25, max: 24 })
+    ));
+
+    assert!(matches!(
+        PackedBlocks::<u24>::from_bytes(bytes.clone(), Transform::Delta, usize::MAX),
+        Err(BitError::Underrun { .. })
+    ));
+    assert!(matches!(
+        PackedBlocks::<u24>::from_bytes(Vec::new(), Transform::FrameOfReference, usize::MAX),
+        Err(BitError::Underrun { .. })
+    ));
+
+    let mut long = bytes.clone();
+    long.push(0);
+    assert_eq!(PackedBlocks::<u24>::from_bytes(long, Transform::Delta, 200).unwrap(), packed);
+}
+
+#[cfg(all(feature = "proptest", not(miri)))]
+mod properties {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn round_trip_u24(values in proptest::collection::vec(any::<u24>(), 0..600)) {
+            check_round_trip(&values);
+        }
+
+        #[test]
+        fn round_trip_sorted_u40(mut values in proptest::collection::vec(any::<u40>(), 0..600)) {
+            values.sort();
+            check_round_trip(&values);
+        }
+
+        #[test]
+        fn round_trip_u120(values in proptest::collection::vec(any::<u120>(), 0..300)) {
+            check_round_trip(&values);
+        }
+
+        #[test]
+        fn round_trip_small_range(base in any::<u56>(), offsets in proptest::collection::vec(0u32..1000, 0..600)) {
+            let values: Vec<u56> = offsets.iter().map(|o| base.wrapping_add(u56::from(*o))).collect();
+            check_round_trip(&values);
+        }
+    }
+}
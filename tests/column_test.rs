@@ -0,0 +1,72 @@
+#![cfg(feature = "alloc")]
+
+use uintx::column::{ColumnDecodeError, NarrowedColumn};
+
+#[test]
+pub fn test_width_selection() {
+    //Every width boundary selects the expected width, one more selects the next width.
+    for width in 1..=16usize {
+        let max = if width == 16 { u128::MAX } else { (1u128 << (width * 8)) - 1 };
+        let values = [0, max / 3, max];
+        let column = NarrowedColumn::from_slice(&values);
+        assert_eq!(column.width(), width);
+        assert_eq!(column.len(), 3);
+        assert_eq!(column.iter().collect::<Vec<_>>(), values);
+        assert_eq!(column.get(2), max);
+
+        if width < 16 {
+            assert_eq!(NarrowedColumn::from_slice(&[max + 1]).width(), width + 1);
+        }
+    }
+
+    assert!(matches!(NarrowedColumn::from_slice(&[0xFF_FFFFu64]), NarrowedColumn::U24(_)));
+    assert!(matches!(NarrowedColumn::from_slice(&[u64::MAX]), NarrowedColumn::U64(_)));
+    assert!(matches!(NarrowedColumn::from_slice::<u64>(&[]), NarrowedColumn::U8(_)));
+    assert!(NarrowedColumn::default().is_empty());
+}
+
+#[test]
+pub fn test_from_iter() {
+    let column: NarrowedColumn = (0..1000u64).map(|i| i << 30).collect();
+    assert!(matches!(column, NarrowedColumn::U40(_)));
+    assert_eq!(column.get(999), 999 << 30);
+
+    let column: NarrowedColumn = [1u128 << 100].into_iter().collect();
+    assert!(matches!(column, NarrowedColumn::U104(_)));
+}
+
+#[test]
+pub fn test_round_trip() {
+    for width in 1..=16usize {
+        let max = if width == 16 { u128::MAX } else { (1u128 << (width * 8)) - 1 };
+        let values: Vec<u128> = (0..50u128).map(|i| max / 50 * i + i % 7).chain([max]).collect();
+        let column = NarrowedColumn::from_slice(&values);
+        let bytes = column.to_bytes();
+        assert_eq!(bytes.len(), column.encoded_len());
+        assert_eq!(bytes[0] as usize, column.width());
+        assert_eq!(NarrowedColumn::from_bytes(&bytes), Ok(column.clone()));
+
+        let mut appended = vec![0xAA];
+        column.write_bytes(&mut appended);
+        assert_eq!(appended[1..], bytes[..]);
+    }
+
+    //Values are stored in little endian byte order after the tag.
+    assert_eq!(NarrowedColumn::from_slice(&[0x010203u32]).to_bytes(), [3, 3, 2, 1]);
+}
+
+#[test]
+pub fn test_decode_errors() {
+    assert_eq!(NarrowedColumn::from_bytes(&[]), Err(ColumnDecodeError::Empty));
+    assert_eq!(NarrowedColumn::from_bytes(&[0]), Err(ColumnDecodeError::InvalidWidth { width: 0 }));
+    assert_eq!(NarrowedColumn::from_bytes(&[17, 1]), Err(ColumnDecodeError::InvalidWidth { width: 17 }));
+    assert_eq!(
+        NarrowedColumn::from_bytes(&[3, 1, 2, 3, 4]),
+        Err(ColumnDecodeError::InvalidLength { len: 4, width: 3 })
+    );
+    assert_eq!(NarrowedColumn::from_bytes(&[5]), Ok(NarrowedColumn::U40(Vec::new())));
+    assert_eq!(
+        ColumnDecodeError::InvalidLength { len: 4, width: 3 }.to_string(),
+        "column data of 4 bytes is not a multiple of the width of 3 bytes"
+    );
+}
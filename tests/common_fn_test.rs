@@ -0,0 +1,118 @@
+use uintx::{u24, u72, UnalignedUint};
+
+#[test]
+pub fn test_rotate() {
+    let a = u24::from_num_truncated(0x80_0001);
+    assert_eq!(a.rotate_left(1).as_num(), 0x00_0003);
+    assert_eq!(a.rotate_left(25).as_num(), 0x00_0003);
+    assert_eq!(a.rotate_right(1).as_num(), 0xC0_0000);
+    assert_eq!(a.rotate_right(49).as_num(), 0xC0_0000);
+    assert_eq!(a.rotate_left(24), a);
+
+    let b = u72::from_num_truncated(1);
+    assert_eq!(b.rotate_right(1).as_num(), 1 << 71);
+    assert_eq!(b.rotate_left(73).as_num(), 2);
+}
+
+#[test]
+pub fn test_reverse_bits() {
+    assert_eq!(
+        u24::from_num_truncated(1).reverse_bits().as_num(),
+        0x80_0000
+    );
+    assert_eq!(
+        u24::from_num_truncated(0x12_3456).reverse_bits().as_num(),
+        0x12_3456u32.reverse_bits() >> 8
+    );
+    assert_eq!(
+        u72::from_num_truncated(0b110).reverse_bits().as_num(),
+        0b011 << 69
+    );
+    assert_eq!(u72::MAX.reverse_bits(), u72::MAX);
+}
+
+#[test]
+pub fn test_checked_mul() {
+    assert_eq!(
+        u24::from_num_truncated(0x1000).checked_mul(u24::from_num_truncated(0x0FFF)),
+        Some(u24::from_num_truncated(0xFF_F000))
+    );
+    assert_eq!(
+        u24::from_num_truncated(0x1000).checked_mul(u24::from_num_truncated(0x1000)),
+        None
+    );
+    assert_eq!(u24::MAX.checked_mul(u24::from_num_truncated(2)), None);
+    assert_eq!(
+        u72::MAX.checked_mul(u72::from_num_truncated(1)),
+        Some(u72::MAX)
+    );
+    assert_eq!(
+        u72::from_num_truncated(1 << 36).checked_mul(u72::from_num_truncated(1 << 36)),
+        None
+    );
+}
+
+#[test]
+pub fn test_next_multiple_of() {
+    assert_eq!(
+        u24::from_num_truncated(5).checked_next_multiple_of(u24::from_num_truncated(4)),
+        Some(u24::from_num_truncated(8))
+    );
+    assert_eq!(
+        u24::from_num_truncated(8).checked_next_multiple_of(u24::from_num_truncated(4)),
+        Some(u24::from_num_truncated(8))
+    );
+    assert_eq!(
+        u24::MAX.checked_next_multiple_of(u24::from_num_truncated(2)),
+        None
+    );
+    assert_eq!(
+        u24::from_num_truncated(5).checked_next_multiple_of(u24::MIN),
+        None
+    );
+    assert_eq!(
+        u24::from_num_truncated(5)
+            .next_multiple_of(u24::from_num_truncated(3))
+            .as_num(),
+        6
+    );
+    assert_eq!(u24::from_num_truncated(5).next_power_of_two().as_num(), 8);
+    assert_eq!(
+        u24::from_num_truncated(0x40_0000)
+            .next_power_of_two()
+            .as_num(),
+        0x40_0000
+    );
+}
+
+#[test]
+pub fn test_shift_modulo() {
+    let a = u24::from_num_truncated(0x80_0001);
+    assert_eq!(a.wrapping_shl(1).as_num(), 0x00_0002);
+    assert_eq!(a.wrapping_shl(25).as_num(), 0x00_0002);
+    assert_eq!(a.wrapping_shr(25).as_num(), 0x40_0000);
+    assert_eq!(a.wrapping_shl(24), a);
+
+    assert_eq!(a.overflowing_shl(1), (u24::from_num_truncated(2), false));
+    assert_eq!(a.overflowing_shl(25), (u24::from_num_truncated(2), true));
+    assert_eq!(a.overflowing_shr(24), (a, true));
+
+    let b = u72::from_num_truncated(3);
+    assert_eq!(b.wrapping_shl(72 + 70).as_num(), 3 << 70);
+    assert_eq!(b.overflowing_shr(73), (u72::from_num_truncated(1), true));
+}
+
+#[test]
+pub fn test_ord() {
+    let mut values: Vec<u24> = [0x00_0100, 0x00_00FF, 0x01_0000, 0x00_0001]
+        .into_iter()
+        .map(u24::from_num_truncated)
+        .collect();
+    values.sort();
+    assert_eq!(
+        values.iter().map(|v| v.as_num()).collect::<Vec<_>>(),
+        [0x00_0001, 0x00_00FF, 0x00_0100, 0x01_0000]
+    );
+    assert!(u72::from_num_truncated(0xFF) < u72::from_num_truncated(0x100));
+    assert_eq!(u24::MAX.max(u24::MIN), u24::MAX);
+}
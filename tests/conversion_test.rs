@@ -0,0 +1,109 @@
+//! Conversions into primitives are implemented as From on the target type where it holds every value
+//! and as TryFrom otherwise, so generic code with From bounds compiles. Most of these tests are checked by the compiler.
+
+use uintx::{u24, u40, u48, u72, u96, UnalignedUint};
+
+fn convert<S, T: From<S>>(value: S) -> T {
+    T::from(value)
+}
+
+fn convert_ref<'a, S: 'a, T: From<&'a S>>(value: &'a S) -> T {
+    T::from(value)
+}
+
+fn sum_as<T: From<u24> + core::ops::Add<Output = T> + Default>(values: &[u24]) -> T {
+    values.iter().fold(T::default(), |acc, v| acc + T::from(*v))
+}
+
+fn widen_error(value: u24) -> Result<(), u64> {
+    Err(value)?
+}
+
+#[test]
+pub fn test_from_bounds() {
+    let x = u24::from(0x123456u32);
+    assert_eq!(u32::from(x), 0x123456);
+    assert_eq!(u64::from(x), 0x123456);
+    assert_eq!(u128::from(x), 0x123456);
+    assert_eq!(i32::from(x), 0x123456);
+    assert_eq!(u32::from(&x), 0x123456);
+    assert_eq!(<[u8; 3]>::from(x), x.to_ne_bytes());
+    assert_eq!(<[u8; 3]>::from(&x), x.to_ne_bytes());
+    assert_eq!(<[i8; 3]>::from(x), x.to_ne_bytes().map(|b| b as i8));
+
+    assert_eq!(convert::<u24, u64>(x), 0x123456);
+    assert_eq!(convert_ref::<u24, u128>(&x), 0x123456);
+    assert_eq!(convert::<u40, u64>(u40::MAX), u40::MAX_VALUE);
+    assert_eq!(convert::<u72, u128>(u72::MAX), u72::MAX_VALUE);
+    assert_eq!(sum_as::<u64>(&[u24::MAX, u24::MAX]), 2 * u24::MAX_VALUE as u64);
+    assert_eq!(widen_error(x), Err(0x123456));
+
+    let y = u48::from_ne_bytes([1, 2, 3, 4, 5, 6]);
+    assert_eq!(<[u16; 3]>::from(y), y.as_u16_array());
+    assert_eq!(<[i16; 3]>::from(y), y.as_i16_array());
+    assert_eq!(<[u24; 2]>::from(y), y.as_u24_array());
+    assert_eq!(<[u32; 3]>::from(u96::MAX), [u32::MAX; 3]);
+}
+
+#[test]
+pub fn test_narrowing() {
+    let x = u24::from(0x123456u32);
+    assert!(u8::try_from(x).is_err());
+    assert!(i16::try_from(x).is_err());
+    assert_eq!(u16::try_from(u24::from(0xFFFFu16)), Ok(0xFFFF));
+    assert_eq!(i8::try_from(u24::from(0x7Fu8)), Ok(0x7F));
+
+    assert!(u32::try_from(u40::MAX).is_err());
+    assert_eq!(u32::try_from(u40::from(u32::MAX)), Ok(u32::MAX));
+    assert_eq!(i64::from(u48::MAX), u48::MAX_VALUE as i64);
+    assert!(u64::try_from(u72::MAX).is_err());
+    assert_eq!(u64::try_from(u72::from(u64::MAX)), Ok(u64::MAX));
+    assert_eq!(i128::from(u96::MAX), u96::MAX_VALUE as i128);
+}
+
+#[test]
+pub fn test_into_call_sites() {
+    let x = u24::from(7u8);
+    let a: u32 = x.into();
+    let b: u64 = (&x).into();
+    let c: [u8; 3] = x.into();
+    assert_eq!(a, 7);
+    assert_eq!(b, 7);
+    assert_eq!(u24::from(c), x);
+
+    //Into bounds are satisfied through the blanket impl.
+    fn as_u128<T: UnalignedUint + Into<u128>>(value: T) -> u128 {
+        value.into()
+    }
+    assert_eq!(as_u128(x), 7);
+    assert_eq!(as_u128(u96::MAX), u96::MAX_VALUE);
+}
+
+#[cfg(feature = "half_support")]
+#[test]
+pub fn test_half() {
+    assert_eq!(convert::<u24, half::f16>(u24::from(2u8)), half::f16::from_f32(2.0));
+    assert_eq!(half::f16::from(&u24::from(3u8)), half::f16::from_f32(3.0));
+}
+
+#[cfg(feature = "intx_support")]
+#[test]
+pub fn test_intx() {
+    //Conversions into intx reinterpret the bits, so they are only Into.
+    let unsigned: intx::U24 = u24::MAX.into();
+    let signed: intx::I24 = u24::MAX.into();
+    assert_eq!(unsigned, intx::U24::MAX);
+    assert_eq!(signed, intx::I24::from_ne_bytes([0xFF; 3]));
+}
+
+#[cfg(feature = "ux_support")]
+#[test]
+pub fn test_ux() {
+    //Conversions into ux discard the bits that do not fit, so they are only Into.
+    let unsigned: ux::u24 = u24::MAX.into();
+    let signed: ux::i24 = (&u24::MAX).into();
+    let narrow: ux::u12 = u24::MAX.into();
+    assert_eq!(unsigned, ux::u24::MAX);
+    assert_eq!(signed, ux::i24::new(-1));
+    assert_eq!(narrow, ux::u12::MAX);
+}
@@ -0,0 +1,122 @@
+//! Runs every method of common_fn_impl! against the model on boundary and random inputs.
+//! The input sets are reduced under miri so the same harness runs in both modes.
+
+mod model;
+
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use uintx::{u104, u112, u120, u24, u40, u48, u56, u72, u80, u88, u96};
+
+const RANDOM_INPUTS: usize = if cfg!(miri) { 4 } else { 256 };
+
+macro_rules! differential_test {
+    ($under_test:ident, $helper:ty, $name:ident) => {
+        mod $name {
+            use super::*;
+
+            crate::model::model!($under_test, $helper);
+
+            fn boundary_values() -> Vec<T> {
+                let mut values: Vec<H> = vec![0, 1, 2, M, M - 1, M / 2, M / 2 + 1, 1 << (BITS - 1), 999];
+                if !cfg!(miri) {
+                    for k in 1..BITS {
+                        values.extend([1 << k, (1 << k) - 1, (1 << k) + 1]);
+                    }
+
+                    let mut power_of_ten: H = 10;
+                    while power_of_ten <= M {
+                        values.extend([power_of_ten - 1, power_of_ten]);
+                        power_of_ten = match power_of_ten.checked_mul(10) {
+                            Some(next) => next,
+                            None => break,
+                        };
+                    }
+                }
+
+                values.sort_unstable();
+                values.dedup();
+                values.into_iter().map(t).collect()
+            }
+
+            fn random_values(seed: u64) -> Vec<T> {
+                let mut rng = StdRng::seed_from_u64(seed);
+                let mut values = vec![T::MIN; RANDOM_INPUTS];
+                for value in values.iter_mut() {
+                    let mut bytes = [0u8; BYTES];
+                    rng.fill_bytes(&mut bytes);
+                    *value = T::from_le_bytes(bytes);
+                }
+
+                values
+            }
+
+            fn shift_amounts() -> Vec<u32> {
+                if cfg!(miri) {
+                    return vec![0, 1, BITS - 1, BITS, BITS + 1, BITS * 2];
+                }
+
+                (0..=BITS * 2).collect()
+            }
+
+            #[test]
+            fn unary() {
+                let values: Vec<T> = boundary_values().into_iter().chain(random_values(1)).collect();
+                let exponents: Vec<u32> = if cfg!(miri) { vec![0, 1, 2, 3, 7] } else { (0..=40).collect() };
+                for (idx, a) in values.iter().copied().enumerate() {
+                    check_bytes(a);
+                    check_bits(a);
+                    check_from_str_radix(a, idx as u8);
+                    for s in shift_amounts() {
+                        check_shift(a, s);
+                    }
+                    for e in exponents.iter().copied() {
+                        check_pow(a, e);
+                    }
+                }
+            }
+
+            #[test]
+            fn number_theory() {
+                let mut moduli = vec![T::MIN, t(1), t(2), t(3), T::MAX, t(M - 1), t((1 << (BITS - 1)) + 1)];
+                moduli.extend(random_values(4).into_iter().take(4));
+                let limit = if cfg!(miri) { RANDOM_INPUTS } else { 64 };
+                let values: Vec<T> = boundary_values().into_iter().take(limit).chain(random_values(5)).collect();
+                for (a, b) in values.iter().copied().zip(values.iter().copied().rev()).take(limit * 2) {
+                    for m in moduli.iter().copied() {
+                        check_number_theory(a, b, m);
+                    }
+                }
+            }
+
+            #[test]
+            fn binary() {
+                let boundary = boundary_values();
+                for a in boundary.iter().copied() {
+                    for b in boundary.iter().copied() {
+                        check_binary(a, b);
+                    }
+                }
+
+                for (a, b) in random_values(2).into_iter().zip(random_values(3)) {
+                    check_binary(a, b);
+                    for c in boundary.iter().copied() {
+                        check_binary(a, c);
+                        check_binary(c, b);
+                    }
+                }
+            }
+        }
+    };
+}
+
+differential_test!(u24, u32, differential_u24);
+differential_test!(u40, u64, differential_u40);
+differential_test!(u48, u64, differential_u48);
+differential_test!(u56, u64, differential_u56);
+differential_test!(u72, u128, differential_u72);
+differential_test!(u80, u128, differential_u80);
+differential_test!(u88, u128, differential_u88);
+differential_test!(u96, u128, differential_u96);
+differential_test!(u104, u128, differential_u104);
+differential_test!(u112, u128, differential_u112);
+differential_test!(u120, u128, differential_u120);
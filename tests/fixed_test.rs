@@ -0,0 +1,188 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use uintx::fixed::{Fixed, RoundingMode, UQ16_8, UQ24_16, UQ32_8, UQ8_16};
+use uintx::{u120, u24, u40, u56, u72, UnalignedUint};
+
+#[test]
+pub fn test_float_conversion() {
+    let n = UQ16_8::from_f64(1.5).unwrap();
+    assert_eq!(n.to_bits(), u24::from(0x180u16));
+    assert_eq!(n.to_f64(), 1.5);
+    assert_eq!(n.to_f32(), 1.5);
+    assert_eq!(n.int_part(), 1);
+    assert_eq!(n.frac_part().to_f64(), 0.5);
+
+    assert_eq!(UQ16_8::from_f64(65_535.996_093_75), Some(UQ16_8::MAX));
+    assert_eq!(UQ16_8::from_f64(65536.0), None);
+    assert_eq!(UQ16_8::from_f64(-1.0), None);
+    assert_eq!(UQ16_8::from_f64(f64::NAN), None);
+    assert_eq!(UQ16_8::saturating_from_f64(1e9), UQ16_8::MAX);
+    assert_eq!(UQ16_8::saturating_from_f64(-1e9), UQ16_8::MIN);
+    assert_eq!(UQ16_8::saturating_from_f64(f64::NAN), UQ16_8::MIN);
+
+    let n = UQ32_8::from_f32(123456.25).unwrap();
+    assert_eq!(n.int_part(), 123456);
+    assert_eq!(n.to_f32(), 123456.25);
+
+    //Fixed<u120, 0> can hold integers that are not exactly representable as f64.
+    let n = Fixed::<u120, 0>::from_f64(2f64.powi(100)).unwrap();
+    assert_eq!(n.int_part(), 1u128 << 100);
+}
+
+#[test]
+pub fn test_float_rounding() {
+    //1.25 is exactly between 1.0 and 1.5 with a single fractional bit.
+    let value = 1.25;
+    type Q = Fixed<u24, 1>;
+    assert_eq!(Q::from_f64_rounded(value, RoundingMode::Floor).unwrap().to_f64(), 1.0);
+    assert_eq!(Q::from_f64_rounded(value, RoundingMode::Ceil).unwrap().to_f64(), 1.5);
+    assert_eq!(Q::from_f64_rounded(value, RoundingMode::Nearest).unwrap().to_f64(), 1.5);
+    assert_eq!(Q::from_f64_rounded(value, RoundingMode::NearestEven).unwrap().to_f64(), 1.0);
+    assert_eq!(Q::from_f64_rounded(1.75, RoundingMode::NearestEven).unwrap().to_f64(), 2.0);
+    assert_eq!(Q::from_f64_rounded(1.3, RoundingMode::Nearest).unwrap().to_f64(), 1.5);
+    assert_eq!(Q::from_f32_rounded(1.2, RoundingMode::Nearest).unwrap().to_f64(), 1.0);
+
+    //Rounding up may overflow.
+    assert_eq!(UQ16_8::from_f64_rounded(65535.999, RoundingMode::Floor), Some(UQ16_8::MAX));
+    assert_eq!(UQ16_8::from_f64_rounded(65535.999, RoundingMode::Ceil), None);
+}
+
+#[test]
+pub fn test_add_sub() {
+    let a = UQ16_8::from_f64(1.25).unwrap();
+    let b = UQ16_8::from_f64(2.5).unwrap();
+    assert_eq!((a + b).to_f64(), 3.75);
+    assert_eq!((b - a).to_f64(), 1.25);
+    assert_eq!(a.checked_sub(b), None);
+    assert_eq!(a.saturating_sub(b), UQ16_8::MIN);
+    assert_eq!(UQ16_8::MAX.checked_add(UQ16_8::delta()), None);
+    assert_eq!(UQ16_8::MAX.saturating_add(a), UQ16_8::MAX);
+    assert!(UQ16_8::from_f64(1.0) < UQ16_8::from_f64(256.0));
+}
+
+#[test]
+pub fn test_mul() {
+    let a = UQ16_8::from_f64(1.5).unwrap();
+    let b = UQ16_8::from_f64(2.25).unwrap();
+    assert_eq!((a * b).to_f64(), 3.375);
+
+    //0.00390625 * 0.5 = 0.001953125 is half a delta.
+    let half = UQ16_8::from_f64(0.5).unwrap();
+    let d = UQ16_8::delta();
+    assert_eq!(d.checked_mul_rounded(half, RoundingMode::Floor), Some(UQ16_8::MIN));
+    assert_eq!(d.checked_mul_rounded(half, RoundingMode::Ceil), Some(d));
+    assert_eq!(d.checked_mul_rounded(half, RoundingMode::Nearest), Some(d));
+    assert_eq!(d.checked_mul_rounded(half, RoundingMode::NearestEven), Some(UQ16_8::MIN));
+
+    let big = UQ16_8::checked_from_int(300).unwrap();
+    assert_eq!(big.checked_mul(big), None);
+    assert_eq!(big.saturating_mul(big), UQ16_8::MAX);
+    assert_eq!(UQ16_8::checked_from_int(65536), None);
+
+    //Intermediate products larger than 128 bits.
+    type Q = Fixed<u120, 60>;
+    let a = Q::checked_from_int(1 << 59).unwrap();
+    let b = Q::from_f64(0.5).unwrap();
+    assert_eq!(a.checked_mul(b).unwrap().int_part(), 1 << 58);
+    assert_eq!(a.checked_mul(Q::checked_from_int(2).unwrap()), None);
+    assert_eq!(Q::MAX.checked_mul(Q::checked_from_int(1).unwrap()), Some(Q::MAX));
+}
+
+#[test]
+pub fn test_div() {
+    let a = UQ16_8::from_f64(3.375).unwrap();
+    let b = UQ16_8::from_f64(1.5).unwrap();
+    assert_eq!((a / b).to_f64(), 2.25);
+    assert_eq!(a.checked_div(UQ16_8::MIN), None);
+    assert_eq!(a.saturating_div(UQ16_8::MIN), UQ16_8::MAX);
+    assert_eq!(UQ16_8::MAX.checked_div(UQ16_8::from_f64(0.5).unwrap()), None);
+
+    let one = UQ8_16::checked_from_int(1).unwrap();
+    let three = UQ8_16::checked_from_int(3).unwrap();
+    //1/3 = 0.0101...b, the first discarded bit is 0.
+    let floor = one.checked_div_rounded(three, RoundingMode::Floor).unwrap();
+    assert_eq!(floor.to_bits(), u24::from(0x5555u16));
+    assert_eq!(one.checked_div_rounded(three, RoundingMode::Nearest), Some(floor));
+    assert_eq!(one.checked_div_rounded(three, RoundingMode::Ceil).unwrap().to_bits(), u24::from(0x5556u16));
+    //2/3 = 0.1010...b, the first discarded bit is 1.
+    let two = UQ8_16::checked_from_int(2).unwrap();
+    assert_eq!(two.checked_div_rounded(three, RoundingMode::Nearest).unwrap().to_bits(), u24::from(0xAAABu16));
+
+    //Dividends shifted beyond 128 bits.
+    type Q = Fixed<u120, 100>;
+    let a = Q::checked_from_int(1 << 19).unwrap();
+    let b = Q::checked_from_int(1 << 10).unwrap();
+    assert_eq!(a.checked_div(b).unwrap().int_part(), 1 << 9);
+    let quarter = Q::from_f64(0.25).unwrap();
+    assert_eq!(b.checked_div(quarter).unwrap().int_part(), 1 << 12);
+    assert_eq!(a.checked_div(quarter), None);
+}
+
+#[test]
+pub fn test_narrow_matches_wide() {
+    //Types up to 64 bits use a u128 intermediate, wider types the 256 bit one. Both must agree.
+    type Narrow = Fixed<u56, 28>;
+    type Wide = Fixed<u72, 28>;
+    let widen = |n: Narrow| Wide::from_bits(u72::from_u128_truncated(n.to_bits().as_u128()));
+    let narrow = |n: Wide| Narrow::from_bits(u56::from_u128_truncated(n.to_bits().as_u128()));
+    let fits = |n: Wide| n.to_bits().as_u128() <= u56::MAX.as_u128();
+    let modes = [RoundingMode::Floor, RoundingMode::Ceil, RoundingMode::Nearest, RoundingMode::NearestEven];
+
+    let mut rng = StdRng::seed_from_u64(34);
+    for _ in 0..10_000 {
+        //Random magnitudes so that both small and overflowing results occur.
+        let a = Narrow::from_bits(u56::from_u128_truncated(rng.gen::<u64>() as u128 >> rng.gen_range(0..64)));
+        let b = Narrow::from_bits(u56::from_u128_truncated(rng.gen::<u64>() as u128 >> rng.gen_range(0..64)));
+        for mode in modes {
+            let expected = widen(a).checked_mul_rounded(widen(b), mode).filter(|n| fits(*n)).map(narrow);
+            assert_eq!(a.checked_mul_rounded(b, mode), expected, "{:?} * {:?} {:?}", a, b, mode);
+            let expected = widen(a).checked_div_rounded(widen(b), mode).filter(|n| fits(*n)).map(narrow);
+            assert_eq!(a.checked_div_rounded(b, mode), expected, "{:?} / {:?} {:?}", a, b, mode);
+        }
+    }
+
+    //The whole integer part of the u128 intermediate is used by FRAC == NUM_BITS.
+    type Frac = Fixed<u40, 40>;
+    let max = Frac::MAX;
+    assert_eq!(max.checked_mul(max).unwrap().to_bits(), u40::MAX.wrapping_sub(u40::from(1u8)));
+    assert_eq!(max.checked_mul_rounded(max, RoundingMode::Ceil), Some(max));
+    assert_eq!(max.checked_div(max), None);
+}
+
+#[test]
+pub fn test_matches_f64() {
+    //Every operation on Q24.16 values with few significant bits is exact in f64.
+    let values = [0.0, 0.5, 1.0, 1.25, 3.0, 7.75, 100.125, 1024.0, 65535.5];
+    for a in values {
+        for b in values {
+            let x = UQ24_16::from_f64(a).unwrap();
+            let y = UQ24_16::from_f64(b).unwrap();
+            assert_eq!(x.checked_add(y).map(|n| n.to_f64()), Some(a + b));
+            assert_eq!(x.checked_mul(y).map(|n| n.to_f64()), UQ24_16::from_f64(a * b).map(|n| n.to_f64()));
+            if b != 0.0 {
+                let q = x.checked_div_rounded(y, RoundingMode::Nearest).map(|n| n.to_f64());
+                assert_eq!(q, UQ24_16::from_f64(a / b).map(|n| n.to_f64()), "{} / {}", a, b);
+            }
+        }
+    }
+}
+
+#[test]
+pub fn test_display() {
+    assert_eq!(UQ16_8::from_f64(1.5).unwrap().to_string(), "1.5");
+    assert_eq!(UQ16_8::MIN.to_string(), "0");
+    assert_eq!(UQ16_8::checked_from_int(42).unwrap().to_string(), "42");
+    assert_eq!(UQ16_8::delta().to_string(), "0.00390625");
+    assert_eq!(UQ16_8::MAX.to_string(), "65535.99609375");
+    assert_eq!(format!("{:?}", UQ16_8::MAX), "65535.99609375");
+
+    assert_eq!(format!("{:.2}", UQ16_8::from_f64(1.5).unwrap()), "1.50");
+    assert_eq!(format!("{:.0}", UQ16_8::from_f64(1.5).unwrap()), "2");
+    assert_eq!(format!("{:.3}", UQ16_8::delta()), "0.004");
+    assert_eq!(format!("{:.2}", UQ16_8::MAX), "65536.00");
+    assert_eq!(format!("{:>8.1}", UQ16_8::from_f64(2.25).unwrap()), "     2.3");
+    assert_eq!(format!("{:08.2}", UQ16_8::from_f64(2.25).unwrap()), "00002.25");
+
+    assert_eq!(Fixed::<u40, 40>::MAX.to_string().len(), "0.".len() + 40);
+    assert_eq!(Fixed::<u120, 0>::MAX.to_string(), u120::MAX.to_string());
+}
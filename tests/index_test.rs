@@ -0,0 +1,59 @@
+use uintx::index::{UintxIndex, UintxSliceExt};
+use uintx::{u24, u40, u48, u72, u120};
+
+#[test]
+pub fn test_usize_conversions() {
+    assert_eq!(u24::try_from(0xFF_FFFFusize), Ok(u24::MAX));
+    assert!(u24::try_from(0x100_0000usize).is_err());
+    assert_eq!(u24::try_from(5isize), Ok(u24::from(5u8)));
+    assert!(u24::try_from(-1isize).is_err());
+    assert_eq!(u72::try_from(usize::MAX).map(|v| v.as_num()), Ok(usize::MAX as u128));
+    assert!(u120::try_from(isize::MIN).is_err());
+
+    assert_eq!(usize::from(u24::MAX), 0xFF_FFFF);
+    assert_eq!(isize::from(&u24::MAX), 0xFF_FFFF);
+    assert_eq!(u24::MAX.as_usize(), 0xFF_FFFF);
+    assert!(usize::try_from(u72::MAX).is_err());
+    assert_eq!(usize::try_from(u72::from(9u8)), Ok(9));
+    assert!(isize::try_from(u120::from(u64::MAX)).is_err());
+
+    #[cfg(target_pointer_width = "64")]
+    {
+        assert_eq!(usize::from(u48::MAX), 0xFFFF_FFFF_FFFF);
+        assert_eq!(isize::from(u40::MAX), 0xFF_FFFF_FFFF);
+    }
+
+    #[cfg(not(target_pointer_width = "64"))]
+    assert!(usize::try_from(u48::MAX).is_err());
+}
+
+#[test]
+pub fn test_get_by() {
+    let mut table: Vec<u16> = (0..300).collect();
+    assert_eq!(table.get_by(u24::from(299u16)), Some(&299));
+    assert_eq!(table.get_by(u24::from(300u16)), None);
+    assert_eq!(table.get_by(u120::MAX), None);
+    *table.get_by_mut(u40::from(7u8)).unwrap() = 1000;
+    assert_eq!(table[7], 1000);
+    assert_eq!(table.get_by_mut(u72::MAX), None);
+}
+
+#[test]
+pub fn test_index() {
+    let mut array = [1, 2, 3];
+    array[UintxIndex(u24::from(1u8))] += 10;
+    assert_eq!(array[UintxIndex::from(u24::from(1u8))], 12);
+
+    let vec = vec!['a', 'b'];
+    #[cfg(feature = "alloc")]
+    assert_eq!(vec[UintxIndex(u48::from(0u8))], 'a');
+    let slice: &[char] = &vec;
+    assert_eq!(slice[UintxIndex(u120::from(1u8))], 'b');
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 3 but the index is 16777215")]
+pub fn test_index_out_of_bounds() {
+    let array = [1, 2, 3];
+    let _ = array[UintxIndex(u24::MAX)];
+}
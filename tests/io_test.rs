@@ -0,0 +1,61 @@
+#![cfg(feature = "std")]
+
+use std::io::{Cursor, ErrorKind};
+use uintx::io::{ReadUintxExt, WriteUintxExt};
+use uintx::{u120, u24, u40, u48};
+
+#[test]
+pub fn test_read_le_be() {
+    let mut cursor = Cursor::new(vec![0x56u8, 0x34, 0x12, 0x12, 0x34, 0x56, 0x11, 0x22, 0x33, 0x44, 0x55]);
+    assert_eq!(cursor.read_u24_le().unwrap(), 0x123456);
+    assert_eq!(cursor.read_u24_be().unwrap(), 0x123456);
+    assert_eq!(cursor.read_u40_be().unwrap(), 0x1122334455u64);
+    assert_eq!(cursor.read_u24_le().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+pub fn test_write_le_be() {
+    let mut buf = Vec::new();
+    buf.write_u24_le(u24::from(0x123456)).unwrap();
+    buf.write_u24_be(u24::from(0x123456)).unwrap();
+    buf.write_u48_le(u48::from(0x112233445566u64)).unwrap();
+    assert_eq!(buf, vec![0x56, 0x34, 0x12, 0x12, 0x34, 0x56, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11]);
+
+    let mut cursor = Cursor::new(buf);
+    assert_eq!(cursor.read_uintx_le::<u24>().unwrap(), 0x123456);
+    assert_eq!(cursor.read_uintx_be::<u24>().unwrap(), 0x123456);
+    assert_eq!(cursor.read_u48_le().unwrap(), 0x112233445566u64);
+}
+
+#[test]
+pub fn test_read_into() {
+    let values: Vec<u40> = (0..100u64).map(|n| u40::from(n * 0x0102030405)).collect();
+    let mut le = Vec::new();
+    le.write_uintx_le_from(&values).unwrap();
+    let mut be = Vec::new();
+    be.write_uintx_be_from(&values).unwrap();
+    assert_eq!(le.len(), 500);
+    assert_eq!(&le[5..10], &[0x05, 0x04, 0x03, 0x02, 0x01]);
+    assert_eq!(&be[5..10], &[0x01, 0x02, 0x03, 0x04, 0x05]);
+
+    let mut out = vec![u40::MIN; 100];
+    Cursor::new(&le).read_u40_le_into(&mut out).unwrap();
+    assert_eq!(out, values);
+
+    let mut out = vec![u40::MIN; 100];
+    Cursor::new(&be).read_u40_be_into(&mut out).unwrap();
+    assert_eq!(out, values);
+
+    let mut out = vec![u40::MIN; 101];
+    let err = Cursor::new(&be).read_u40_be_into(&mut out).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+pub fn test_wide() {
+    let mut buf = Vec::new();
+    buf.write_u120_be(u120::MAX - 1).unwrap();
+    assert_eq!(buf.len(), 15);
+    assert_eq!(buf[14], 0xFE);
+    assert_eq!(Cursor::new(buf).read_u120_be().unwrap(), u120::MAX - 1);
+}
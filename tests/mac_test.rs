@@ -0,0 +1,79 @@
+use uintx::mac::{MacAddr, MacAddrFormat, ParseMacAddrError};
+use uintx::{u24, u48};
+
+#[test]
+pub fn test_parse() {
+    let expected = MacAddr::new([0xAA, 0xBB, 0xCC, 0x0D, 0xEE, 0xFF]);
+    for s in ["aa:bb:cc:0d:ee:ff", "AA-BB-CC-0D-EE-FF", "aabb.cc0d.eeff", "AABBCC0DEEFF", "aA:Bb:cC:0d:eE:Ff"] {
+        assert_eq!(s.parse::<MacAddr>(), Ok(expected), "{}", s);
+    }
+
+    assert_eq!(u48::from(expected).as_num(), 0xAABBCC0DEEFF);
+
+    for s in ["", "aa:bb:cc:dd:ee", "aa:bb:cc:dd:ee:ff:00", "aa:bb-cc:dd:ee:ff", "aa.bb.cc.dd.ee.ff", "aabb:ccdd:eeff"] {
+        assert_eq!(s.parse::<MacAddr>(), Err(ParseMacAddrError::InvalidFormat), "{}", s);
+    }
+
+    assert_eq!("aa:bb:cc:dd:ee:fg".parse::<MacAddr>(), Err(ParseMacAddrError::InvalidDigit { position: 16 }));
+    assert_eq!("+abbccddeeff".parse::<MacAddr>(), Err(ParseMacAddrError::InvalidDigit { position: 0 }));
+    assert_eq!("aabb.ccdd.ee f".parse::<MacAddr>(), Err(ParseMacAddrError::InvalidDigit { position: 12 }));
+}
+
+#[test]
+pub fn test_format() {
+    let mac = MacAddr::new([0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+    assert_eq!(mac.to_string(), "00:1a:2b:3c:4d:5e");
+    assert_eq!(format!("{:?}", mac), "MacAddr(00:1a:2b:3c:4d:5e)");
+    assert_eq!(mac.display_as(MacAddrFormat::Hyphen).to_string(), "00-1A-2B-3C-4D-5E");
+    assert_eq!(mac.display_as(MacAddrFormat::Dot).to_string(), "001a.2b3c.4d5e");
+    assert_eq!(mac.display_as(MacAddrFormat::Plain).to_string(), "001a2b3c4d5e");
+    assert_eq!(format!("[{:>19}]", mac), "[  00:1a:2b:3c:4d:5e]");
+
+    for format in [MacAddrFormat::Colon, MacAddrFormat::Hyphen, MacAddrFormat::Dot, MacAddrFormat::Plain] {
+        assert_eq!(mac.display_as(format).to_string().parse(), Ok(mac));
+    }
+}
+
+#[test]
+pub fn test_bits() {
+    let mac = MacAddr::new([0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+    assert_eq!(mac.oui(), u24::from(0x001A2Bu32));
+    assert_eq!(mac.nic(), u24::from(0x3C4D5Eu32));
+    assert_eq!(MacAddr::from_oui_nic(mac.oui(), mac.nic()), mac);
+    assert!(mac.is_unicast() && mac.is_universal() && !mac.is_broadcast());
+
+    let multicast: MacAddr = "01:00:5e:00:00:fb".parse().unwrap();
+    assert!(multicast.is_multicast() && multicast.is_universal());
+    let local: MacAddr = "02:00:00:00:00:01".parse().unwrap();
+    assert!(local.is_local() && local.is_unicast());
+    assert!(MacAddr::BROADCAST.is_broadcast() && MacAddr::BROADCAST.is_multicast());
+    assert_eq!(MacAddr::default(), MacAddr::ZERO);
+
+    assert!(MacAddr::new([0, 0, 0, 0, 0, 1]) < MacAddr::new([0, 0, 0, 0, 1, 0]));
+    assert!(MacAddr::new([1, 0, 0, 0, 0, 0]) > MacAddr::new([0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]));
+    assert_eq!(<[u8; 6]>::from(mac), [0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+    assert_eq!(MacAddr::from(u48::from(mac)), mac);
+}
+
+#[test]
+pub fn test_eui64() {
+    let mac: MacAddr = "00:1a:2b:3c:4d:5e".parse().unwrap();
+    assert_eq!(mac.to_eui64(), 0x001A_2BFF_FE3C_4D5E);
+    assert_eq!(mac.to_modified_eui64(), 0x021A_2BFF_FE3C_4D5E);
+    assert_eq!(MacAddr::from_eui64(mac.to_eui64()), Some(mac));
+    assert_eq!(MacAddr::from_modified_eui64(mac.to_modified_eui64()), Some(mac));
+    assert_eq!(MacAddr::from_eui64(0x001A_2B00_003C_4D5E), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+pub fn test_serde() {
+    let mac: MacAddr = "00:1a:2b:3c:4d:5e".parse().unwrap();
+    assert_eq!(serde_json::to_string(&mac).unwrap(), "\"00:1a:2b:3c:4d:5e\"");
+    assert_eq!(serde_json::from_str::<MacAddr>("\"00-1A-2B-3C-4D-5E\"").unwrap(), mac);
+    assert!(serde_json::from_str::<MacAddr>("\"00:1a\"").is_err());
+
+    let bytes = postcard::to_allocvec(&mac).unwrap();
+    assert_eq!(bytes, [6, 0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+    assert_eq!(postcard::from_bytes::<MacAddr>(&bytes).unwrap(), mac);
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "mmap")]
+#![cfg(not(miri))]
+
+use std::io;
+use std::path::PathBuf;
+use uintx::mmap::{EndianView, Endianness, MappedArray, MappedArrayMut};
+use uintx::{u24, u40, UnalignedUint};
+
+///
+/// A file in the temp directory that is removed when dropped.
+///
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str, contents: &[u8]) -> Self {
+        let path = std::env::temp_dir().join(format!("uintx_mmap_test_{}_{}", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        TempFile(path)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn values() -> Vec<u40> {
+    (0..1000u64).map(|i| u40::from_num_truncated(i * 0x1_0203_0405)).collect()
+}
+
+#[test]
+pub fn test_native() {
+    let values = values();
+    let file = TempFile::new("native", u40::slice_as_bytes(&values));
+    let mapped = unsafe { MappedArray::<u40>::open(&file.0) }.unwrap();
+    assert_eq!(&mapped[..], &values[..]);
+    assert_eq!(mapped.len(), 1000);
+    assert!(mapped.header().is_empty());
+    assert_eq!(mapped.view(Endianness::NATIVE).as_native(), Some(&values[..]));
+}
+
+#[test]
+pub fn test_header_and_endianness() {
+    let values = values();
+    for endianness in [Endianness::Little, Endianness::Big] {
+        let mut contents = b"IDX1".to_vec();
+        for v in &values {
+            match endianness {
+                Endianness::Little => contents.extend_from_slice(&v.to_le_bytes()),
+                Endianness::Big => contents.extend_from_slice(&v.to_be_bytes()),
+            }
+        }
+        let file = TempFile::new(&format!("{:?}", endianness), &contents);
+
+        let mapped = unsafe { MappedArray::<u40>::open_with_header(&file.0, 4) }.unwrap();
+        assert_eq!(mapped.header(), b"IDX1");
+        let view = mapped.view(endianness);
+        assert_eq!(view.len(), values.len());
+        assert_eq!(view.iter().collect::<Vec<_>>(), values);
+        assert_eq!(view.get(999), Some(values[999]));
+        assert_eq!(view.get(1000), None);
+        assert_eq!(view.as_native().is_some(), endianness == Endianness::NATIVE);
+    }
+}
+
+#[test]
+pub fn test_invalid_length() {
+    let file = TempFile::new("invalid", &[0; 11]);
+    let err = unsafe { MappedArray::<u40>::open(&file.0) }.err().unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert!(unsafe { MappedArray::<u40>::open_with_header(&file.0, 1) }.is_ok());
+    assert!(unsafe { MappedArray::<u40>::open_with_header(&file.0, 12) }.is_err());
+    assert!(unsafe { MappedArrayMut::<u24>::open_with_header(&file.0, 1) }.is_err());
+    assert_eq!(unsafe { MappedArray::<u24>::open_with_header(&file.0, 2) }.unwrap().len(), 3);
+
+    let empty = TempFile::new("empty", &[]);
+    assert!(unsafe { MappedArray::<u40>::open(&empty.0) }.unwrap().is_empty());
+    assert!(unsafe { MappedArray::<u40>::open("/nonexistent/uintx/file") }.is_err());
+}
+
+#[test]
+pub fn test_mutable() {
+    let file = TempFile::new("mutable", &[0; 2 + 3 * 4]);
+    {
+        let mut mapped = unsafe { MappedArrayMut::<u24>::open_with_header(&file.0, 2) }.unwrap();
+        mapped.header_mut().copy_from_slice(b"V2");
+        mapped[0] = u24::from(0x010203u32);
+        let mut view = mapped.view_mut(Endianness::Big);
+        view.set(1, u24::from(0x040506u32));
+        assert_eq!(view.get(1), Some(u24::from(0x040506u32)));
+        assert_eq!(view.as_view().len(), 4);
+        assert_eq!(view.as_native_mut().is_some(), Endianness::NATIVE == Endianness::Big);
+        mapped.view_mut(Endianness::Little).set(3, u24::MAX - u24::from(1u8));
+        mapped.flush().unwrap();
+
+        let read_only = mapped.make_read_only().unwrap();
+        assert_eq!(read_only.header(), b"V2");
+    }
+
+    let contents = std::fs::read(&file.0).unwrap();
+    assert_eq!(&contents[..2], b"V2");
+    assert_eq!(contents[2..5], u24::from(0x010203u32).to_ne_bytes());
+    assert_eq!(contents[5..8], [4, 5, 6]);
+    assert_eq!(contents[8..11], [0, 0, 0]);
+    assert_eq!(contents[11..14], [0xFE, 0xFF, 0xFF]);
+
+    let slice = [u24::from_ne_bytes([0, 0, 1])];
+    assert_eq!(EndianView::new(&slice, Endianness::Big).get(0), Some(u24::from(1u8)));
+}
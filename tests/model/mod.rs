@@ -0,0 +1,260 @@
+//! Independent model of the common_fn_impl! methods shared by the reference and differential tests.
+//!
+//! Every check compares a method of the type under test against the next largest aligned type
+//! masked to the width of the type under test.
+
+macro_rules! model {
+    ($under_test:ident, $helper:ty) => {
+        #[allow(unused_imports)]
+        use uintx::UnalignedUint;
+
+        type T = $under_test;
+        type H = $helper;
+
+        const M: H = T::MAX_VALUE;
+        const BITS: u32 = T::NUM_BITS as u32;
+        const MISSING: u32 = T::NUM_BITS_MISSING_FOR_ALIGNMENT as u32;
+        const BYTES: usize = T::NUM_BITS / 8;
+
+        fn t(n: H) -> T {
+            assert!(n <= M);
+            T::from_u128_truncated(n as u128)
+        }
+
+        fn fit(n: Option<H>) -> Option<H> {
+            n.filter(|v| *v <= M)
+        }
+
+        pub fn check_bytes(a: T) {
+            let x = a.as_num();
+            assert_eq!(&a.to_le_bytes()[..], &x.to_le_bytes()[..BYTES]);
+            assert_eq!(&a.to_be_bytes()[..], &x.to_be_bytes()[(MISSING / 8) as usize..]);
+            assert_eq!(a.to_ne_bytes(), *a.inner_ref());
+            assert_eq!(T::from_le_bytes(a.to_le_bytes()), a);
+            assert_eq!(T::from_be_bytes(a.to_be_bytes()), a);
+            assert_eq!(T::from_ne_bytes(a.to_ne_bytes()), a);
+            assert_eq!(a.swap_bytes().as_num(), x.swap_bytes() >> MISSING);
+            assert_eq!(a.to_le().to_ne_bytes(), a.to_le_bytes());
+            assert_eq!(a.to_be().to_ne_bytes(), a.to_be_bytes());
+            assert_eq!(T::from_le(a.to_le()), a);
+            assert_eq!(T::from_be(a.to_be()), a);
+
+            let mut b = a;
+            b.inner_ref_mut().reverse();
+            assert_eq!(b, a.swap_bytes());
+        }
+
+        pub fn check_bits(a: T) {
+            let x = a.as_num();
+            assert_eq!(a.count_ones(), x.count_ones());
+            assert_eq!(a.count_zeros(), BITS - x.count_ones());
+            assert_eq!(a.leading_zeros(), x.leading_zeros() - MISSING);
+            assert_eq!(a.trailing_zeros(), x.trailing_zeros().min(BITS));
+            assert_eq!(a.leading_ones(), (x << MISSING).leading_ones());
+            assert_eq!(a.trailing_ones(), x.trailing_ones());
+            assert_eq!(a.reverse_bits().as_num(), x.reverse_bits() >> MISSING);
+            assert_eq!(a.is_power_of_two(), x.is_power_of_two());
+
+            assert_eq!(a.checked_ilog2(), x.checked_ilog2());
+            assert_eq!(a.checked_ilog10(), x.checked_ilog10());
+            if x != 0 {
+                assert_eq!(a.ilog2(), x.ilog2());
+                assert_eq!(a.ilog10(), x.ilog10());
+            }
+
+            let next = fit(x.checked_next_power_of_two());
+            assert_eq!(a.checked_next_power_of_two().map(T::as_num), next);
+            if let Some(next) = next {
+                assert_eq!(a.next_power_of_two().as_num(), next);
+            }
+
+            assert_eq!(a.wrapping_neg().as_num(), x.wrapping_neg() & M);
+            assert_eq!(a.overflowing_neg(), (a.wrapping_neg(), x != 0));
+            assert_eq!(a.checked_neg().map(T::as_num), (x == 0).then_some(0));
+        }
+
+        pub fn check_shift(a: T, s: u32) {
+            let x = a.as_num();
+            if s <= BITS {
+                assert_eq!(a.shl(s).as_num(), (x << s) & M);
+                assert_eq!(a.shr(s).as_num(), x >> s);
+            }
+
+            let wrapped = s % BITS;
+            assert_eq!(a.wrapping_shl(s).as_num(), (x << wrapped) & M);
+            assert_eq!(a.wrapping_shr(s).as_num(), x >> wrapped);
+            assert_eq!(a.overflowing_shl(s), (a.wrapping_shl(s), s >= BITS));
+            assert_eq!(a.overflowing_shr(s), (a.wrapping_shr(s), s >= BITS));
+            assert_eq!(a.checked_shl(s).map(T::as_num), (s < BITS).then(|| (x << s) & M));
+            assert_eq!(a.checked_shr(s).map(T::as_num), (s < BITS).then(|| x >> s));
+            assert_eq!(a.rotate_left(s).as_num(), ((x << wrapped) | (x >> (BITS - wrapped))) & M);
+            assert_eq!(a.rotate_right(s).as_num(), ((x >> wrapped) | (x << (BITS - wrapped))) & M);
+        }
+
+        pub fn check_pow(a: T, e: u32) {
+            let x = a.as_num();
+            let power = fit(x.checked_pow(e));
+            assert_eq!(a.checked_pow(e).map(T::as_num), power);
+            assert_eq!(a.wrapping_pow(e).as_num(), x.wrapping_pow(e) & M);
+            assert_eq!(a.saturating_pow(e).as_num(), power.unwrap_or(M));
+            if let Some(power) = power {
+                assert_eq!(a.pow(e).as_num(), power);
+            }
+        }
+
+        pub fn check_binary(a: T, b: T) {
+            let (x, y) = (a.as_num(), b.as_num());
+            assert_eq!(T::eq(&a, &b), x == y);
+            assert_eq!(T::gt(&a, &b), x > y);
+            assert_eq!(T::ge(&a, &b), x >= y);
+            assert_eq!(T::lt(&a, &b), x < y);
+            assert_eq!(T::le(&a, &b), x <= y);
+            assert_eq!(a.cmp(&b), x.cmp(&y));
+            assert_eq!(a.partial_cmp(&b), Some(x.cmp(&y)));
+            assert_eq!(a < b, x < y);
+            assert_eq!(a.max(b).as_num(), x.max(y));
+
+            let sum = fit(x.checked_add(y));
+            assert_eq!(a.checked_add(b).map(T::as_num), sum);
+            assert_eq!(a.wrapping_add(b).as_num(), x.wrapping_add(y) & M);
+            assert_eq!(a.overflowing_add(b), (a.wrapping_add(b), sum.is_none()));
+            assert_eq!(a.saturating_add(b).as_num(), sum.unwrap_or(M));
+            if let Some(sum) = sum {
+                assert_eq!(a.add(b).as_num(), sum);
+            }
+
+            let diff = x.checked_sub(y);
+            assert_eq!(a.checked_sub(b).map(T::as_num), diff);
+            assert_eq!(a.wrapping_sub(b).as_num(), x.wrapping_sub(y) & M);
+            assert_eq!(a.overflowing_sub(b), (a.wrapping_sub(b), diff.is_none()));
+            assert_eq!(a.saturating_sub(b).as_num(), x.saturating_sub(y));
+            assert_eq!(a.abs_diff(b).as_num(), x.abs_diff(y));
+            if let Some(diff) = diff {
+                assert_eq!(a.sub(b).as_num(), diff);
+            }
+
+            let product = fit(x.checked_mul(y));
+            assert_eq!(a.checked_mul(b).map(T::as_num), product);
+            assert_eq!(a.wrapping_mul(b).as_num(), x.wrapping_mul(y) & M);
+            assert_eq!(a.overflowing_mul(b), (a.wrapping_mul(b), product.is_none()));
+            assert_eq!(a.saturating_mul(b).as_num(), product.unwrap_or(M));
+            if let Some(product) = product {
+                assert_eq!(a.mul(b).as_num(), product);
+            }
+
+            assert_eq!(a.checked_ilog(b), x.checked_ilog(y));
+            if x != 0 && y >= 2 {
+                assert_eq!(a.ilog(b), x.ilog(y));
+            }
+
+            assert_eq!(a.checked_div(b).map(T::as_num), x.checked_div(y));
+            assert_eq!(a.checked_rem(b).map(T::as_num), x.checked_rem(y));
+            assert_eq!(a.checked_div_euclid(b).map(T::as_num), x.checked_div_euclid(y));
+            assert_eq!(a.checked_rem_euclid(b).map(T::as_num), x.checked_rem_euclid(y));
+            assert_eq!(a.checked_next_multiple_of(b).map(T::as_num), fit(x.checked_next_multiple_of(y)));
+            if y == 0 {
+                assert_eq!(a.saturating_div(b), T::MAX);
+                return;
+            }
+
+            assert_eq!(a.div(b).as_num(), x / y);
+            assert_eq!(a.rem(b).as_num(), x % y);
+            assert_eq!(a.div_euclid(b).as_num(), x / y);
+            assert_eq!(a.rem_euclid(b).as_num(), x % y);
+            assert_eq!(a.div_ceil(b).as_num(), x.div_ceil(y));
+            assert_eq!(a.saturating_div(b).as_num(), x / y);
+            assert_eq!(a.wrapping_div(b).as_num(), x / y);
+            assert_eq!(a.wrapping_rem(b).as_num(), x % y);
+            assert_eq!(a.wrapping_div_euclid(b).as_num(), x / y);
+            assert_eq!(a.wrapping_rem_euclid(b).as_num(), x % y);
+            assert_eq!(a.overflowing_div(b), (t(x / y), false));
+            assert_eq!(a.overflowing_rem(b), (t(x % y), false));
+            assert_eq!(a.overflowing_div_euclid(b), (t(x / y), false));
+            assert_eq!(a.overflowing_rem_euclid(b), (t(x % y), false));
+            if let Some(multiple) = fit(x.checked_next_multiple_of(y)) {
+                assert_eq!(a.next_multiple_of(b).as_num(), multiple);
+            }
+        }
+
+        fn model_gcd(mut x: u128, mut y: u128) -> u128 {
+            while y != 0 {
+                let r = x % y;
+                x = y;
+                y = r;
+            }
+
+            x
+        }
+
+        fn model_mul_mod(x: u128, mut y: u128, m: u128) -> u128 {
+            //Double and add, x and r stay below 2^NUM_BITS so doubling cannot overflow u128.
+            let mut x = x % m;
+            let mut r = 0;
+            while y != 0 {
+                if y & 1 == 1 {
+                    r = (r + x) % m;
+                }
+                x = (x * 2) % m;
+                y >>= 1;
+            }
+
+            r
+        }
+
+        fn model_pow_mod(x: u128, mut e: u128, m: u128) -> u128 {
+            let mut base = x % m;
+            let mut r = 1 % m;
+            while e != 0 {
+                if e & 1 == 1 {
+                    r = model_mul_mod(r, base, m);
+                }
+                base = model_mul_mod(base, base, m);
+                e >>= 1;
+            }
+
+            r
+        }
+
+        pub fn check_number_theory(a: T, b: T, m: T) {
+            let (x, y, z) = (a.as_u128(), b.as_u128(), m.as_u128());
+            assert_eq!(a.isqrt().as_u128(), x.isqrt());
+            assert_eq!(a.checked_isqrt(), Some(a.isqrt()));
+
+            let gcd = model_gcd(x, y);
+            assert_eq!(a.gcd(b).as_u128(), gcd);
+            let lcm = if gcd == 0 { Some(0) } else { (x / gcd).checked_mul(y).filter(|v| *v <= M as u128) };
+            assert_eq!(a.checked_lcm(b).map(T::as_u128), lcm);
+            if let Some(lcm) = lcm {
+                assert_eq!(a.lcm(b).as_u128(), lcm);
+            }
+
+            if z == 0 {
+                assert_eq!(a.inv_mod(m), None);
+                return;
+            }
+
+            assert_eq!(a.mul_mod(b, m).as_u128(), model_mul_mod(x, y, z));
+            assert_eq!(a.pow_mod(b, m).as_u128(), model_pow_mod(x, y, z));
+            match a.inv_mod(m) {
+                Some(inv) => {
+                    assert!(inv.as_u128() < z);
+                    assert_eq!(model_mul_mod(x, inv.as_u128(), z), 1 % z);
+                }
+                None => assert_ne!(model_gcd(x % z, z), 1),
+            }
+        }
+
+        pub fn check_from_str_radix(a: T, over: u8) {
+            let x = a.as_num();
+            assert_eq!(T::from_str_radix(&format!("{}", x), 10).unwrap(), a);
+            assert_eq!(T::from_str_radix(&format!("{:x}", x), 16).unwrap(), a);
+            assert_eq!(T::from_str_radix(&format!("{:b}", x), 2).unwrap(), a);
+
+            let too_large = (M as u128 + over.max(1) as u128).to_string();
+            assert!(T::from_str_radix(&too_large, 10).is_err());
+            assert!(T::from_str_radix("", 10).is_err());
+        }
+    };
+}
+
+pub(crate) use model;
@@ -0,0 +1,33 @@
+#![cfg(feature = "num_integer_support")]
+
+use num_integer::{Integer, Roots};
+use uintx::{u120, u24, u48, u72};
+
+#[test]
+pub fn test_integer() {
+    let a = u24::from(84u8);
+    let b = u24::from(36u8);
+    assert_eq!(Integer::gcd(&a, &b), 12u32);
+    assert_eq!(Integer::lcm(&a, &b), 252u32);
+    assert_eq!(a.div_rem(&b), (u24::from(2u8), u24::from(12u8)));
+    assert_eq!(a.div_floor(&b), 2u32);
+    assert_eq!(a.mod_floor(&b), 12u32);
+    assert!(a.is_even());
+    assert!(!a.is_odd());
+    assert!(Integer::is_multiple_of(&a, &u24::from(7u8)));
+    assert!(!Integer::is_multiple_of(&a, &u24::MIN));
+    assert!(Integer::is_multiple_of(&u24::MIN, &u24::MIN));
+    assert_eq!(Integer::next_multiple_of(&a, &b), 108u32);
+    assert_eq!(Integer::prev_multiple_of(&a, &b), 72u32);
+    assert_eq!(Integer::gcd(&u72::MAX, &u72::from(3u8)), 3u128);
+}
+
+#[test]
+pub fn test_roots() {
+    assert_eq!(u48::MAX.sqrt(), 0xFFFFFFu64);
+    assert_eq!(u24::MAX.cbrt(), 255u32);
+    let root = u120::MAX.nth_root(5).as_num();
+    assert!(root.pow(5) <= u120::MAX.as_num() && (root + 1).pow(5) > u120::MAX.as_num());
+    assert_eq!(u120::MAX.sqrt(), u120::MAX.isqrt());
+    assert_eq!(u72::from(1000u32).nth_root(3), 10u128);
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "alloc")]
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use uintx::index::UintxSliceExt;
+use uintx::packed_vec::PackedVec;
+use uintx::{u120, u24, u40, u56, u72, UnalignedUint};
+
+fn check_padding<T: UnalignedUint>(vec: &PackedVec<T>) {
+    if !vec.is_padded() {
+        return;
+    }
+
+    //Every element must be followed by enough readable bytes to fetch the helper type.
+    let pad = size_of::<T::Helper>() - T::BYTES;
+    assert_eq!(vec.padding(), pad);
+    let all = unsafe { core::slice::from_raw_parts(vec.as_ptr().cast::<u8>(), vec.as_bytes().len() + pad) };
+    assert!(all[vec.as_bytes().len()..].iter().all(|b| *b == 0));
+}
+
+fn check_random<T: UnalignedUint>(padded: bool) {
+    let mut rng = StdRng::seed_from_u64(T::BYTES as u64);
+    let mut vec = if padded { PackedVec::<T>::new_padded() } else { PackedVec::<T>::new() };
+    let mut expected = Vec::new();
+    for _ in 0..200 {
+        let n = T::from_u128_truncated(rng.gen());
+        match rng.gen_range(0..6) {
+            0 => {
+                assert_eq!(vec.pop(), expected.pop());
+            }
+            1 => {
+                vec.extend_from_slice(&[n, n]);
+                expected.extend_from_slice(&[n, n]);
+            }
+            2 => {
+                vec.try_push_num(n.as_num()).unwrap();
+                expected.push(n);
+            }
+            3 => {
+                let len = rng.gen_range(0..=expected.len());
+                vec.truncate(len);
+                expected.truncate(len);
+            }
+            _ => {
+                vec.push(n);
+                expected.push(n);
+            }
+        }
+        assert_eq!(vec.as_slice(), expected.as_slice());
+        assert_eq!(vec.len(), expected.len());
+        check_padding(&vec);
+    }
+
+    vec.sort();
+    expected.sort_by_key(|v| v.as_num());
+    assert_eq!(vec.as_slice(), expected.as_slice());
+    for v in &expected {
+        assert_eq!(vec[vec.binary_search_num(v.as_num()).unwrap()], *v);
+    }
+    assert_eq!(vec.iter_nums().collect::<Vec<_>>(), expected.iter().map(|v| v.as_num()).collect::<Vec<_>>());
+    assert_eq!(vec.into_iter().rev().collect::<Vec<_>>(), expected.iter().rev().map(|v| v.as_num()).collect::<Vec<_>>());
+}
+
+#[test]
+pub fn test_random() {
+    for padded in [false, true] {
+        check_random::<u24>(padded);
+        check_random::<u40>(padded);
+        check_random::<u56>(padded);
+        check_random::<u72>(padded);
+        check_random::<u120>(padded);
+    }
+}
+
+#[test]
+pub fn test_checked_narrowing() {
+    let mut vec = PackedVec::<u24>::new();
+    vec.try_extend_from_nums(&[1u32, 2, 3]).unwrap();
+    //Nothing is appended if any value is too large.
+    assert!(vec.try_extend_from_nums(&[4u32, 0x100_0000]).is_err());
+    assert!(vec.try_push_num(u64::MAX).is_err());
+    vec.try_extend_from_nums(&[4u8, 5]).unwrap();
+    vec.try_push_num(0xFF_FFFFu32).unwrap();
+    assert_eq!(vec.iter_nums().collect::<Vec<_>>(), [1, 2, 3, 4, 5, 0xFF_FFFF]);
+    assert_eq!(vec.get_num(5), Some(0xFF_FFFF));
+    assert_eq!(vec.get_num(6), None);
+}
+
+#[test]
+pub fn test_bytes() {
+    let mut vec = PackedVec::<u24>::with_capacity_padded(8);
+    assert!(vec.capacity() >= 8);
+    vec.reserve(100);
+    assert!(vec.capacity() >= 100);
+
+    vec.push(u24::from(0x010203u32));
+    assert_eq!(vec.as_bytes(), u24::from(0x010203u32).to_ne_bytes());
+    let new = vec.extend_zeroed(2);
+    assert_eq!(new.len(), 6);
+    new.copy_from_slice(&[1, 2, 3, 4, 5, 6]);
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec[1].to_ne_bytes(), [1, 2, 3]);
+    check_padding(&vec);
+
+    assert_eq!(vec.extend_from_bytes(&[7, 8]), None);
+    assert_eq!(vec.extend_from_bytes(&[7, 8, 9]), Some(()));
+    assert_eq!(vec.len(), 4);
+    vec.as_bytes_mut()[9] = 0;
+    assert_eq!(vec[3].to_ne_bytes(), [0, 8, 9]);
+    check_padding(&vec);
+}
+
+#[test]
+pub fn test_traits() {
+    let values = [u40::from(3u8), u40::MAX, u40::MIN];
+    let vec: PackedVec<u40> = values.iter().copied().collect();
+    assert_eq!(vec, PackedVec::from(&values[..]));
+    assert_eq!(vec, PackedVec::from(values.to_vec()));
+    assert_eq!(Vec::from(vec.clone()), values);
+    assert_eq!(format!("{:?}", vec), format!("{:?}", values));
+    assert_eq!(vec[1..], values[1..]);
+    assert_eq!(vec.get_by(u24::from(1u8)), Some(&u40::MAX));
+    assert_eq!(vec.iter().max(), Some(&u40::MAX));
+    assert_eq!((&vec).into_iter().sum::<u64>(), u40::MAX_VALUE + 3);
+
+    let mut padded = PackedVec::<u40>::new_padded();
+    padded.extend(&values);
+    assert_eq!(padded, vec);
+    padded[0] = u40::from(4u8);
+    padded.resize(5, u40::from(1u8));
+    assert_eq!(padded.iter_nums().collect::<Vec<_>>(), [4, u40::MAX_VALUE, 0, 1, 1]);
+    padded.clear();
+    assert!(padded.is_empty());
+    check_padding(&padded);
+}
+
+#[test]
+pub fn test_extend_panic() {
+    let mut vec = PackedVec::<u40>::new_padded();
+    vec.extend([u40::from(1u8), u40::from(2u8)]);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.extend((3u8..10).map(|i| if i < 6 { u40::from(i) } else { panic!("iterator failed") }));
+    }));
+    assert!(result.is_err());
+
+    //The values appended before the panic are kept and the padding is restored.
+    assert_eq!(vec.iter_nums().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    check_padding(&vec);
+    vec.push(u40::MAX);
+    assert_eq!(vec.len(), 6);
+    check_padding(&vec);
+}
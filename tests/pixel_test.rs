@@ -0,0 +1,137 @@
+use uintx::pixel::{
+    convert_scanline, convert_scanline_bytes, premultiply_scanline, unpremultiply_scanline, Bgr888, Cmykog48, Pixel,
+    Rgb161616, Rgb565, Rgb888,
+};
+use uintx::{u24, u48};
+
+#[test]
+pub fn test_channels() {
+    let mut p = Rgb888::new(1, 2, 3);
+    assert_eq!((p.r(), p.g(), p.b()), (1, 2, 3));
+    p.set_g(20);
+    assert_eq!((p.r(), p.g(), p.b()), (1, 20, 3));
+    assert_eq!(u24::from(p).to_ne_bytes(), [1, 20, 3]);
+    assert_eq!(format!("{:?}", p), "Rgb888 { r: 1, g: 20, b: 3 }");
+
+    let mut p = Bgr888::new(1, 2, 3);
+    assert_eq!((p.b(), p.g(), p.r()), (1, 2, 3));
+    p.set_r(30);
+    assert_eq!(u24::from(p).to_ne_bytes(), [1, 2, 30]);
+
+    let mut p = Rgb565::new(31, 63, 31);
+    assert_eq!(u16::from(p), 0xFFFF);
+    p.set_g(0);
+    assert_eq!(u16::from(p), 0xF81F);
+    assert_eq!(Rgb565::from(0xF800u16).r(), 31);
+    assert_eq!(Rgb565::from(0x07E0u16).g(), 63);
+    assert_eq!(Rgb565::from(0x001Fu16).b(), 31);
+
+    let mut p = Rgb161616::new(0x1234, 0x5678, 0x9ABC);
+    assert_eq!((p.r(), p.g(), p.b()), (0x1234, 0x5678, 0x9ABC));
+    p.set_b(1);
+    assert_eq!(u48::from(p).as_u16_array(), [0x1234, 0x5678, 1]);
+
+    let mut p = Cmykog48::new(1, 2, 3, 4, 5, 6);
+    assert_eq!((p.c(), p.m(), p.y(), p.k(), p.o(), p.g()), (1, 2, 3, 4, 5, 6));
+    p.set_o(50);
+    assert_eq!(u48::from(p).to_ne_bytes(), [1, 2, 3, 4, 50, 6]);
+    assert_eq!(format!("{:?}", p), "Cmykog48 { c: 1, m: 2, y: 3, k: 4, o: 50, g: 6 }");
+}
+
+#[test]
+pub fn test_conversions() {
+    let rgb = Rgb888::new(10, 20, 30);
+    assert_eq!(Bgr888::from(rgb), Bgr888::new(30, 20, 10));
+    assert_eq!(Rgb888::from(Bgr888::from(rgb)), rgb);
+
+    assert_eq!(Rgb161616::from(Rgb888::new(255, 0, 1)), Rgb161616::new(65535, 0, 257));
+    assert_eq!(Rgb888::from(Rgb161616::new(65535, 128, 129)), Rgb888::new(255, 0, 1));
+    for c in 0..=255u8 {
+        let p = Rgb888::new(c, c, c);
+        assert_eq!(Rgb888::from(Rgb161616::from(p)), p);
+    }
+
+    //Every Rgb565 value survives the round trip through Rgb888.
+    for n in 0..=u16::MAX {
+        let p = Rgb565::from(n);
+        assert_eq!(Rgb565::from(Rgb888::from(p)), p);
+    }
+    assert_eq!(Rgb888::from(Rgb565::new(31, 63, 31)), Rgb888::new(255, 255, 255));
+
+    assert_eq!(Cmykog48::from(Rgb888::new(0, 0, 0)), Cmykog48::new(0, 0, 0, 255, 0, 0));
+    assert_eq!(Cmykog48::from(Rgb888::new(255, 255, 255)), Cmykog48::new(0, 0, 0, 0, 0, 0));
+    assert_eq!(Cmykog48::from(Rgb888::new(255, 0, 0)), Cmykog48::new(0, 255, 255, 0, 0, 0));
+    assert_eq!(Rgb888::from(Cmykog48::new(0, 255, 255, 0, 0, 0)), Rgb888::new(255, 0, 0));
+    assert_eq!(Rgb888::from(Cmykog48::new(0, 0, 0, 0, 0, 255)), Rgb888::new(0, 255, 0));
+    assert_eq!(Rgb888::from(Cmykog48::new(0, 0, 0, 0, 255, 0)), Rgb888::new(255, 128, 0));
+    for c in [0u8, 1, 17, 128, 254, 255] {
+        let p = Rgb888::new(c, 255 - c, c / 2);
+        let back = Rgb888::from(Cmykog48::from(p));
+        assert!(back.r().abs_diff(p.r()) <= 1 && back.g().abs_diff(p.g()) <= 1 && back.b().abs_diff(p.b()) <= 1);
+    }
+
+    //Conversions between formats other than Rgb888.
+    assert_eq!(Rgb565::from(Bgr888::new(0, 0, 255)), Rgb565::new(31, 0, 0));
+    assert_eq!(Rgb161616::from(Rgb565::new(31, 0, 0)), Rgb161616::new(65535, 0, 0));
+    assert_eq!(Bgr888::from(Cmykog48::new(0, 0, 0, 255, 0, 0)), Bgr888::new(0, 0, 0));
+}
+
+#[test]
+pub fn test_premultiply() {
+    let p = Rgb888::new(255, 128, 0);
+    assert_eq!(p.premultiply(255), p);
+    assert_eq!(p.premultiply(0), Rgb888::new(0, 0, 0));
+    assert_eq!(p.premultiply(128), Rgb888::new(128, 64, 0));
+    assert_eq!(p.premultiply(128).unpremultiply(128), p);
+    assert_eq!(p.unpremultiply(0), Rgb888::new(0, 0, 0));
+    //Channels larger than alpha are clamped.
+    assert_eq!(Rgb888::new(200, 10, 0).unpremultiply(100), Rgb888::new(255, 26, 0));
+
+    assert_eq!(Rgb565::new(31, 63, 31).premultiply(128), Rgb565::new(16, 32, 16));
+    assert_eq!(Rgb565::new(16, 32, 16).unpremultiply(128), Rgb565::new(31, 63, 31));
+    assert_eq!(Rgb161616::new(65535, 1000, 0).premultiply(32768), Rgb161616::new(32768, 500, 0));
+    assert_eq!(Cmykog48::new(255, 0, 0, 0, 0, 100).premultiply(51), Cmykog48::new(51, 0, 0, 0, 0, 20));
+
+    let mut line = [Rgb888::new(255, 255, 255), Rgb888::new(100, 50, 0)];
+    premultiply_scanline(&mut line, &[0, 255]);
+    assert_eq!(line, [Rgb888::new(0, 0, 0), Rgb888::new(100, 50, 0)]);
+    unpremultiply_scanline(&mut line, &[0, 255]);
+    assert_eq!(line, [Rgb888::new(0, 0, 0), Rgb888::new(100, 50, 0)]);
+}
+
+#[test]
+pub fn test_scanline() {
+    let src = [Rgb888::new(1, 2, 3), Rgb888::new(4, 5, 6)];
+    let mut dst = [Bgr888::default(); 2];
+    convert_scanline(&src, &mut dst);
+    assert_eq!(dst, [Bgr888::new(3, 2, 1), Bgr888::new(6, 5, 4)]);
+
+    let bytes = [1u8, 2, 3, 4, 5, 6];
+    let mut out = [0u8; 6];
+    assert_eq!(convert_scanline_bytes::<Rgb888, Bgr888>(&bytes, &mut out), 2);
+    assert_eq!(out, [3, 2, 1, 6, 5, 4]);
+
+    let mut wide = [0u8; 12];
+    convert_scanline_bytes::<Rgb888, Rgb161616>(&bytes, &mut wide);
+    let wide = Rgb161616::slice_from_bytes(&wide).unwrap();
+    assert_eq!(wide[1], Rgb161616::new(4 * 257, 5 * 257, 6 * 257));
+
+    let mut rgb565 = [0u8; 4];
+    convert_scanline_bytes::<Rgb888, Rgb565>(&[255, 0, 0, 0, 0, 255], &mut rgb565);
+    assert_eq!(rgb565, [0x00, 0xF8, 0x1F, 0x00]);
+
+    assert_eq!(Rgb888::slice_from_bytes(&[0u8; 4]), None);
+    assert_eq!(Rgb888::slice_as_bytes(&src), &bytes);
+    let mut buf = bytes;
+    Bgr888::slice_from_bytes_mut(&mut buf).unwrap()[0].set_b(9);
+    assert_eq!(buf[0], 9);
+    assert_eq!(Cmykog48::BYTES, 6);
+    assert_eq!(Cmykog48::CHANNELS, 6);
+}
+
+#[test]
+#[should_panic]
+pub fn test_scanline_length_mismatch() {
+    let mut dst = [Bgr888::default(); 1];
+    convert_scanline(&[Rgb888::default(); 2], &mut dst);
+}
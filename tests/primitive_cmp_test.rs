@@ -0,0 +1,65 @@
+use core::cmp::Ordering;
+use uintx::{u104, u112, u120, u24, u40, u48, u56, u72, u80, u88, u96, UnalignedUint};
+
+///
+/// Numeric ordering of an unsigned value n against a primitive given as i128 or u128.
+///
+fn expected(n: u128, other: i128, other_unsigned: u128, signed: bool) -> Ordering {
+    if signed && other < 0 {
+        return Ordering::Greater;
+    }
+
+    n.cmp(&other_unsigned)
+}
+
+///
+/// Compares x against every primitive in both directions and checks the result against the u128 value.
+///
+macro_rules! check {
+    ($x:expr, $($primitive:ty),*) => {
+        let x = $x;
+        let n = x.as_u128();
+        $(
+            for other in [<$primitive>::MIN, <$primitive>::MIN / 2, 0, 1, <$primitive>::MAX / 2, <$primitive>::MAX] {
+                let expected = expected(n, other as i128, other as u128, <$primitive>::MIN != 0);
+                assert_eq!(x.partial_cmp(&other), Some(expected), "{} {}", n, other);
+                assert_eq!(other.partial_cmp(&x), Some(expected.reverse()), "{} {}", other, n);
+                assert_eq!(x == other, expected == Ordering::Equal, "{} {}", n, other);
+                assert_eq!(other == x, expected == Ordering::Equal, "{} {}", other, n);
+            }
+        )*
+    };
+}
+
+macro_rules! test_type {
+    ($($ty:ident),*) => {
+        #[test]
+        pub fn test_all_types() {
+            $(
+                for x in [$ty::MIN, $ty::MAX, $ty::from(1u8), $ty::from(u8::MAX), $ty::from(u16::MAX), $ty::from(0x1_0000u32)] {
+                    check!(x, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+                }
+            )*
+        }
+    };
+}
+
+test_type!(u24, u40, u48, u56, u72, u80, u88, u96, u104, u112, u120);
+
+#[test]
+pub fn test_no_truncation() {
+    //u64::from would truncate, the comparison does not.
+    let x = u24::from(0x10u8);
+    assert!(x != 0x1_0000_0010u64);
+    assert!(x < 0x1_0000_0010u64);
+    assert!(0x1_0000_0010u64 > x);
+    assert!(x > -1i32);
+    assert!(-1i32 < x);
+    assert!(x != -16i64);
+    assert!(u48::MAX < usize::MAX || usize::BITS < 48);
+    assert!(u120::MAX < u128::MAX);
+    assert!(u120::MAX > i64::MAX);
+    assert_eq!(x, 16);
+    assert!(16u32 == x);
+    assert!(x <= 16);
+}
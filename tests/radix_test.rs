@@ -0,0 +1,100 @@
+//! Compares the radix sorts against sorting by as_num().
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use uintx::radix::{
+    radix_sort_by_key_unstable, radix_sort_by_key_with_scratch, radix_sort_unstable, radix_sort_with_scratch,
+};
+use uintx::{u104, u112, u120, u24, u40, u48, u56, u72, u80, u88, u96, UnalignedUint};
+
+const LEN: usize = if cfg!(miri) { 100 } else { 5000 };
+
+///
+/// Random inputs with full range values, values that only differ in a few low bytes and many duplicates.
+///
+fn inputs<T: UnalignedUint>() -> Vec<Vec<T>> {
+    let mut rng = StdRng::seed_from_u64(T::BYTES as u64);
+    let full: Vec<T> = (0..LEN).map(|_| T::from_u128_truncated(rng.gen())).collect();
+    let low: Vec<T> = (0..LEN).map(|_| T::from_u128_truncated(rng.gen_range(0..70_000))).collect();
+    let high: Vec<T> = (0..LEN).map(|_| T::from_u128_truncated(rng.gen_range(0..4u128) << (T::NUM_BITS - 2))).collect();
+    let small: Vec<T> = (0..20).map(|_| T::from_u128_truncated(rng.gen())).collect();
+    vec![Vec::new(), vec![T::MAX], full, low, high, small, vec![T::MAX; 100]]
+}
+
+fn check<T: UnalignedUint>() {
+    for input in inputs::<T>() {
+        let mut expected = input.clone();
+        expected.sort_by_key(|v| v.as_num());
+
+        let mut unstable = input.clone();
+        radix_sort_unstable(&mut unstable);
+        assert_eq!(unstable, expected);
+
+        let mut stable = input.clone();
+        let mut scratch = vec![T::MIN; input.len() + 3];
+        radix_sort_with_scratch(&mut stable, &mut scratch);
+        assert_eq!(stable, expected);
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut allocating = input.clone();
+            uintx::radix::radix_sort(&mut allocating);
+            assert_eq!(allocating, expected);
+        }
+
+        //The index of every element tells if equal keys kept their order.
+        let mut indexed: Vec<(T, usize)> = input.iter().copied().zip(0..).collect();
+        let mut expected_indexed = indexed.clone();
+        expected_indexed.sort_by_key(|e| e.0.as_num());
+        let mut scratch = indexed.clone();
+        radix_sort_by_key_with_scratch(&mut indexed, &mut scratch, |e| e.0);
+        assert_eq!(indexed, expected_indexed);
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut allocating: Vec<(T, usize)> = input.iter().copied().zip(0..).collect();
+            uintx::radix::radix_sort_by_key(&mut allocating, |e| e.0);
+            assert_eq!(allocating, expected_indexed);
+        }
+
+        let mut unstable_indexed: Vec<(T, usize)> = input.iter().copied().zip(0..).collect();
+        radix_sort_by_key_unstable(&mut unstable_indexed, |e| e.0);
+        assert_eq!(unstable_indexed.iter().map(|e| e.0).collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+pub fn test_all_types() {
+    check::<u24>();
+    check::<u40>();
+    check::<u48>();
+    check::<u56>();
+    check::<u72>();
+    check::<u80>();
+    check::<u88>();
+    check::<u96>();
+    check::<u104>();
+    check::<u112>();
+    check::<u120>();
+}
+
+#[test]
+pub fn test_key_of_other_type() {
+    //Sorting records by a u24 key that is derived from the record.
+    let mut records: Vec<u64> = (0..1000u64).map(|i| (i * 7919) % 1000 + (i << 32)).collect();
+    let mut expected = records.clone();
+    expected.sort_by_key(|r| *r as u32 & 0xFF_FFFF);
+    radix_sort_by_key_unstable(&mut records, |r| u24::from_num_truncated(*r as u32));
+    assert!(records.windows(2).all(|w| (w[0] as u32 & 0xFF_FFFF) <= (w[1] as u32 & 0xFF_FFFF)));
+    let mut scratch = vec![0; records.len()];
+    records = (0..1000u64).map(|i| (i * 7919) % 1000 + (i << 32)).collect();
+    radix_sort_by_key_with_scratch(&mut records, &mut scratch, |r| u24::from_num_truncated(*r as u32));
+    assert_eq!(records, expected);
+}
+
+#[test]
+#[should_panic]
+pub fn test_scratch_too_small() {
+    let mut data = [u24::MAX, u24::MIN];
+    radix_sort_with_scratch(&mut data, &mut [u24::MIN]);
+}
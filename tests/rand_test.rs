@@ -0,0 +1,79 @@
+#![cfg(feature = "rand")]
+
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use uintx::distributions::{fill, try_fill};
+use uintx::{u120, u24, u40, u56, u72};
+
+#[test]
+pub fn test_standard() {
+    let mut rng = StdRng::seed_from_u64(0x5EED);
+    let mut or = u72::MIN;
+    for _ in 0..1000 {
+        or |= rng.gen::<u72>();
+    }
+    //Every bit is set at least once.
+    assert_eq!(or, u72::MAX);
+
+    //Little endian interpretation of the random bytes on every host.
+    let mut a = StdRng::seed_from_u64(1);
+    let mut b = StdRng::seed_from_u64(1);
+    let mut bytes = [0u8; 3];
+    a.fill(&mut bytes);
+    assert_eq!(b.gen::<u24>(), u24::from_le_bytes(bytes));
+}
+
+#[test]
+pub fn test_gen_range() {
+    let mut rng = StdRng::seed_from_u64(0x5EED);
+    for _ in 0..1000 {
+        let n = rng.gen_range(u40::from(5u8)..u40::MAX);
+        assert!(n >= 5u64 && n < u40::MAX);
+
+        let n = rng.gen_range(u120::from(10u8)..=u120::from(12u8));
+        assert!((10u128..=12u128).contains(&n.as_num()));
+
+        let n = rng.gen_range(u24::MAX..=u24::MAX);
+        assert_eq!(n, u24::MAX);
+    }
+}
+
+#[test]
+pub fn test_uniform_is_unbiased() {
+    //Same stream as the helper type must be produced.
+    let dist = Uniform::new(u56::from(3u8), u56::from(1000u32));
+    let helper = Uniform::new(3u64, 1000u64);
+    let mut a = StdRng::seed_from_u64(7);
+    let mut b = StdRng::seed_from_u64(7);
+    let mut buckets = [0u32; 4];
+    for _ in 0..4000 {
+        let n = dist.sample(&mut a);
+        assert_eq!(n, helper.sample(&mut b));
+        buckets[(n.as_num() % 4) as usize] += 1;
+    }
+
+    for bucket in buckets {
+        assert!(bucket > 800, "{:?}", buckets);
+    }
+}
+
+#[test]
+pub fn test_fill() {
+    let mut rng = StdRng::seed_from_u64(0x5EED);
+    let mut data = [u72::MIN; 64];
+    fill(&mut data, &mut rng);
+    assert!(data.iter().any(|n| *n != u72::MIN));
+    assert!(data.windows(2).all(|w| w[0] != w[1]));
+
+    //Same values as filling a byte buffer and reading it as little endian.
+    let mut a = StdRng::seed_from_u64(3);
+    let mut b = StdRng::seed_from_u64(3);
+    let mut data = [u24::MIN; 16];
+    let mut bytes = [0u8; 48];
+    try_fill(&mut data, &mut a).unwrap();
+    b.fill(&mut bytes[..]);
+    for (n, chunk) in data.iter().zip(bytes.chunks_exact(3)) {
+        assert_eq!(*n, u24::from_le_bytes(chunk.try_into().unwrap()));
+    }
+}
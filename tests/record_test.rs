@@ -0,0 +1,113 @@
+#![cfg(feature = "derive")]
+use uintx::record::{PackedRecord, RecordSlice, RecordSliceMut};
+use uintx::{u24, u40};
+
+#[derive(PackedRecord, Copy, Clone, Debug, Default, PartialEq)]
+struct Header {
+    kind: u8,
+    length: u24,
+    #[be]
+    offset: u40,
+    flags: u16,
+}
+
+#[derive(PackedRecord, Copy, Clone, Debug, PartialEq)]
+#[be]
+struct Network(u16, #[le] u24, [u24; 2]);
+
+#[derive(PackedRecord, Copy, Clone, Debug, PartialEq)]
+struct Nested {
+    header: Header,
+    #[be]
+    network: Network,
+    pairs: [[u8; 2]; 2],
+}
+
+#[derive(PackedRecord, Copy, Clone, Debug, PartialEq)]
+struct Empty;
+
+#[derive(PackedRecord, Copy, Clone, Debug, PartialEq)]
+struct Small(u24);
+
+fn header() -> Header {
+    Header { kind: 7, length: u24::from(0x010203u32), offset: u40::from(0x0405060708u64), flags: 0x090A }
+}
+
+#[test]
+pub fn test_named() {
+    assert_eq!(Header::SIZE, 11);
+    let bytes = header().to_bytes();
+    assert_eq!(bytes, [7, 0x03, 0x02, 0x01, 0x04, 0x05, 0x06, 0x07, 0x08, 0x0A, 0x09]);
+    assert_eq!(Header::from_bytes(&bytes), header());
+}
+
+#[test]
+pub fn test_tuple_and_unit() {
+    let network = Network(0x0102, u24::from(0x030405u32), [u24::from(0x060708u32), u24::from(0x090A0Bu32)]);
+    assert_eq!(Network::SIZE, 11);
+    let bytes = network.to_bytes();
+    assert_eq!(bytes, [0x01, 0x02, 0x05, 0x04, 0x03, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B]);
+    assert_eq!(Network::from_bytes(&bytes), network);
+
+    assert_eq!(Empty::SIZE, 0);
+    assert_eq!(Empty::from_bytes(&[]), Empty);
+    assert!(RecordSlice::<Empty>::new(&[]).is_none());
+}
+
+#[test]
+pub fn test_nested() {
+    let network = Network(1, u24::from(2u8), [u24::from(3u8), u24::from(4u8)]);
+    let nested = Nested { header: header(), network, pairs: [[1, 2], [3, 4]] };
+    assert_eq!(Nested::SIZE, Header::SIZE + Network::SIZE + 4);
+
+    //Nested records keep their own byte order, the #[be] on the field has no effect.
+    let bytes = nested.to_bytes();
+    assert_eq!(&bytes[..Header::SIZE], &header().to_bytes());
+    assert_eq!(&bytes[Header::SIZE..Header::SIZE + Network::SIZE], &network.to_bytes());
+    assert_eq!(&bytes[Header::SIZE + Network::SIZE..], &[1, 2, 3, 4]);
+    assert_eq!(Nested::from_bytes(&bytes), nested);
+}
+
+#[test]
+pub fn test_slices() {
+    let mut table = [header().to_bytes(), Header::default().to_bytes(), header().to_bytes()].concat();
+    assert!(RecordSlice::<Header>::new(&table[1..]).is_none());
+
+    let view = RecordSlice::<Header>::new(&table).unwrap();
+    assert_eq!(view.len(), 3);
+    assert_eq!(view.get(0), Some(header()));
+    assert_eq!(view.get(1), Some(Header::default()));
+    assert_eq!(view.get(3), None);
+    assert_eq!(view.get(usize::MAX), None);
+    assert_eq!(view.iter().filter(|h| h.kind == 7).count(), 2);
+
+    //Records do not need to be aligned.
+    let mut unaligned = vec![0u8];
+    unaligned.extend_from_slice(&table);
+    assert_eq!(RecordSlice::<Header>::new(&unaligned[1..]).unwrap().get(2), Some(header()));
+
+    let mut view = RecordSliceMut::<Header>::new(&mut table).unwrap();
+    let mut changed = header();
+    changed.flags = 0xFFFF;
+    view.set(1, &changed);
+    assert_eq!(view.get(1), Some(changed));
+    assert_eq!(view.as_slice().iter().collect::<Vec<_>>(), [header(), changed, header()]);
+    assert_eq!(&table[Header::SIZE * 2 - 2..Header::SIZE * 2], &[0xFF, 0xFF]);
+}
+
+#[test]
+pub fn test_get_huge_index() {
+    //usize::MAX is a multiple of 3, so the record starts at usize::MAX and its end overflows.
+    let mut table = [0u8; 6];
+    assert_eq!(Small::SIZE, 3);
+    assert_eq!(RecordSlice::<Small>::new(&table).unwrap().get(usize::MAX / 3), None);
+    assert_eq!(RecordSliceMut::<Small>::new(&mut table).unwrap().get(usize::MAX / 3), None);
+    assert_eq!(RecordSlice::<Small>::new(&table).unwrap().get(1), Some(Small(u24::MIN)));
+}
+
+#[test]
+#[should_panic]
+pub fn test_set_out_of_bounds() {
+    let mut table = [0u8; 22];
+    RecordSliceMut::<Header>::new(&mut table).unwrap().set(2, &header());
+}
@@ -0,0 +1,67 @@
+#![cfg(all(feature = "proptest", not(miri)))]
+
+//! Compares every method of common_fn_impl! against the model using edge biased proptest inputs.
+
+mod model;
+
+use proptest::prelude::*;
+use uintx::{u104, u112, u120, u24, u40, u48, u56, u72, u80, u88, u96};
+
+macro_rules! reference_tests {
+    ($under_test:ident, $helper:ty, $module:ident) => {
+        mod $module {
+            use super::*;
+
+            crate::model::model!($under_test, $helper);
+
+            proptest! {
+                #[test]
+                fn bytes(a in any::<T>()) {
+                    check_bytes(a);
+                }
+
+                #[test]
+                fn bits(a in any::<T>()) {
+                    check_bits(a);
+                }
+
+                #[test]
+                fn shift(a in any::<T>(), s in 0u32..(BITS * 3)) {
+                    check_shift(a, s);
+                }
+
+                #[test]
+                fn pow(a in any::<T>(), e in 0u32..20) {
+                    check_pow(a, e);
+                }
+
+                #[test]
+                fn binary(a in any::<T>(), b in any::<T>()) {
+                    check_binary(a, b);
+                }
+
+                #[test]
+                fn number_theory(a in any::<T>(), b in any::<T>(), m in any::<T>()) {
+                    check_number_theory(a, b, m);
+                }
+
+                #[test]
+                fn from_str_radix(a in any::<T>(), over in 1u8..=255) {
+                    check_from_str_radix(a, over);
+                }
+            }
+        }
+    };
+}
+
+reference_tests!(u24, u32, reference_u24);
+reference_tests!(u40, u64, reference_u40);
+reference_tests!(u48, u64, reference_u48);
+reference_tests!(u56, u64, reference_u56);
+reference_tests!(u72, u128, reference_u72);
+reference_tests!(u80, u128, reference_u80);
+reference_tests!(u88, u128, reference_u88);
+reference_tests!(u96, u128, reference_u96);
+reference_tests!(u104, u128, reference_u104);
+reference_tests!(u112, u128, reference_u112);
+reference_tests!(u120, u128, reference_u120);
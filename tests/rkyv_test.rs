@@ -0,0 +1,67 @@
+#![cfg(feature = "rkyv")]
+
+use rkyv::rancor::Error;
+use uintx::{u104, u112, u120, u24, u40, u48, u56, u72, u80, u88, u96, ArchivedLe, UnalignedUint};
+
+#[derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize, Debug, PartialEq)]
+struct Entry {
+    offset: u40,
+    length: u24,
+    tags: Vec<u48>,
+    checksum: u120,
+}
+
+fn check<T>(value: T)
+where
+    T: UnalignedUint + rkyv::Archive<Archived = ArchivedLe<T>>,
+    T: for<'a> rkyv::Serialize<rkyv::api::high::HighSerializer<rkyv::util::AlignedVec, rkyv::ser::allocator::ArenaHandle<'a>, Error>>,
+    ArchivedLe<T>: rkyv::Deserialize<T, rkyv::api::high::HighDeserializer<Error>>,
+{
+    let bytes = rkyv::to_bytes::<Error>(&value).unwrap();
+    //The archive is the little endian bytes on every host.
+    assert_eq!(&bytes[..], value.to_le_bytes().as_ref());
+    let archived = rkyv::access::<ArchivedLe<T>, Error>(&bytes).unwrap();
+    assert_eq!(archived.to_native(), value);
+    assert_eq!(*archived, value);
+    assert_eq!(archived.as_num(), value.as_num());
+    assert_eq!(rkyv::deserialize::<T, Error>(archived).unwrap(), value);
+}
+
+#[test]
+pub fn test_all_types() {
+    macro_rules! check_type {
+        ($($ty:ident),*) => {
+            $(
+                check($ty::MIN);
+                check($ty::MAX);
+                check($ty::from_u128_truncated(0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10));
+            )*
+        };
+    }
+
+    check_type!(u24, u40, u48, u56, u72, u80, u88, u96, u104, u112, u120);
+}
+
+#[test]
+pub fn test_struct() {
+    let entry = Entry {
+        offset: u40::from_le_bytes([0x90, 0x78, 0x56, 0x34, 0x12]),
+        length: u24::from(0xABCDEFu32),
+        tags: vec![u48::MAX, u48::from(7u8)],
+        checksum: u120::MAX,
+    };
+
+    let bytes = rkyv::to_bytes::<Error>(&entry).unwrap();
+    let archived = rkyv::access::<ArchivedEntry, Error>(&bytes).unwrap();
+    assert_eq!(archived.offset, entry.offset);
+    assert_eq!(archived.length.to_native(), u24::from(0xABCDEFu32));
+    assert_eq!(archived.tags.len(), 2);
+    assert_eq!(archived.tags[0], u48::MAX);
+    assert_eq!(archived.tags[1].as_num(), 7);
+    assert_eq!(archived.checksum, u120::MAX);
+    assert!(archived.tags[1] < archived.tags[0]);
+    assert_eq!(format!("{:?} {}", archived.length, archived.length), "11259375 11259375");
+
+    let deserialized = rkyv::from_bytes::<Entry, Error>(&bytes).unwrap();
+    assert_eq!(deserialized, entry);
+}
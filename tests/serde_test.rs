@@ -0,0 +1,112 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
+use uintx::{u104, u120, u24, u40, u48, u56, u72, u80, u88, u96, u112};
+
+#[test]
+pub fn test_json_is_numeric() {
+    assert_eq!(serde_json::to_string(&u24::from(0xABCDEFu32)).unwrap(), "11259375");
+    assert_eq!(serde_json::to_string(&u48::MAX).unwrap(), "281474976710655");
+    assert_eq!(serde_json::to_string(&u120::MAX).unwrap(), u120::MAX.to_string());
+
+    assert_eq!(serde_json::from_str::<u24>("11259375").unwrap(), 0xABCDEFu32);
+    assert_eq!(serde_json::from_str::<u72>("4722366482869645213695").unwrap(), u72::MAX);
+    assert_eq!(serde_json::from_str::<u40>("\"0xFFFFFFFFFF\"").unwrap(), u40::MAX);
+    assert_eq!(serde_json::from_str::<u56>("\"1234\"").unwrap(), 1234u64);
+}
+
+#[test]
+pub fn test_json_range_errors() {
+    let err = serde_json::from_str::<u24>("16777216").unwrap_err().to_string();
+    assert!(err.contains("out of range"), "{}", err);
+    assert!(err.contains("16777215"), "{}", err);
+
+    assert!(serde_json::from_str::<u48>("-1").is_err());
+    assert!(serde_json::from_str::<u48>("1.5").is_err());
+    assert!(serde_json::from_str::<u48>("\"0xfffffffffffff\"").is_err());
+    assert!(serde_json::from_str::<u80>("1208925819614629174706176").is_err());
+}
+
+macro_rules! round_trip {
+    ($ty:ty) => {
+        for value in [<$ty>::MIN, <$ty>::from(1u8), <$ty>::from(0xA5u8).shl(20), <$ty>::MAX] {
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(serde_json::from_str::<$ty>(&json).unwrap(), value);
+
+            let bin = postcard::to_allocvec(&value).unwrap();
+            assert_eq!(bin.len(), core::mem::size_of::<$ty>());
+            assert_eq!(bin, value.to_le_bytes().to_vec());
+            assert_eq!(postcard::from_bytes::<$ty>(&bin).unwrap(), value);
+        }
+    };
+}
+
+#[test]
+pub fn test_round_trip() {
+    round_trip!(u24);
+    round_trip!(u40);
+    round_trip!(u48);
+    round_trip!(u56);
+    round_trip!(u72);
+    round_trip!(u80);
+    round_trip!(u88);
+    round_trip!(u96);
+    round_trip!(u104);
+    round_trip!(u112);
+    round_trip!(u120);
+}
+
+#[test]
+pub fn test_tokens() {
+    assert_tokens(&u24::from(0x010203u32).readable(), &[Token::U32(0x010203)]);
+    assert_tokens(&u48::from(0x010203u32).readable(), &[Token::U64(0x010203)]);
+    assert_tokens(
+        &u24::from(0x010203u32).compact(),
+        &[
+            Token::NewtypeStruct { name: "u24" },
+            Token::Tuple { len: 3 },
+            Token::U8(3),
+            Token::U8(2),
+            Token::U8(1),
+            Token::TupleEnd,
+        ],
+    );
+
+    assert_de_tokens_error::<serde_test::Readable<u24>>(
+        &[Token::I64(-5)],
+        "invalid value: integer `-5`, expected an integer between 0 and 16777215",
+    );
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Adapters {
+    #[serde(with = "uintx::serde_as::as_be_bytes")]
+    mac: u48,
+    #[serde(with = "uintx::serde_as::as_hex_string")]
+    id: u72,
+}
+
+#[test]
+pub fn test_adapters() {
+    let value = Adapters {
+        mac: u48::from(0x0011223344AAu64),
+        id: u72::from(0xABCDEFu32),
+    };
+
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"mac":[0,17,34,51,68,170],"id":"000000000000abcdef"}"#);
+    assert_eq!(serde_json::from_str::<Adapters>(&json).unwrap(), value);
+
+    let bin = postcard::to_allocvec(&value).unwrap();
+    assert_eq!(&bin[..7], &[6, 0x00, 0x11, 0x22, 0x33, 0x44, 0xAA]);
+    assert_eq!(postcard::from_bytes::<Adapters>(&bin).unwrap(), value);
+
+    let parsed: Adapters = serde_json::from_str(r#"{"mac":[0,0,0,0,0,1],"id":"0xFF"}"#).unwrap();
+    assert_eq!(parsed.mac, 1u64);
+    assert_eq!(parsed.id, 0xFFu128);
+
+    assert!(serde_json::from_str::<Adapters>(r#"{"mac":[0,0,0,0,1],"id":"0"}"#).is_err());
+    assert!(serde_json::from_str::<Adapters>(r#"{"mac":[0,0,0,0,0,1],"id":"1000000000000000000"}"#).is_err());
+    assert!(serde_json::from_str::<Adapters>(r#"{"mac":[0,0,0,0,0,1],"id":"xyz"}"#).is_err());
+}
@@ -1,5 +1,5 @@
-use getrandom::getrandom;
 use std::mem::size_of;
+use uintx::UnalignedUint;
 use uintx::u104;
 use uintx::u112;
 use uintx::u120;
@@ -14,6 +14,16 @@ use uintx::u96;
 
 const TEST_SET_SIZE: usize = if cfg!(miri) { 0xF } else if cfg!(target_endian = "big") { 0xF} else { 0xFFFF };
 
+#[cfg(feature = "rand")]
+fn fill_random<T: UnalignedUint>(data: &mut [T]) {
+    uintx::distributions::fill(data, &mut rand::thread_rng());
+}
+
+#[cfg(not(feature = "rand"))]
+fn fill_random<T: UnalignedUint>(data: &mut [T]) {
+    rand::RngCore::fill_bytes(&mut rand::thread_rng(), T::slice_as_bytes_mut(data));
+}
+
 macro_rules! test_type {
     ($under_test:ty, $tt:ident) => {
         struct $tt {
@@ -34,14 +44,8 @@ macro_rules! test_type {
             }
 
             fn make_test_data() -> $tt {
-                let mut dta = Vec::new();
-                for _ in 0..TEST_SET_SIZE {
-                    let mut inner: Vec<u8> = vec![0; size_of::<$under_test>()];
-                    getrandom(inner.as_mut_slice()).expect("GETRANDOM");
-                    let copy: $under_test =
-                        unsafe { (*inner.as_ptr().cast::<$under_test>()).clone() };
-                    dta.push(copy);
-                }
+                let mut dta = vec![<$under_test>::MIN; TEST_SET_SIZE];
+                fill_random(dta.as_mut_slice());
                 dta.push(<$under_test>::MAX);
                 dta.push(<$under_test>::MIN);
 
@@ -0,0 +1,304 @@
+//! Compares UInt of several widths against num-bigint on boundary and random inputs.
+
+use num_bigint::BigUint;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cmp::Ordering;
+use uintx::{u120, u144, u192, u24, u256, u48, UInt};
+
+const RANDOM_INPUTS: usize = if cfg!(miri) { 4 } else { 64 };
+
+fn big<const N: usize>(n: UInt<N>) -> BigUint {
+    BigUint::from_bytes_le(&n.to_le_bytes())
+}
+
+fn modulus<const N: usize>() -> BigUint {
+    BigUint::from(1u8) << (N * 8)
+}
+
+///
+/// Reduces a BigUint to N bytes, returns the value and whether it was too large.
+///
+fn from_big<const N: usize>(n: &BigUint) -> (UInt<N>, bool) {
+    let reduced = n % modulus::<N>();
+    let mut bytes = reduced.to_bytes_le();
+    bytes.resize(N, 0);
+    (UInt::from_le_bytes(bytes.try_into().unwrap()), &reduced != n)
+}
+
+fn big_gcd(a: &BigUint, b: &BigUint) -> BigUint {
+    let (mut a, mut b) = (a.clone(), b.clone());
+    while b != BigUint::ZERO {
+        let r = &a % &b;
+        a = b;
+        b = r;
+    }
+
+    a
+}
+
+///
+/// Converts the result of a checked operation, None if the BigUint does not fit into N bytes.
+///
+fn checked_from_big<const N: usize>(n: &BigUint) -> Option<UInt<N>> {
+    match from_big::<N>(n) {
+        (r, false) => Some(r),
+        (_, true) => None,
+    }
+}
+
+fn inputs<const N: usize>() -> Vec<UInt<N>> {
+    let mut rng = StdRng::seed_from_u64(N as u64);
+    let mut values = vec![UInt::MIN, UInt::MAX, UInt::from_u128_truncated(1), UInt::from_u128_truncated(10)];
+    for bits in [7, 8, 63, 64, 65, 127, 128, 129] {
+        if bits < N * 8 {
+            values.push(UInt::from_u128_truncated(1) << bits);
+            values.push((UInt::from_u128_truncated(1) << bits) - UInt::from_u128_truncated(1));
+        }
+    }
+
+    for _ in 0..RANDOM_INPUTS {
+        let mut bytes = [0u8; N];
+        rng.fill(&mut bytes[..]);
+        //Random lengths so small divisors and the u64 division path are covered too.
+        let len = rng.gen_range(0..=N);
+        bytes[len..].fill(0);
+        values.push(UInt::from_le_bytes(bytes));
+    }
+
+    values
+}
+
+fn check_width<const N: usize>() {
+    let values = inputs::<N>();
+    for &a in &values {
+        let ba = big(a);
+        assert_eq!(a.to_string(), ba.to_string());
+        assert_eq!(format!("{:x}", a), ba.to_str_radix(16));
+        assert_eq!(format!("{:X}", a), ba.to_str_radix(16).to_uppercase());
+        assert_eq!(format!("{:o}", a), ba.to_str_radix(8));
+        assert_eq!(format!("{:b}", a), ba.to_str_radix(2));
+        assert_eq!(a.to_string().parse::<UInt<N>>(), Ok(a));
+        assert_eq!(UInt::<N>::from_str_radix(&ba.to_str_radix(36), 36), Ok(a));
+
+        assert_eq!(a.count_ones() as u64, ba.count_ones());
+        assert_eq!(a.leading_zeros() as u64, N as u64 * 8 - ba.bits());
+        assert_eq!(a.trailing_zeros() as u64, ba.trailing_zeros().unwrap_or(N as u64 * 8));
+        assert_eq!(UInt::<N>::from_be_bytes(a.to_be_bytes()), a);
+        assert_eq!(a.swap_bytes().swap_bytes(), a);
+        assert_eq!(a.reverse_bits().reverse_bits(), a);
+        assert_eq!(!!a, a);
+
+        for n in [0u32, 1, 7, 8, 9, 63, 64, 65, N as u32 * 8 - 1].into_iter().filter(|n| *n < N as u32 * 8) {
+            assert_eq!(big(a << n), (&ba << n) % modulus::<N>(), "{} << {}", a, n);
+            assert_eq!(big(a >> n), &ba >> n, "{} >> {}", a, n);
+            let rotated = ((&ba << n) | (&ba >> (N as u32 * 8 - n))) % modulus::<N>();
+            assert_eq!(big(a.rotate_left(n)), rotated, "{} rotl {}", a, n);
+            assert_eq!(a.rotate_left(n).rotate_right(n), a);
+        }
+        assert_eq!(a.checked_shl(N as u32 * 8), None);
+
+        assert_eq!(a.overflowing_pow(3), from_big::<N>(&ba.pow(3)), "{}^3", a);
+
+        assert_eq!(big(a.isqrt()), ba.sqrt(), "isqrt {}", a);
+        assert_eq!(a.checked_isqrt(), Some(a.isqrt()));
+        assert_eq!(big(a.wrapping_neg()), (modulus::<N>() - &ba) % modulus::<N>(), "neg {}", a);
+        assert_eq!(a.overflowing_neg(), (a.wrapping_neg(), ba != BigUint::ZERO));
+        assert_eq!(a.checked_neg(), if ba == BigUint::ZERO { Some(a) } else { None });
+        assert_eq!(UInt::<N>::from_be(a.to_be()), a);
+        assert_eq!(a.to_be().to_ne_bytes(), a.to_be_bytes());
+        assert_eq!(UInt::<N>::from_be(UInt::from_ne_bytes(a.to_be_bytes())), a);
+
+        if ba == BigUint::ZERO {
+            assert_eq!(a.checked_ilog10(), None);
+            assert_eq!(a.checked_ilog(UInt::from_u128_truncated(3)), None);
+            assert_eq!(a.checked_next_power_of_two(), Some(UInt::from_u128_truncated(1)));
+        } else {
+            let digits = ba.to_string().len() as u32;
+            assert_eq!(a.ilog10(), digits - 1, "ilog10 {}", a);
+            assert_eq!(a.checked_ilog10(), Some(digits - 1));
+            assert_eq!(a.checked_ilog(UInt::from_u128_truncated(1)), None);
+            let power = BigUint::from(1u8) << (ba.bits() - u64::from(ba.count_ones() == 1));
+            assert_eq!(a.checked_next_power_of_two(), checked_from_big::<N>(&power), "next pow2 {}", a);
+            if let Some(p) = a.checked_next_power_of_two() {
+                assert_eq!(a.next_power_of_two(), p);
+            }
+        }
+
+        for &b in &values {
+            let bb = big(b);
+            if ba == BigUint::ZERO || bb < BigUint::from(2u8) {
+                continue;
+            }
+
+            //The largest n with b^n <= a.
+            let mut n = 0;
+            let mut p = bb.clone();
+            while p <= ba {
+                p *= &bb;
+                n += 1;
+            }
+            assert_eq!(a.ilog(b), n, "ilog {} {}", a, b);
+            assert_eq!(a.checked_ilog(b), Some(n));
+        }
+
+        for &b in &values {
+            let bb = big(b);
+            let context = format!("{} {}", a, b);
+            assert_eq!(a.cmp(&b), ba.cmp(&bb), "{}", context);
+            assert_eq!(a.overflowing_add(b), from_big::<N>(&(&ba + &bb)), "{}", context);
+            assert_eq!(big(a.wrapping_sub(b)), (&ba + modulus::<N>() - &bb) % modulus::<N>(), "{}", context);
+            assert_eq!(a.overflowing_sub(b).1, ba < bb, "{}", context);
+            assert_eq!(a.overflowing_mul(b), from_big::<N>(&(&ba * &bb)), "{}", context);
+            assert_eq!(big(a & b), &ba & &bb, "{}", context);
+            assert_eq!(big(a | b), &ba | &bb, "{}", context);
+            assert_eq!(big(a ^ b), &ba ^ &bb, "{}", context);
+            assert_eq!(big(a.abs_diff(b)), if ba > bb { &ba - &bb } else { &bb - &ba }, "{}", context);
+
+            let gcd = big_gcd(&ba, &bb);
+            assert_eq!(big(a.gcd(b)), gcd, "gcd {}", context);
+            let lcm = if gcd == BigUint::ZERO { BigUint::ZERO } else { &ba / &gcd * &bb };
+            assert_eq!(a.checked_lcm(b), checked_from_big::<N>(&lcm), "lcm {}", context);
+            if let Some(l) = a.checked_lcm(b) {
+                assert_eq!(a.lcm(b), l);
+            }
+
+            if b == UInt::MIN {
+                assert_eq!(a.checked_div(b), None);
+                assert_eq!(a.checked_rem(b), None);
+                assert_eq!(a.checked_div_euclid(b), None);
+                assert_eq!(a.checked_rem_euclid(b), None);
+                assert_eq!(a.checked_next_multiple_of(b), None);
+                assert_eq!(a.saturating_div(b), UInt::MAX);
+            } else {
+                assert_eq!(big(a / b), &ba / &bb, "{}", context);
+                assert_eq!(big(a % b), &ba % &bb, "{}", context);
+                assert_eq!(big(a.div_euclid(b)), &ba / &bb, "{}", context);
+                assert_eq!(big(a.rem_euclid(b)), &ba % &bb, "{}", context);
+                assert_eq!(a.checked_div_euclid(b), Some(a / b));
+                assert_eq!(a.checked_rem_euclid(b), Some(a % b));
+                assert_eq!(a.wrapping_div_euclid(b), a / b);
+                assert_eq!(a.wrapping_rem_euclid(b), a % b);
+                assert_eq!(a.overflowing_div_euclid(b), (a / b, false));
+                assert_eq!(a.overflowing_rem_euclid(b), (a % b, false));
+                assert_eq!(a.saturating_div(b), a / b);
+
+                let multiple = (&ba + &bb - 1u8) / &bb * &bb;
+                assert_eq!(a.checked_next_multiple_of(b), checked_from_big::<N>(&multiple), "{}", context);
+                if let Some(m) = a.checked_next_multiple_of(b) {
+                    assert_eq!(a.next_multiple_of(b), m);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+pub fn test_against_biguint() {
+    check_width::<3>();
+    check_width::<9>();
+    check_width::<16>();
+    check_width::<17>();
+    check_width::<18>();
+    check_width::<24>();
+    check_width::<32>();
+}
+
+#[test]
+pub fn test_constants_and_saturation() {
+    assert_eq!(u144::BITS, 144);
+    assert_eq!(u144::BYTES, 18);
+    assert_eq!(size_of::<u144>(), 18);
+    assert_eq!(align_of::<u192>(), 1);
+    assert_eq!(u192::MAX.to_string(), "6277101735386680763835789423207666416102355444464034512895");
+    assert_eq!(u192::MAX.saturating_add(u192::from_u128_truncated(1)), u192::MAX);
+    assert_eq!(u192::MIN.saturating_sub(u192::from_u128_truncated(1)), u192::MIN);
+    assert_eq!(u192::MAX.checked_mul(u192::from_u128_truncated(2)), None);
+    assert_eq!(u256::from_u128_truncated(2).checked_pow(255), Some(u256::from_u128_truncated(1) << 255u32));
+    assert_eq!(u256::from_u128_truncated(2).checked_pow(256), None);
+    assert_eq!(u144::from_u128_truncated(3).checked_next_power_of_two(), Some(u144::from_u128_truncated(4)));
+    assert_eq!(u144::MAX.checked_next_power_of_two(), None);
+    assert_eq!(u144::MAX.ilog2(), 143);
+    assert_eq!(u192::MAX.ilog10(), 57);
+    assert_eq!(u256::MAX.isqrt(), u256::from_u128_truncated(u128::MAX));
+    assert_eq!(u144::from_u128_truncated(12).gcd(u144::from_u128_truncated(18)), u144::from_u128_truncated(6));
+    assert_eq!(u144::from_u128_truncated(12).lcm(u144::from_u128_truncated(18)), u144::from_u128_truncated(36));
+    assert_eq!(u144::MAX.checked_next_multiple_of(u144::from_u128_truncated(2)), None);
+    assert_eq!(u144::from_u128_truncated(1).wrapping_neg(), u144::MAX);
+    assert_eq!(u144::from_u128_truncated(7).div_ceil(u144::from_u128_truncated(2)), u144::from_u128_truncated(4));
+    assert_eq!(format!("{:#08x}", u144::from_u128_truncated(255)), "0x0000ff");
+    assert_eq!(format!("{:>6}", u144::from_u128_truncated(42)), "    42");
+    assert_eq!(format!("{:?}", u144::MIN), "0");
+}
+
+#[test]
+pub fn test_parse_errors() {
+    assert!("".parse::<u144>().is_err());
+    assert!("+".parse::<u144>().is_err());
+    assert!("12a".parse::<u144>().is_err());
+    assert_eq!("+0012".parse::<u144>(), Ok(u144::from_u128_truncated(12)));
+    assert_eq!("0".repeat(100).parse::<u144>(), Ok(u144::MIN));
+
+    let too_large = (big(u144::MAX) + 1u8).to_string();
+    assert_eq!(too_large.parse::<u144>().unwrap_err().to_string(), "number too large to fit in target type");
+    assert_eq!("".parse::<u144>().unwrap_err().to_string(), "cannot parse integer from empty string");
+}
+
+#[test]
+pub fn test_conversions() {
+    let x = u24::from(0x123456u32);
+    let ux: UInt<3> = x.into();
+    assert_eq!(ux.to_le_bytes(), x.to_le_bytes());
+    assert_eq!(u24::from(ux), x);
+    assert_eq!(UInt::<15>::from(u120::MAX), UInt::<15>::MAX);
+    assert_eq!(u48::from(UInt::<6>::MAX), u48::MAX);
+
+    assert_eq!(ux.widen::<18>(), u144::from_u128_truncated(0x123456));
+    assert_eq!(ux.checked_resize::<2>(), None);
+    assert_eq!(ux.checked_resize::<3>(), Some(ux));
+    assert_eq!(ux.truncate::<2>(), UInt::<2>::from_u128_truncated(0x3456));
+    assert_eq!(u144::MAX.checked_to_u128(), None);
+    assert_eq!(u144::from_u128_truncated(u128::MAX).checked_to_u128(), Some(u128::MAX));
+    assert_eq!(UInt::<3>::checked_from_u128(1 << 24), None);
+
+    assert_eq!(u144::try_from(5u8), Ok(u144::from_u128_truncated(5)));
+    assert!(UInt::<1>::try_from(256u16).is_err());
+    assert_eq!(u64::try_from(u144::from_u128_truncated(u64::MAX as u128)), Ok(u64::MAX));
+    assert!(u64::try_from(u144::from_u128_truncated(u64::MAX as u128 + 1)).is_err());
+    assert!(u128::try_from(u144::MAX).is_err());
+}
+
+#[test]
+pub fn test_ordering_and_ops() {
+    let mut values: Vec<u144> = [5u128, 1 << 100, 0, 3].into_iter().map(u144::from_u128_truncated).collect();
+    values.push(u144::MAX);
+    values.sort();
+    assert_eq!(values[0], u144::MIN);
+    assert_eq!(values[3], u144::from_u128_truncated(1 << 100));
+    assert_eq!(values.iter().max(), Some(&u144::MAX));
+    assert_eq!(u144::MAX.const_cmp(u144::MIN), Ordering::Greater);
+
+    let mut x = u144::from_u128_truncated(10);
+    x += u144::from_u128_truncated(5);
+    x *= &u144::from_u128_truncated(2);
+    x -= u144::from_u128_truncated(6);
+    x /= u144::from_u128_truncated(4);
+    x %= u144::from_u128_truncated(4);
+    x <<= 140u32;
+    x >>= 139usize;
+    assert_eq!(x, u144::from_u128_truncated(4));
+    assert_eq!(std::ops::Add::add(&x, &x), u144::from_u128_truncated(8));
+}
+
+#[test]
+#[should_panic]
+pub fn test_div_by_zero() {
+    let _ = u192::MAX / u192::MIN;
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+pub fn test_add_overflow() {
+    let _ = u192::MAX + u192::from_u128_truncated(1);
+}
@@ -0,0 +1,97 @@
+use uintx::{u104, u112, u120, u24, u40, u48, u56, u72, u80, u88, u96, Checked, Saturating, Wrapping};
+
+macro_rules! test_type {
+    ($($ty:ident),*) => {
+        #[test]
+        pub fn test_all_types() {
+            $(
+                let one = $ty::from(1u8);
+                let two = $ty::from(2u8);
+
+                assert_eq!((Wrapping($ty::MAX) + Wrapping(one)).0, $ty::MIN);
+                assert_eq!((Wrapping($ty::MIN) - Wrapping(one)).0, $ty::MAX);
+                assert_eq!((Wrapping($ty::MAX) * Wrapping(two)).0, $ty::MAX.wrapping_mul(two));
+                assert_eq!((-Wrapping(one)).0, $ty::MAX);
+                assert_eq!((Wrapping(one) << ($ty::NUM_BITS as u32 + 1)).0, two);
+                assert_eq!((Wrapping(two) >> 1).0, one);
+                assert_eq!((!Wrapping($ty::MIN)).0, $ty::MAX);
+
+                assert_eq!((Saturating($ty::MAX) + Saturating(one)).0, $ty::MAX);
+                assert_eq!((Saturating($ty::MIN) - Saturating(one)).0, $ty::MIN);
+                assert_eq!((Saturating($ty::MAX) * Saturating(two)).0, $ty::MAX);
+                assert_eq!((Saturating($ty::MAX) / Saturating(two)).0, $ty::MAX.div(two));
+                assert_eq!((Saturating($ty::MAX) % Saturating(two)).0, one);
+
+                assert_eq!((Checked::new($ty::MAX) + one).get(), None);
+                assert_eq!((Checked::new($ty::MAX) - one).get(), Some($ty::MAX.wrapping_sub(one)));
+                assert_eq!((Checked::new(one) / $ty::MIN).get(), None);
+                assert_eq!((Checked::new(one) % $ty::MIN).get(), None);
+                assert_eq!((-Checked::new(one)).get(), None);
+                assert_eq!((-Checked::new($ty::MIN)).get(), Some($ty::MIN));
+                assert_eq!((Checked::new(one) << $ty::NUM_BITS as u32).get(), None);
+                assert_eq!((Checked::new(two) >> 1).get(), Some(one));
+                assert_eq!((Checked::new($ty::MAX) & two).get(), Some(two));
+                assert_eq!((!Checked::new($ty::MAX)).get(), Some($ty::MIN));
+            )*
+        }
+    };
+}
+
+test_type!(u24, u40, u48, u56, u72, u80, u88, u96, u104, u112, u120);
+
+#[test]
+pub fn test_assign_ops() {
+    let one = u24::from(1u8);
+    let mut w = Wrapping(u24::MAX);
+    w += Wrapping(one);
+    w -= &Wrapping(one);
+    w *= Wrapping(u24::from(3u8));
+    w <<= 1;
+    w >>= 1;
+    w ^= Wrapping(one);
+    assert_eq!(w.0, u24::MAX.wrapping_mul(u24::from(3u8)).wrapping_shl(1).wrapping_shr(1) ^ one);
+
+    let mut s = Saturating(u24::MIN);
+    s -= Saturating(one);
+    assert_eq!(s.0, u24::MIN);
+    s += Saturating(u24::MAX);
+    s += Saturating(u24::MAX);
+    assert_eq!(s.0, u24::MAX);
+    s /= Saturating(u24::MAX);
+    assert_eq!(s.0, one);
+
+    let mut c = Checked::new(u24::from(10u8));
+    c *= u24::from(2u8);
+    c -= Checked::new(u24::from(5u8));
+    c %= u24::from(4u8);
+    assert_eq!(c.get(), Some(u24::from(3u8)));
+    c <<= 30;
+    assert!(c.is_overflow());
+    //Once overflowed the value stays None.
+    c -= u24::from(5u8);
+    c &= Checked::new(u24::MIN);
+    assert_eq!(c, Checked(None));
+}
+
+#[test]
+pub fn test_sum_product() {
+    let values = [u24::MAX, u24::from(2u8), u24::from(3u8)];
+    assert_eq!(values.iter().copied().map(Wrapping).sum::<Wrapping<u24>>().0, u24::from(4u8));
+    assert_eq!(values.iter().copied().map(Saturating).sum::<Saturating<u24>>().0, u24::MAX);
+    assert_eq!(values.iter().copied().map(Checked::new).sum::<Checked<u24>>().get(), None);
+    assert_eq!(values[1..].iter().copied().map(Checked::new).product::<Checked<u24>>().get(), Some(u24::from(6u8)));
+    assert_eq!(values.iter().copied().map(Saturating).product::<Saturating<u24>>().0, u24::MAX);
+    assert_eq!(core::iter::empty().product::<Wrapping<u48>>().0, u48::from(1u8));
+    assert_eq!(Checked::<u24>::default().get(), Some(u24::MIN));
+}
+
+#[test]
+pub fn test_formatting() {
+    let x = u24::from(255u8);
+    assert_eq!(format!("{}", Wrapping(x)), "255");
+    assert_eq!(format!("{:?}", Saturating(x)), "255");
+    assert_eq!(format!("{:x}", Wrapping(x)), "ff");
+    assert_eq!(format!("{:#b}", Saturating(u24::from(5u8))), "0b101");
+    assert_eq!(Wrapping::from(x), Wrapping(x));
+    assert_eq!(Checked::from(x).get(), Some(x));
+}
@@ -0,0 +1,170 @@
+//!
+//! Derive macros for the uintx crate. Use them through the derive feature of uintx.
+//!
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, Index};
+
+#[derive(Copy, Clone)]
+enum Endian {
+    Little,
+    Big,
+}
+
+///
+/// Returns the byte order given by a #[le] or #[be] attribute or default if there is none.
+///
+fn endian(attrs: &[Attribute], default: Endian) -> syn::Result<Endian> {
+    let mut result = None;
+    for attr in attrs {
+        let endian = if attr.path().is_ident("le") {
+            Endian::Little
+        } else if attr.path().is_ident("be") {
+            Endian::Big
+        } else {
+            continue;
+        };
+
+        attr.meta.require_path_only()?;
+        if result.is_some() {
+            return Err(Error::new_spanned(attr, "only one of #[le] and #[be] may be given"));
+        }
+
+        result = Some(endian);
+    }
+
+    Ok(result.unwrap_or(default))
+}
+
+///
+/// Derives uintx::record::PackedRecord for a struct.
+///
+/// Generates the constant SIZE, from_bytes(&[u8; SIZE]) and to_bytes() -> [u8; SIZE].
+/// Fields are stored back to back without padding in declaration order.
+/// Every field type has to implement uintx::record::PackedField, which includes primitives,
+/// all uintx types, arrays of them and other PackedRecord structs.
+///
+/// Fields are little endian unless the field or the struct is annotated with #[be].
+/// #[le] switches a field back to little endian. Nested records always use their own byte order.
+///
+#[proc_macro_derive(PackedRecord, attributes(le, be))]
+pub fn derive_packed_record(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "PackedRecord can not be derived for generic types"));
+    }
+
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        _ => return Err(Error::new_spanned(name, "PackedRecord can only be derived for structs")),
+    };
+
+    let record = quote!(::uintx::record);
+    let default = endian(&input.attrs, Endian::Little)?;
+
+    let mut sizes = Vec::new();
+    let mut reads = Vec::new();
+    let mut writes = Vec::new();
+    for (index, field) in data.fields.iter().enumerate() {
+        let ty = &field.ty;
+        let (read, write) = match endian(&field.attrs, default)? {
+            Endian::Little => (quote!(read_le), quote!(write_le)),
+            Endian::Big => (quote!(read_be), quote!(write_be)),
+        };
+
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = Index::from(index);
+                quote!(#index)
+            }
+        };
+
+        sizes.push(quote!(<#ty as #record::PackedField>::SIZE));
+        reads.push(quote! {{
+            let end = offset + <#ty as #record::PackedField>::SIZE;
+            let value = <#ty as #record::PackedField>::#read(&bytes[offset..end]);
+            offset = end;
+            value
+        }});
+        writes.push(quote! {
+            let end = offset + <#ty as #record::PackedField>::SIZE;
+            <#ty as #record::PackedField>::#write(&self.#member, &mut bytes[offset..end]);
+            offset = end;
+        });
+    }
+
+    let construct = match &data.fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote!(#name { #(#names: #reads),* })
+        }
+        Fields::Unnamed(_) => quote!(#name(#(#reads),*)),
+        Fields::Unit => quote!(#name),
+    };
+
+    let size = quote!(0usize #(+ #sizes)*);
+
+    Ok(quote! {
+        impl #name {
+            ///
+            /// Size of the record in bytes.
+            ///
+            pub const SIZE: usize = #size;
+
+            ///
+            /// Decodes the record from its packed representation.
+            ///
+            #[inline]
+            pub fn from_bytes(bytes: &[u8; #name::SIZE]) -> Self {
+                <Self as #record::PackedField>::read_le(bytes)
+            }
+
+            ///
+            /// Encodes the record into its packed representation.
+            ///
+            #[inline]
+            pub fn to_bytes(&self) -> [u8; #name::SIZE] {
+                let mut bytes = [0u8; #name::SIZE];
+                <Self as #record::PackedField>::write_le(self, &mut bytes);
+                bytes
+            }
+        }
+
+        impl #record::PackedField for #name {
+            const SIZE: usize = #size;
+
+            #[allow(unused_assignments, unused_mut, unused_variables)]
+            fn read_le(bytes: &[u8]) -> Self {
+                assert_eq!(bytes.len(), #name::SIZE, "byte slice does not have the size of the record");
+                let mut offset = 0usize;
+                #construct
+            }
+
+            #[inline]
+            fn read_be(bytes: &[u8]) -> Self {
+                <Self as #record::PackedField>::read_le(bytes)
+            }
+
+            #[allow(unused_assignments, unused_mut, unused_variables)]
+            fn write_le(&self, bytes: &mut [u8]) {
+                assert_eq!(bytes.len(), #name::SIZE, "byte slice does not have the size of the record");
+                let mut offset = 0usize;
+                #(#writes)*
+            }
+
+            #[inline]
+            fn write_be(&self, bytes: &mut [u8]) {
+                <Self as #record::PackedField>::write_le(self, bytes)
+            }
+        }
+
+        impl #record::PackedRecord for #name {}
+    })
+}